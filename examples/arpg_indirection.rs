@@ -122,7 +122,7 @@ fn copy_action_state(
                 // This copies the `ActionData` between the ActionStates,
                 // including information about how long the buttons have been pressed or released
                 ability_state.set_button_data(
-                    *matching_ability,
+                    matching_ability,
                     slot_state.button_data_mut_or_default(&slot).clone(),
                 );
             }
@@ -63,9 +63,11 @@ fn generate_input_control_kind_body(
                 return Ok(quote!(#crate_path::InputControlKind::#default_control));
             }
 
+            // Braced patterns match unit, tuple and struct variants alike,
+            // so variants carrying data can have their own control kinds too.
             let controls: Vec<_> = controls
                 .iter()
-                .map(|(variant, control)| quote!(Self::#variant => #crate_path::InputControlKind::#control,))
+                .map(|(variant, control)| quote!(Self::#variant { .. } => #crate_path::InputControlKind::#control,))
                 .collect();
             Ok(quote! {
                 match self {
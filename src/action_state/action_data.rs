@@ -1,16 +1,21 @@
 //! Contains types used to store the state of the actions held in an [`ActionState`](super::ActionState).
 
 use bevy::{
-    math::{Vec2, Vec3},
+    ecs::entity::Entity,
+    math::{CompassOctant, Dir2, Rot2, Vec2, Vec3},
     reflect::Reflect,
-    utils::Instant,
+    utils::{Duration, Instant},
 };
 use serde::{Deserialize, Serialize};
 
 use crate::buttonlike::ButtonValue;
+use crate::input_processing::CircleDeadZone;
 #[cfg(feature = "timing")]
 use crate::timing::Timing;
-use crate::{buttonlike::ButtonState, InputControlKind};
+use crate::{
+    buttonlike::{ButtonState, EdgeMode},
+    InputControlKind,
+};
 
 /// Data about the state of an action.
 ///
@@ -47,12 +52,44 @@ impl ActionData {
         match self.kind_data {
             ActionKindData::Button(ref mut data) => {
                 data.state.tick();
+                data.cooldown.tick(_current_instant, _previous_instant);
 
                 #[cfg(feature = "timing")]
                 data.timing.tick(_current_instant, _previous_instant);
             }
             ActionKindData::Axis(ref mut _data) => {}
-            ActionKindData::DualAxis(ref mut _data) => {}
+            ActionKindData::DualAxis(ref mut data) => data.activation.tick(),
+            ActionKindData::TripleAxis(ref mut _data) => {}
+        }
+    }
+
+    /// Excludes the given `duration` from the timers of the action, as if it never passed.
+    ///
+    /// Unlike [`ActionData::tick`], this doesn't advance the [`ButtonState`],
+    /// so an action that was just pressed or released stays so until the next tick.
+    pub fn skip(&mut self, duration: Duration) {
+        if let ActionKindData::Button(ref mut data) = self.kind_data {
+            data.cooldown.skip(duration);
+
+            #[cfg(feature = "timing")]
+            data.timing.skip(duration);
+        }
+    }
+
+    /// Ticks the action data by a fixed number of `steps`, like [`ActionData::tick`]
+    /// but counting steps instead of measuring time.
+    ///
+    /// [`Cooldown`]s are measured in time, and are not advanced.
+    pub fn tick_fixed(&mut self, _steps: u32) {
+        match self.kind_data {
+            ActionKindData::Button(ref mut data) => {
+                data.state.tick();
+
+                #[cfg(feature = "timing")]
+                data.timing.tick_fixed(_steps);
+            }
+            ActionKindData::Axis(ref mut _data) => {}
+            ActionKindData::DualAxis(ref mut data) => data.activation.tick(),
             ActionKindData::TripleAxis(ref mut _data) => {}
         }
     }
@@ -88,7 +125,9 @@ impl ActionKindData {
             }
             Self::DualAxis(data) => {
                 data.fixed_update_pair = data.pair;
+                data.fixed_update_activation = data.activation;
                 data.pair = data.update_pair;
+                data.activation = data.update_activation;
             }
             Self::TripleAxis(data) => {
                 data.fixed_update_triple = data.triple;
@@ -113,7 +152,9 @@ impl ActionKindData {
             }
             Self::DualAxis(data) => {
                 data.update_pair = data.pair;
+                data.update_activation = data.activation;
                 data.pair = data.fixed_update_pair;
+                data.activation = data.fixed_update_activation;
             }
             Self::TripleAxis(data) => {
                 data.update_triple = data.triple;
@@ -141,6 +182,41 @@ pub struct ButtonData {
     /// When was the button pressed / released, and how long has it been held for?
     #[cfg(feature = "timing")]
     pub timing: Timing,
+    /// Should the button stay pressed across input updates until it is explicitly released?
+    ///
+    /// This is set by [`ActionState::press_persistent`](super::ActionState::press_persistent).
+    #[serde(default)]
+    pub persistent: bool,
+    /// How long after being fired must the button wait before it can be fired again?
+    #[serde(default)]
+    pub cooldown: Cooldown,
+    /// The gamepad that is currently pressing the button, if it accepts input from any gamepad.
+    ///
+    /// This is set by [`InputMap::set_any_gamepad`](crate::input_map::InputMap::set_any_gamepad).
+    #[serde(default)]
+    pub triggering_gamepad: Option<Entity>,
+    /// The index of the first binding of the action in its [`InputMap`](crate::input_map::InputMap)
+    /// that is currently pressing the button, such as `0` for the primary binding and `1` for the secondary one.
+    #[serde(default)]
+    pub triggering_binding_index: Option<usize>,
+    /// When should the button fire as its inputs are pressed and released?
+    ///
+    /// This is set by [`InputMap::set_edge_mode`](crate::input_map::InputMap::set_edge_mode).
+    #[serde(default)]
+    pub edge_mode: EdgeMode,
+    /// Were the inputs of the button pressed on the last [`ActionState::update`](super::ActionState::update)?
+    ///
+    /// This differs from `state` when the [`EdgeMode`] is not [`EdgeMode::Level`].
+    #[serde(default)]
+    pub input_pressed: bool,
+    /// Was the current `state` set directly on the [`ActionState`](super::ActionState),
+    /// rather than by input passed through [`ActionState::update`](super::ActionState::update)?
+    ///
+    /// Inputs mocked on the [`World`](bevy::prelude::World) are passed through [`ActionState::update`](super::ActionState::update) like real input,
+    /// so they don't count as being set directly.
+    #[serde(skip)]
+    #[reflect(ignore)]
+    pub set_directly: bool,
 }
 
 impl ButtonData {
@@ -154,6 +230,13 @@ impl ButtonData {
         fixed_update_value: 1.0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        persistent: false,
+        cooldown: Cooldown::NEW,
+        triggering_gamepad: None,
+        triggering_binding_index: None,
+        edge_mode: EdgeMode::Level,
+        input_pressed: false,
+        set_directly: false,
     };
 
     /// The default data for a button that was just released.
@@ -166,6 +249,13 @@ impl ButtonData {
         fixed_update_value: 0.0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        persistent: false,
+        cooldown: Cooldown::NEW,
+        triggering_gamepad: None,
+        triggering_binding_index: None,
+        edge_mode: EdgeMode::Level,
+        input_pressed: false,
+        set_directly: false,
     };
 
     /// The default data for a button that is released,
@@ -182,6 +272,13 @@ impl ButtonData {
         fixed_update_value: 0.0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        persistent: false,
+        cooldown: Cooldown::NEW,
+        triggering_gamepad: None,
+        triggering_binding_index: None,
+        edge_mode: EdgeMode::Level,
+        input_pressed: false,
+        set_directly: false,
     };
 
     /// Is the action currently pressed?
@@ -192,10 +289,12 @@ impl ButtonData {
     }
 
     /// Was the action pressed since the last time it was ticked?
+    ///
+    /// Presses made while the action was on [`Cooldown`] are ignored.
     #[inline]
     #[must_use]
     pub fn just_pressed(&self) -> bool {
-        self.state.just_pressed()
+        self.state.just_pressed() && !self.cooldown.suppressed
     }
 
     /// Is the action currently released?
@@ -212,6 +311,14 @@ impl ButtonData {
         self.state.just_released()
     }
 
+    /// Records whether the inputs of the button are pressed,
+    /// returning whether the button should be pressed according to its [`EdgeMode`].
+    #[inline]
+    pub(crate) fn apply_edge_mode(&mut self, input_pressed: bool) -> bool {
+        let previous = core::mem::replace(&mut self.input_pressed, input_pressed);
+        self.edge_mode.fires(previous, input_pressed)
+    }
+
     /// Convert `self` to a [`ButtonValue`].
     #[inline]
     #[must_use]
@@ -220,6 +327,77 @@ impl ButtonData {
     }
 }
 
+/// Tracks the cooldown of a [`Buttonlike`](crate::user_input::Buttonlike) action.
+///
+/// Each time the action is pressed while off cooldown, it fires and the cooldown restarts.
+/// Presses made while the cooldown is running still count as pressed,
+/// but will not be reported as [`just_pressed`](ButtonData::just_pressed).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub struct Cooldown {
+    /// The total [`Duration`] of the cooldown.
+    ///
+    /// A cooldown of [`Duration::ZERO`] means the action can always be fired.
+    pub duration: Duration,
+    /// The [`Duration`] left before the action can be fired again.
+    pub remaining: Duration,
+    /// The [`Instant`] at which the cooldown started.
+    /// Recorded as the [`Time`](bevy::time::Time) at the start of the tick after the action fired.
+    #[serde(skip)]
+    pub instant_started: Option<Instant>,
+    /// Was the current press made while the cooldown was running?
+    pub suppressed: bool,
+}
+
+impl Cooldown {
+    /// The default cooldown, which never blocks the action
+    pub const NEW: Cooldown = Cooldown {
+        duration: Duration::ZERO,
+        remaining: Duration::ZERO,
+        instant_started: None,
+        suppressed: false,
+    };
+
+    /// Is the cooldown still running?
+    #[inline]
+    #[must_use]
+    pub fn active(&self) -> bool {
+        self.remaining > Duration::ZERO
+    }
+
+    /// Registers a new press of the action, firing it and restarting the cooldown if possible.
+    ///
+    /// Returns `true` if the action was fired.
+    pub fn trigger(&mut self) -> bool {
+        self.suppressed = self.active();
+        if !self.suppressed && self.duration > Duration::ZERO {
+            self.remaining = self.duration;
+            self.instant_started = None;
+        }
+        !self.suppressed
+    }
+
+    /// Advances the `remaining` duration of this cooldown.
+    ///
+    /// If the `instant_started` is None, it will be set to the previous time.
+    pub fn tick(&mut self, current_instant: Instant, previous_instant: Instant) {
+        if !self.active() {
+            return;
+        }
+
+        let instant_started = *self.instant_started.get_or_insert(previous_instant);
+        self.remaining = self
+            .duration
+            .saturating_sub(current_instant.saturating_duration_since(instant_started));
+    }
+
+    /// Excludes the given `duration` from this cooldown, as if it never passed.
+    pub fn skip(&mut self, duration: Duration) {
+        if let Some(instant_started) = self.instant_started.as_mut() {
+            *instant_started += duration;
+        }
+    }
+}
+
 /// The raw data for an [`ActionState`](super::ActionState) corresponding to a single virtual axis.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize, Reflect)]
 pub struct AxisData {
@@ -232,6 +410,17 @@ pub struct AxisData {
 }
 
 /// The raw data for an [`ActionState`](super::ActionState) corresponding to a pair of virtual axes.
+///
+/// # Serialization
+///
+/// The data is serialized as a struct holding each of its fields in declaration order,
+/// where every [`Vec2`] is a `Vec2` tuple struct of its `x` and `y` components,
+/// and every [`ButtonState`] is a unit variant.
+/// In RON, the default data looks like this:
+///
+/// ```ron
+/// (pair:(0.0,0.0),update_pair:(0.0,0.0),fixed_update_pair:(0.0,0.0),activation:Released,update_activation:Released,fixed_update_activation:Released)
+/// ```
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize, Reflect)]
 pub struct DualAxisData {
     /// The XY coordinates of the axis
@@ -240,6 +429,280 @@ pub struct DualAxisData {
     pub update_pair: Vec2,
     /// The `pair` of the action in the `FixedMain` schedule
     pub fixed_update_pair: Vec2,
+    /// Has the `pair` left neutral, tracked like a button so that transitions can be detected?
+    ///
+    /// This is pressed while the length of the `pair` exceeds [`DualAxisData::ACTIVATION_THRESHOLD`].
+    #[serde(default)]
+    pub activation: ButtonState,
+    /// The `activation` of the action in the `Main` schedule
+    #[serde(default)]
+    pub update_activation: ButtonState,
+    /// The `activation` of the action in the `FixedMain` schedule
+    #[serde(default)]
+    pub fixed_update_activation: ButtonState,
+}
+
+impl From<Vec2> for DualAxisData {
+    /// Creates data whose current `pair` is the given value, with the `activation` state set to match.
+    fn from(pair: Vec2) -> Self {
+        let mut data = Self::default();
+        data.set_pair(pair);
+        data
+    }
+}
+
+impl From<DualAxisData> for Vec2 {
+    /// Returns the current `pair` of the data.
+    fn from(data: DualAxisData) -> Self {
+        data.pair
+    }
+}
+
+impl DualAxisData {
+    /// The length that the `pair` must exceed to no longer be considered neutral.
+    pub const ACTIVATION_THRESHOLD: f32 = 0.01;
+
+    /// Creates data whose current `pair` points in the direction of `rotation`,
+    /// measured counterclockwise from the positive X axis, with the given `magnitude`.
+    ///
+    /// The `activation` state is set to match the `pair`.
+    /// This is useful for building stick vectors from an angle, such as in radial menus.
+    ///
+    /// ```rust
+    /// use bevy::math::{Rot2, Vec2};
+    /// use leafwing_input_manager::action_state::DualAxisData;
+    ///
+    /// let data = DualAxisData::from_polar(Rot2::degrees(90.0), 0.5);
+    /// assert!((data.pair - Vec2::new(0.0, 0.5)).length() < 0.0001);
+    /// assert!(data.activation.pressed());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_polar(rotation: Rot2, magnitude: f32) -> Self {
+        let mut data = Self::default();
+        data.set_pair(rotation * Vec2::new(magnitude, 0.0));
+        data
+    }
+
+    /// Sets the current `pair`, updating the `activation` state to match.
+    #[inline]
+    pub fn set_pair(&mut self, pair: Vec2) {
+        self.pair = pair;
+
+        if pair.length() > Self::ACTIVATION_THRESHOLD {
+            self.activation.press();
+        } else {
+            self.activation.release();
+        }
+    }
+
+    /// Clamps the length of the current `pair` to be at most `max`, preserving its direction.
+    #[inline]
+    pub fn clamp_length(&mut self, max: f32) {
+        self.pair = self.pair.clamp_length_max(max);
+    }
+
+    /// Returns a copy of this data with the length of the current `pair` clamped to be at most `max`.
+    ///
+    /// Unlike [`DualAxisData::clamp_length`], this leaves `self` untouched,
+    /// which allows chaining in builder-style code.
+    #[inline]
+    #[must_use]
+    pub fn clamped_length(mut self, max: f32) -> Self {
+        self.clamp_length(max);
+        self
+    }
+
+    /// Returns a copy of this data with each component of the current `pair`
+    /// clamped to the range between the matching components of `min` and `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `min` is greater than the matching component of `max`.
+    #[inline]
+    #[must_use]
+    pub fn clamp_to_bounds(mut self, min: Vec2, max: Vec2) -> Self {
+        self.pair = self.pair.clamp(min, max);
+        self
+    }
+
+    /// Returns the direction of the current `pair`,
+    /// or [`None`] if it is neutral (its length does not exceed [`DualAxisData::ACTIVATION_THRESHOLD`]).
+    #[inline]
+    #[must_use]
+    pub fn direction(&self) -> Option<Dir2> {
+        if self.pair.length() > Self::ACTIVATION_THRESHOLD {
+            Dir2::new(self.pair).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the current `pair` is effectively centered: its length does not exceed the `threshold`.
+    ///
+    /// Pass [`DualAxisData::ACTIVATION_THRESHOLD`] to match the threshold used by [`DualAxisData::direction`].
+    ///
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_input_manager::action_state::DualAxisData;
+    ///
+    /// let mut data = DualAxisData::default();
+    /// data.set_pair(Vec2::new(0.03, 0.04));
+    ///
+    /// assert!(data.is_neutral(0.1));
+    /// assert!(!data.is_neutral(DualAxisData::ACTIVATION_THRESHOLD));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_neutral(&self, threshold: f32) -> bool {
+        self.pair.length() <= threshold
+    }
+
+    /// Returns the [`CompassOctant`] that the current `pair` points towards,
+    /// or [`None`] if it is neutral (its length does not exceed [`DualAxisData::ACTIVATION_THRESHOLD`]).
+    ///
+    /// Each octant spans 45 degrees, centered on its compass direction.
+    /// Directions exactly on the boundary between two octants are classified
+    /// as the cardinal direction (north, east, south or west) rather than the diagonal one.
+    ///
+    /// This is useful for discrete selection, such as radial menus.
+    #[inline]
+    #[must_use]
+    pub fn octant(&self) -> Option<CompassOctant> {
+        // tan(22.5°), the slope of the boundaries between cardinal and diagonal octants
+        const BOUNDARY_SLOPE: f32 = std::f32::consts::SQRT_2 - 1.0;
+
+        self.direction()?;
+        let Vec2 { x, y } = self.pair;
+        let octant = if y.abs() <= x.abs() * BOUNDARY_SLOPE {
+            if x > 0.0 {
+                CompassOctant::East
+            } else {
+                CompassOctant::West
+            }
+        } else if x.abs() <= y.abs() * BOUNDARY_SLOPE {
+            if y > 0.0 {
+                CompassOctant::North
+            } else {
+                CompassOctant::South
+            }
+        } else {
+            match (x > 0.0, y > 0.0) {
+                (true, true) => CompassOctant::NorthEast,
+                (true, false) => CompassOctant::SouthEast,
+                (false, true) => CompassOctant::NorthWest,
+                (false, false) => CompassOctant::SouthWest,
+            }
+        };
+        Some(octant)
+    }
+
+    /// Returns the length of the current `pair`.
+    #[inline]
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.pair.length()
+    }
+
+    /// Returns the [`Rot2`] from the positive X axis to the direction of the current `pair`,
+    /// or [`None`] if it is neutral.
+    ///
+    /// This is the inverse of [`DualAxisData::from_polar`], together with [`DualAxisData::length`].
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self) -> Option<Rot2> {
+        self.direction()
+            .map(|direction| Dir2::X.rotation_to(direction))
+    }
+
+    /// Returns the [`Rot2`] that rotates the direction of `self` onto the direction of `other`.
+    ///
+    /// Returns [`None`] if either `pair` is neutral.
+    #[inline]
+    #[must_use]
+    pub fn angle_between(&self, other: &DualAxisData) -> Option<Rot2> {
+        let from = self.direction()?;
+        let to = other.direction()?;
+        Some(from.rotation_to(to))
+    }
+
+    /// Returns the signed angle in radians from the direction of `self` to the direction of `other`,
+    /// in the range `(-PI, PI]`.
+    ///
+    /// Positive angles are counterclockwise.
+    /// Returns [`None`] if either `pair` is neutral.
+    #[inline]
+    #[must_use]
+    pub fn signed_angle_to(&self, other: &DualAxisData) -> Option<f32> {
+        self.angle_between(other).map(Rot2::as_radians)
+    }
+
+    /// Returns a copy of this data with the current `pair` projected onto `direction`,
+    /// keeping only its component along that direction.
+    ///
+    /// The `activation` state is updated to match the projected `pair`,
+    /// so input perpendicular to `direction` becomes neutral.
+    /// This is useful for locking movement to a single axis, such as in side-scrollers.
+    #[inline]
+    #[must_use]
+    pub fn project_onto(&self, direction: Dir2) -> DualAxisData {
+        let mut projected = self.clone();
+        projected.set_pair(self.pair.project_onto_normalized(*direction));
+        projected
+    }
+
+    /// Returns a copy of this data with a [`CircleDeadZone`] of radius `min` applied to the current `pair`,
+    /// without needing to add a processor to the input.
+    ///
+    /// Values inside the dead zone become neutral,
+    /// while values outside of it are scaled to keep the output continuous.
+    /// The `activation` state is updated to match the processed `pair`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    #[must_use]
+    pub fn with_circle_deadzone(&self, min: f32) -> DualAxisData {
+        let mut processed = self.clone();
+        processed.set_pair(CircleDeadZone::new(min).normalize(self.pair));
+        processed
+    }
+
+    /// Checks if this data is equal to `other`, allowing each component of the pairs to differ by at most `epsilon`.
+    ///
+    /// The `activation` states must match exactly.
+    /// Unlike the exact [`PartialEq`] implementation, this is robust to rounding errors,
+    /// such as those introduced by processing.
+    ///
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_input_manager::action_state::DualAxisData;
+    ///
+    /// let mut data = DualAxisData::default();
+    /// data.set_pair(Vec2::new(0.5, 0.5));
+    /// let mut other = DualAxisData::default();
+    /// other.set_pair(Vec2::new(0.5, 0.5001));
+    ///
+    /// assert_ne!(data, other);
+    /// assert!(data.approx_eq(&other, 0.001));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn approx_eq(&self, other: &DualAxisData, epsilon: f32) -> bool {
+        self.pair.abs_diff_eq(other.pair, epsilon)
+            && self.update_pair.abs_diff_eq(other.update_pair, epsilon)
+            && self
+                .fixed_update_pair
+                .abs_diff_eq(other.fixed_update_pair, epsilon)
+            && self.activation == other.activation
+            && self.update_activation == other.update_activation
+            && self.fixed_update_activation == other.fixed_update_activation
+    }
 }
 
 /// The raw data for an [`ActionState`](super::ActionState) corresponding to a triple of virtual axes.
@@ -252,3 +715,275 @@ pub struct TripleAxisData {
     /// The `triple` of the action in the `FixedMain` schedule
     pub fixed_update_triple: Vec3,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use bevy::math::{Dir2, Rot2, Vec2};
+
+    use super::DualAxisData;
+    use crate::buttonlike::ButtonState;
+
+    #[test]
+    fn test_dual_axis_data_from_vec2() {
+        let data = DualAxisData::from(Vec2::new(0.6, -0.8));
+        assert_eq!(data.pair, Vec2::new(0.6, -0.8));
+        assert!(data.activation.pressed());
+        assert_eq!(Vec2::from(data), Vec2::new(0.6, -0.8));
+
+        let neutral = DualAxisData::from(Vec2::ZERO);
+        assert!(neutral.activation.released());
+    }
+
+    #[test]
+    fn test_dual_axis_data_serialization() {
+        use serde_test::{assert_tokens, Token};
+
+        let data = DualAxisData {
+            update_activation: ButtonState::Pressed,
+            ..DualAxisData::from(Vec2::new(1.0, -0.5))
+        };
+
+        let pair = |x: f32, y: f32| {
+            [
+                Token::TupleStruct {
+                    name: "Vec2",
+                    len: 2,
+                },
+                Token::F32(x),
+                Token::F32(y),
+                Token::TupleStructEnd,
+            ]
+        };
+
+        let mut tokens = vec![
+            Token::Struct {
+                name: "DualAxisData",
+                len: 6,
+            },
+            Token::Str("pair"),
+        ];
+        tokens.extend(pair(1.0, -0.5));
+        tokens.push(Token::Str("update_pair"));
+        tokens.extend(pair(0.0, 0.0));
+        tokens.push(Token::Str("fixed_update_pair"));
+        tokens.extend(pair(0.0, 0.0));
+        for (field, variant) in [
+            ("activation", "JustPressed"),
+            ("update_activation", "Pressed"),
+            ("fixed_update_activation", "Released"),
+        ] {
+            tokens.push(Token::Str(field));
+            tokens.push(Token::UnitVariant {
+                name: "ButtonState",
+                variant,
+            });
+        }
+        tokens.push(Token::StructEnd);
+        assert_tokens(&data, &tokens);
+
+        let serialized = ron::to_string(&data).unwrap();
+        assert_eq!(
+            serialized,
+            "(pair:(1.0,-0.5),update_pair:(0.0,0.0),fixed_update_pair:(0.0,0.0),\
+             activation:JustPressed,update_activation:Pressed,fixed_update_activation:Released)"
+        );
+        let deserialized: DualAxisData = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, data);
+    }
+
+    #[test]
+    fn test_dual_axis_data_clamping() {
+        let original = DualAxisData {
+            pair: Vec2::new(3.0, 4.0),
+            ..Default::default()
+        };
+
+        let clamped = original.clone().clamped_length(1.0);
+        assert_eq!(original.pair, Vec2::new(3.0, 4.0));
+        assert!((clamped.pair - Vec2::new(0.6, 0.8)).length() < f32::EPSILON);
+
+        let mut mutated = original.clone();
+        mutated.clamp_length(1.0);
+        assert_eq!(mutated, clamped);
+
+        let bounded = original.clamp_to_bounds(Vec2::new(-1.0, -2.0), Vec2::new(1.0, 2.0));
+        assert_eq!(bounded.pair, Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_dual_axis_data_angles() {
+        let data = |pair| DualAxisData {
+            pair,
+            ..Default::default()
+        };
+
+        let right = data(Vec2::X);
+        let up = data(Vec2::new(0.0, 0.5));
+        let neutral = data(Vec2::ZERO);
+
+        let angle = right.signed_angle_to(&up).unwrap();
+        assert!((angle - FRAC_PI_2).abs() < 0.0001);
+        let angle = up.signed_angle_to(&right).unwrap();
+        assert!((angle + FRAC_PI_2).abs() < 0.0001);
+
+        let rotation = right.angle_between(&up).unwrap();
+        assert!((rotation * Vec2::X - Vec2::Y).length() < 0.0001);
+
+        assert_eq!(right.angle_between(&neutral), None);
+        assert_eq!(neutral.signed_angle_to(&up), None);
+    }
+
+    #[test]
+    fn test_dual_axis_data_approx_eq() {
+        let mut data = DualAxisData::default();
+        data.set_pair(Vec2::new(0.3, 0.4));
+        let mut nearby = DualAxisData::default();
+        nearby.set_pair(Vec2::new(0.3001, 0.3999));
+
+        assert_ne!(data, nearby);
+        assert!(data.approx_eq(&nearby, 0.001));
+        assert!(nearby.approx_eq(&data, 0.001));
+        assert!(!data.approx_eq(&nearby, 0.00001));
+
+        // Larger differences in a single component are not ignored
+        let mut far = DualAxisData::default();
+        far.set_pair(Vec2::new(0.3, 0.41));
+        assert!(!data.approx_eq(&far, 0.001));
+
+        // Activation states must match exactly
+        let mut neutral = DualAxisData::default();
+        neutral.set_pair(Vec2::new(0.005, 0.0));
+        let mut active = DualAxisData::default();
+        active.set_pair(Vec2::new(0.015, 0.0));
+        assert!(!neutral.approx_eq(&active, 0.1));
+    }
+
+    #[test]
+    fn test_dual_axis_data_from_polar() {
+        let up = DualAxisData::from_polar(Rot2::degrees(90.0), 1.0);
+        assert!((up.pair - Vec2::Y).length() < 0.0001);
+        assert!(up.activation.pressed());
+
+        let neutral = DualAxisData::from_polar(Rot2::degrees(45.0), 0.0);
+        assert_eq!(neutral.pair, Vec2::ZERO);
+        assert!(neutral.activation.released());
+        assert_eq!(neutral.rotation(), None);
+
+        // Round-tripping through the polar accessors
+        let rotation = Rot2::degrees(-135.0);
+        let data = DualAxisData::from_polar(rotation, 0.75);
+        assert!((data.length() - 0.75).abs() < 0.0001);
+        let angle = data.rotation().unwrap().angle_to(rotation);
+        assert!(angle.abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_dual_axis_data_projection() {
+        let mut diagonal = DualAxisData::default();
+        diagonal.set_pair(Vec2::new(0.6, 0.8));
+
+        let horizontal = diagonal.project_onto(Dir2::X);
+        assert_eq!(horizontal.pair, Vec2::new(0.6, 0.0));
+        assert!(horizontal.activation.pressed());
+
+        let reversed = diagonal.project_onto(Dir2::NEG_Y);
+        assert!((reversed.pair - Vec2::new(0.0, 0.8)).length() < 0.0001);
+
+        let mut vertical = DualAxisData::default();
+        vertical.set_pair(Vec2::Y);
+        let projected = vertical.project_onto(Dir2::X);
+        assert_eq!(projected.pair, Vec2::ZERO);
+        assert!(projected.activation.released());
+    }
+
+    #[test]
+    fn test_dual_axis_data_circle_deadzone() {
+        let mut small = DualAxisData::default();
+        small.set_pair(Vec2::new(0.06, 0.06));
+
+        let filtered = small.with_circle_deadzone(0.1);
+        assert_eq!(filtered.pair, Vec2::ZERO);
+        assert!(filtered.activation.released());
+
+        let mut large = DualAxisData::default();
+        large.set_pair(Vec2::new(0.2, 0.0));
+
+        let scaled = large.with_circle_deadzone(0.1);
+        assert_eq!(scaled.pair, Vec2::new(0.11111112, 0.0));
+        assert!(scaled.activation.pressed());
+        assert_eq!(large.with_circle_deadzone(0.0), large);
+    }
+
+    #[test]
+    fn test_dual_axis_data_octant() {
+        use bevy::math::CompassOctant::{self, *};
+
+        let octant = |x, y| {
+            DualAxisData {
+                pair: Vec2::new(x, y),
+                ..Default::default()
+            }
+            .octant()
+        };
+
+        // Neutral input has no octant
+        assert_eq!(octant(0.0, 0.0), None);
+        assert_eq!(octant(0.005, -0.005), None);
+
+        // Each compass direction, slightly to either side of its center
+        let centers: [(CompassOctant, f32); 8] = [
+            (East, 0.0),
+            (NorthEast, 45.0),
+            (North, 90.0),
+            (NorthWest, 135.0),
+            (West, 180.0),
+            (SouthWest, 225.0),
+            (South, 270.0),
+            (SouthEast, 315.0),
+        ];
+        for (expected, center) in centers {
+            for offset in [-22.0, 0.0, 22.0] {
+                let angle = (center + offset).to_radians();
+                let pair = Vec2::from_angle(angle) * 0.5;
+                assert_eq!(
+                    octant(pair.x, pair.y),
+                    Some(expected),
+                    "{center} + {offset}"
+                );
+            }
+        }
+
+        // Ties on the boundaries go to the cardinal direction
+        let slope = std::f32::consts::SQRT_2 - 1.0;
+        assert_eq!(octant(1.0, slope), Some(East));
+        assert_eq!(octant(slope, 1.0), Some(North));
+        assert_eq!(octant(-1.0, -slope), Some(West));
+        assert_eq!(octant(slope, -1.0), Some(South));
+        assert_eq!(octant(1.0, slope + 0.001), Some(NorthEast));
+        assert_eq!(octant(-slope - 0.001, -1.0), Some(SouthWest));
+    }
+
+    #[test]
+    fn test_dual_axis_data_is_neutral() {
+        let data = |x, y| DualAxisData {
+            pair: Vec2::new(x, y),
+            ..Default::default()
+        };
+
+        assert!(data(0.0, 0.0).is_neutral(DualAxisData::ACTIVATION_THRESHOLD));
+        assert!(data(0.0, 0.0).is_neutral(0.0));
+
+        // Just inside and just outside the threshold
+        assert!(data(0.06, 0.08).is_neutral(0.11));
+        assert!(!data(0.06, 0.08).is_neutral(0.09));
+        assert!(data(-0.099, 0.0).is_neutral(0.1));
+        assert!(!data(-0.101, 0.0).is_neutral(0.1));
+
+        // Neutral pairs have no direction with the default threshold
+        let drift = data(0.005, 0.005);
+        assert!(drift.is_neutral(DualAxisData::ACTIVATION_THRESHOLD));
+        assert_eq!(drift.direction(), None);
+    }
+}
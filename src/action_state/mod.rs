@@ -1,17 +1,16 @@
 //! This module contains [`ActionState`] and its supporting methods and impls.
 
+use crate::buttonlike::EdgeMode;
 use crate::input_map::UpdatedValue;
 use crate::{action_diff::ActionDiff, input_map::UpdatedActions};
 use crate::{Actionlike, InputControlKind};
 
-use bevy::prelude::Resource;
+use bevy::prelude::{Entity, Resource};
 use bevy::reflect::Reflect;
-#[cfg(feature = "timing")]
-use bevy::utils::Duration;
-use bevy::utils::{HashMap, Instant};
+use bevy::utils::{Duration, HashMap, Instant};
 use bevy::{ecs::component::Component, prelude::ReflectComponent};
 use bevy::{
-    math::{Vec2, Vec3},
+    math::{CompassOctant, Dir2, Rot2, Vec2, Vec3},
     prelude::ReflectResource,
 };
 use serde::{Deserialize, Serialize};
@@ -40,6 +39,19 @@ pub use action_data::*;
 /// but they are not reported to the user using standard methods like [`ActionState::pressed`].
 /// To check the underlying values, access their [`ActionData`] directly.
 ///
+/// # Querying actions
+///
+/// Every method takes actions by reference, so [`Actionlike`] types don't need to be [`Copy`].
+/// Actions carrying heavier data, such as `Cast(String)`, can be queried without being cloned.
+///
+/// # Serialization
+///
+/// When `A` implements [`Serialize`] and [`Deserialize`], so does the [`ActionState`],
+/// which is handy for debugging tools and replays.
+/// The state, value and axis data of each action are captured,
+/// along with how long buttons have been held and how much of their cooldown remains.
+/// [`Instant`]s are machine-specific, so they are skipped and reset to [`None`] when deserializing.
+///
 /// # Example
 ///
 /// ```rust
@@ -82,13 +94,33 @@ pub use action_data::*;
 /// assert!(action_state.released(&Action::Jump));
 /// assert!(!action_state.just_released(&Action::Jump));
 /// ```
-#[derive(Resource, Component, Clone, Debug, PartialEq, Serialize, Deserialize, Reflect)]
+#[derive(Resource, Component, Clone, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(Resource, Component)]
 pub struct ActionState<A: Actionlike> {
     /// Whether or not all of the actions are disabled.
     disabled: bool,
     /// The shared action data for each action
     action_data: HashMap<A, ActionData>,
+    /// The [`Instant`] at which the frame covered by the latest [`ActionState::tick`] started.
+    #[cfg(feature = "timing")]
+    #[serde(skip)]
+    frame_started: Option<Instant>,
+    /// The time elapsed between the two [`Instant`]s passed to the latest [`ActionState::tick`].
+    #[serde(skip)]
+    last_tick_duration: Duration,
+    /// The `last_tick_duration` of the `Update` schedule, stored while in the `FixedMain` schedule.
+    #[serde(skip)]
+    update_tick_duration: Duration,
+    /// The `last_tick_duration` of the `FixedMain` schedule, stored while in the `Update` schedule.
+    #[serde(skip)]
+    fixed_update_tick_duration: Duration,
+}
+
+// The timing fields aren't serialized, so they are left out of comparisons
+impl<A: Actionlike> PartialEq for ActionState<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.disabled == other.disabled && self.action_data == other.action_data
+    }
 }
 
 // The derive does not work unless A: Default,
@@ -98,6 +130,11 @@ impl<A: Actionlike> Default for ActionState<A> {
         Self {
             disabled: false,
             action_data: HashMap::default(),
+            #[cfg(feature = "timing")]
+            frame_started: None,
+            last_tick_duration: Duration::ZERO,
+            update_tick_duration: Duration::ZERO,
+            fixed_update_tick_duration: Duration::ZERO,
         }
     }
 }
@@ -117,6 +154,9 @@ impl<A: Actionlike> ActionState<A> {
         for action_datum in self.action_data.values_mut() {
             action_datum.kind_data.swap_to_update_state();
         }
+
+        self.fixed_update_tick_duration = self.last_tick_duration;
+        self.last_tick_duration = self.update_tick_duration;
     }
 
     /// We are about to enter the `FixedMain` schedule, so we:
@@ -126,6 +166,9 @@ impl<A: Actionlike> ActionState<A> {
         for action_datum in self.action_data.values_mut() {
             action_datum.kind_data.swap_to_fixed_update_state();
         }
+
+        self.update_tick_duration = self.last_tick_duration;
+        self.last_tick_duration = self.fixed_update_tick_duration;
     }
 
     /// Updates the [`ActionState`] based on the provided [`UpdatedActions`].
@@ -136,24 +179,87 @@ impl<A: Actionlike> ActionState<A> {
     /// Actions that are disabled will still be updated: instead, their values will be read as released / zero.
     /// You can see their underlying values by checking their [`ActionData`] directly.
     pub fn update(&mut self, updated_actions: UpdatedActions<A>) {
+        self.update_with_bindings(updated_actions, &HashMap::default());
+    }
+
+    /// Updates the [`ActionState`] like [`ActionState::update`],
+    /// recording the index of the binding that pressed each buttonlike action from `triggering_bindings`.
+    pub(crate) fn update_with_bindings(
+        &mut self,
+        updated_actions: UpdatedActions<A>,
+        triggering_bindings: &HashMap<A, usize>,
+    ) {
         for (action, updated_value) in updated_actions.iter() {
-            match updated_value {
-                UpdatedValue::Button(pressed) => {
-                    if *pressed {
-                        self.press(action);
-                    } else {
-                        self.release(action);
-                    }
-                }
-                UpdatedValue::Axis(value) => {
-                    self.set_value(action, *value);
-                }
-                UpdatedValue::DualAxis(pair) => {
-                    self.set_axis_pair(action, *pair);
-                }
-                UpdatedValue::TripleAxis(triple) => {
-                    self.set_axis_triple(action, *triple);
+            let triggering_binding = triggering_bindings.get(action).copied();
+            self.update_action(action, updated_value, triggering_binding);
+        }
+    }
+
+    /// Updates the [`ActionState`] like [`ActionState::update`],
+    /// appending every [`Buttonlike`](crate::user_input::Buttonlike) action whose pressed state changed
+    /// (that is, which was newly pressed or newly released) to `changed_actions`.
+    ///
+    /// This allows event-driven systems to react to changes without scanning every action.
+    /// The buffer is not cleared beforehand, so it can be reused across frames.
+    pub fn update_with_changes(
+        &mut self,
+        updated_actions: UpdatedActions<A>,
+        changed_actions: &mut Vec<A>,
+    ) {
+        let button_pressed = |action_state: &Self, action: &A| {
+            action_state
+                .button_data(action)
+                .is_some_and(ButtonData::pressed)
+        };
+
+        for (action, updated_value) in updated_actions.iter() {
+            let was_pressed = button_pressed(self, action);
+            self.update_action(action, updated_value, None);
+
+            if matches!(updated_value, UpdatedValue::Button(_))
+                && was_pressed != button_pressed(self, action)
+            {
+                changed_actions.push(action.clone());
+            }
+        }
+    }
+
+    /// Updates a single `action` based on its `updated_value`,
+    /// recording the index of the `triggering_binding` of buttonlike actions.
+    fn update_action(
+        &mut self,
+        action: &A,
+        updated_value: &UpdatedValue,
+        triggering_binding: Option<usize>,
+    ) {
+        match updated_value {
+            UpdatedValue::Button(input_pressed) => {
+                let button_data = self.button_data_mut_or_default(action);
+                button_data.triggering_binding_index = triggering_binding;
+                let pressed = button_data.apply_edge_mode(*input_pressed);
+
+                if pressed {
+                    self.press(action);
+                } else if self
+                    .button_data(action)
+                    .is_some_and(|button_data| button_data.persistent)
+                {
+                    // Persistent presses are only cleared by an explicit release
+                    return;
+                } else {
+                    self.release(action);
                 }
+
+                self.button_data_mut_or_default(action).set_directly = false;
+            }
+            UpdatedValue::Axis(value) => {
+                self.set_value(action, *value);
+            }
+            UpdatedValue::DualAxis(pair) => {
+                self.set_axis_pair(action, *pair);
+            }
+            UpdatedValue::TripleAxis(triple) => {
+                self.set_axis_triple(action, *triple);
             }
         }
     }
@@ -204,12 +310,47 @@ impl<A: Actionlike> ActionState<A> {
     /// assert!(!action_state.just_pressed(&Action::Jump));
     /// ```
     pub fn tick(&mut self, _current_instant: Instant, _previous_instant: Instant) {
+        #[cfg(feature = "timing")]
+        {
+            self.frame_started = Some(_previous_instant);
+        }
+        self.last_tick_duration = _current_instant.saturating_duration_since(_previous_instant);
+
         // Advanced the action states
         self.action_data
             .values_mut()
             .for_each(|action_datum| action_datum.tick(_current_instant, _previous_instant));
     }
 
+    /// Excludes the given `duration` from the timers of all actions, as if it never passed.
+    ///
+    /// Held actions keep the duration for which they have been pressed, and running [`Cooldown`]s keep their remaining time,
+    /// which is useful to freeze actions while the game is paused.
+    /// This is done automatically while the [run conditions](crate::plugin::InputManagerPlugin::run_if) of the plugin are not met.
+    ///
+    /// Button states are not advanced either, so actions that were just pressed or released
+    /// keep reporting it on every skipped frame, until the next [`ActionState::tick`].
+    pub fn skip(&mut self, duration: Duration) {
+        self.action_data
+            .values_mut()
+            .for_each(|action_datum| action_datum.skip(duration));
+    }
+
+    /// Advances the time for all actions by a fixed number of `steps`,
+    /// transitioning them like [`ActionState::tick`] without reading the wall clock.
+    ///
+    /// If the `timing` feature flag is enabled, the time for which buttons have been held or released
+    /// is counted in steps, and can be read with [`ActionState::current_ticks`] and [`ActionState::previous_ticks`].
+    /// As these are integers, they stay in sync across machines,
+    /// which makes this suitable for lockstep networking and replays.
+    ///
+    /// The [`Duration`]s and [`Cooldown`]s measured by [`ActionState::tick`] are not advanced.
+    pub fn tick_fixed(&mut self, steps: u32) {
+        self.action_data
+            .values_mut()
+            .for_each(|action_datum| action_datum.tick_fixed(steps));
+    }
+
     /// A reference to the [`ActionData`] corresponding to the `action`.
     #[inline]
     #[must_use]
@@ -571,6 +712,8 @@ impl<A: Actionlike> ActionState<A> {
         let button_data = self.button_data_mut_or_default(action);
         button_data.value = value;
 
+        button_data.set_directly = value > 0.0;
+
         if value > 0.0 {
             #[cfg(feature = "timing")]
             if button_data.state.released() {
@@ -667,13 +810,104 @@ impl<A: Actionlike> ActionState<A> {
         action_data.map_or(Vec2::ZERO, |action_data| action_data.pair)
     }
 
+    /// Get the [`DualAxisData`] of each of the `actions`, in the same order.
+    ///
+    /// This is a shorthand for reading several dual-axis actions at once, such as in a movement system.
+    /// Actions that have never been triggered or that are disabled report a default [`DualAxisData`].
+    #[must_use]
+    #[track_caller]
+    pub fn axis_pairs(&self, actions: &[A]) -> Vec<DualAxisData> {
+        actions
+            .iter()
+            .map(|action| {
+                debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+                if self.action_disabled(action) {
+                    return DualAxisData::default();
+                }
+
+                self.dual_axis_data(action).cloned().unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Returns the [`Rot2`] from the positive X axis to the direction of the dual-axis `action`,
+    /// or [`None`] if it is neutral or disabled.
+    ///
+    /// This is handy for twin-stick aiming, where a stick sets the facing of the player,
+    /// which should be kept while the stick is released.
+    /// See [`DualAxisData::rotation`] for more details.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Aim,
+    /// }
+    ///
+    /// fn face_aim(action_state: Res<ActionState<Action>>, mut transform: Single<&mut Transform>) {
+    ///     if let Some(rotation) = action_state.aim_rotation(&Action::Aim) {
+    ///         transform.rotation = Quat::from_rotation_z(rotation.as_radians());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn aim_rotation(&self, action: &A) -> Option<Rot2> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+        if self.action_disabled(action) {
+            return None;
+        }
+
+        self.dual_axis_data(action)?.rotation()
+    }
+
     /// Sets the [`Vec2`] of the `action` to the provided `pair`.
     #[track_caller]
     pub fn set_axis_pair(&mut self, action: &A, pair: Vec2) {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
 
         let dual_axis_data = self.dual_axis_data_mut_or_default(action);
-        dual_axis_data.pair = pair;
+        dual_axis_data.set_pair(pair);
+    }
+
+    /// Did the axis pair of the `action` leave neutral since the last time [tick](ActionState::tick) was called?
+    ///
+    /// The pair is considered neutral while its length is at most [`DualAxisData::ACTIVATION_THRESHOLD`].
+    /// This is useful for one-off reactions, such as playing a sound when the player first moves a stick.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn axis_just_activated(&self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+        if self.action_disabled(action) {
+            return false;
+        }
+
+        self.dual_axis_data(action)
+            .is_some_and(|data| data.activation.just_pressed())
+    }
+
+    /// Did the axis pair of the `action` return to neutral since the last time [tick](ActionState::tick) was called?
+    ///
+    /// The pair is considered neutral while its length is at most [`DualAxisData::ACTIVATION_THRESHOLD`].
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn axis_just_deactivated(&self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+        if self.action_disabled(action) {
+            return false;
+        }
+
+        self.dual_axis_data(action)
+            .is_some_and(|data| data.activation.just_released())
     }
 
     /// Get the [`Vec2`] associated with the corresponding `action`, clamped to `[-1.0, 1.0]`.
@@ -769,15 +1003,15 @@ impl<A: Actionlike> ActionState<A> {
     /// // And transfer it to the actual ability that we care about
     /// // without losing timing information
     /// if let Some(state) = slot_1_state {
-    ///    action_state.set_button_data(Action::Run, state.clone());
+    ///    action_state.set_button_data(&Action::Run, state.clone());
     /// }
     /// ```
     #[inline]
     #[track_caller]
-    pub fn set_button_data(&mut self, action: A, data: ButtonData) {
+    pub fn set_button_data(&mut self, action: &A, data: ButtonData) {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        let button_data = self.button_data_mut_or_default(&action);
+        let button_data = self.button_data_mut_or_default(action);
         *button_data = data;
     }
 
@@ -785,6 +1019,12 @@ impl<A: Actionlike> ActionState<A> {
     ///
     /// No initial instant or reasons why the button was pressed will be recorded.
     /// Instead, this is set through [`ActionState::tick()`]
+    ///
+    /// The press takes effect immediately, so [`ActionState::just_pressed`] is `true` this frame.
+    /// It lasts until the next time the `action` is updated from real input via [`ActionState::update`]:
+    /// if the `action` is mapped in the [`InputMap`](crate::input_map::InputMap) and its inputs are not held,
+    /// it will be released then.
+    /// Use [`ActionState::press_persistent`] if the press should survive those updates.
     #[inline]
     #[track_caller]
     pub fn press(&mut self, action: &A) {
@@ -797,14 +1037,157 @@ impl<A: Actionlike> ActionState<A> {
             action_data.timing.flip();
         }
 
+        if action_data.state.released() {
+            action_data.cooldown.trigger();
+        }
+
         action_data.state.press();
         action_data.value = 1.0;
+
+        action_data.set_directly = true;
+    }
+
+    /// Press the `action`, keeping it pressed across input updates until it is explicitly
+    /// released via [`ActionState::release`] (or [`ActionState::reset`]).
+    ///
+    /// Unlike [`ActionState::press`], real input released in [`ActionState::update`] cannot release the `action`.
+    /// This is useful for scripted sequences and tests.
+    #[inline]
+    #[track_caller]
+    pub fn press_persistent(&mut self, action: &A) {
+        self.press(action);
+        self.button_data_mut_or_default(action).persistent = true;
+    }
+
+    /// Sets the [`Cooldown`] of the `action` to `duration`.
+    ///
+    /// After the `action` fires, further presses will not be reported as
+    /// [`just_pressed`](ActionState::just_pressed) until the cooldown has elapsed,
+    /// although [`pressed`](ActionState::pressed) still reflects the underlying input.
+    /// Setting a cooldown of [`Duration::ZERO`] disables it.
+    ///
+    /// Cooldowns configured on an [`InputMap`](crate::input_map::InputMap) via
+    /// [`InputMap::set_cooldown`](crate::input_map::InputMap::set_cooldown) are applied automatically.
+    #[inline]
+    #[track_caller]
+    pub fn set_cooldown(&mut self, action: &A, duration: Duration) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        let cooldown = &mut self.button_data_mut_or_default(action).cooldown;
+        cooldown.duration = duration;
+        cooldown.remaining = cooldown.remaining.min(duration);
+    }
+
+    /// Sets the [`EdgeMode`] of the `action`, controlling when it fires as its inputs change.
+    ///
+    /// Edge modes configured on an [`InputMap`](crate::input_map::InputMap) via
+    /// [`InputMap::set_edge_mode`](crate::input_map::InputMap::set_edge_mode) are applied automatically.
+    #[inline]
+    #[track_caller]
+    pub fn set_edge_mode(&mut self, action: &A, edge_mode: EdgeMode) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).edge_mode = edge_mode;
+    }
+
+    /// The [`Duration`] left before the `action` can be fired again.
+    ///
+    /// This is [`Duration::ZERO`] if the `action` is not on cooldown, which is useful for UI.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn cooldown_remaining(&self, action: &A) -> Duration {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|button_data| button_data.cooldown.remaining)
+            .unwrap_or_default()
+    }
+
+    /// The time elapsed between the two [`Instant`]s passed to the latest [`ActionState::tick`],
+    /// by which cooldowns and hold durations were last advanced.
+    ///
+    /// This lets other systems, such as UI drawing cooldown bars,
+    /// interpolate consistently with the internal timing.
+    /// This is [`Duration::ZERO`] before the first tick, and is unaffected by [`ActionState::tick_fixed`].
+    /// Like the action data, it is tracked separately for the `Update` and `FixedMain` schedules.
+    #[inline]
+    #[must_use]
+    pub fn last_tick_duration(&self) -> Duration {
+        self.last_tick_duration
+    }
+
+    /// Records the gamepad [`Entity`] that is currently pressing the `action`.
+    ///
+    /// For actions configured via [`InputMap::set_any_gamepad`](crate::input_map::InputMap::set_any_gamepad),
+    /// this is updated automatically.
+    #[inline]
+    #[track_caller]
+    pub fn set_triggering_gamepad(&mut self, action: &A, gamepad: Option<Entity>) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).triggering_gamepad = gamepad;
+    }
+
+    /// The gamepad [`Entity`] that is currently pressing the `action`, if any.
+    ///
+    /// This is only tracked for actions configured via [`InputMap::set_any_gamepad`](crate::input_map::InputMap::set_any_gamepad),
+    /// and is [`None`] if the `action` is released or pressed by a non-gamepad input.
+    /// This is useful for "press Start to join" screens, to find out which gamepad should be assigned to the new player.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn triggering_gamepad(&self, action: &A) -> Option<Entity> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        if self.action_disabled(action) {
+            return None;
+        }
+
+        self.button_data(action)
+            .and_then(|button_data| button_data.triggering_gamepad)
+    }
+
+    /// Records the index of the binding in the [`InputMap`](crate::input_map::InputMap) that is currently pressing the `action`.
+    ///
+    /// This is updated automatically for every [`Buttonlike`](crate::user_input::Buttonlike) action in the [`InputMap`](crate::input_map::InputMap).
+    #[inline]
+    #[track_caller]
+    pub fn set_triggering_binding_index(&mut self, action: &A, index: Option<usize>) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action)
+            .triggering_binding_index = index;
+    }
+
+    /// The index of the first binding of the `action` that is currently pressing it, if any.
+    ///
+    /// Bindings are counted in the order they were inserted into the [`InputMap`](crate::input_map::InputMap),
+    /// as returned by [`InputMap::get_buttonlike`](crate::input_map::InputMap::get_buttonlike),
+    /// so the primary binding is `0` and the secondary one is `1`.
+    /// This is useful for highlighting the binding slot that fired in the UI.
+    ///
+    /// This is [`None`] if the `action` is released, or was pressed directly through [`ActionState::press`].
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn triggering_binding_index(&self, action: &A) -> Option<usize> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        if !self.pressed(action) {
+            return None;
+        }
+
+        self.button_data(action)
+            .and_then(|button_data| button_data.triggering_binding_index)
     }
 
     /// Release the `action`
     ///
     /// No initial instant will be recorded.
     /// Instead, this is set through [`ActionState::tick()`]
+    ///
+    /// This also clears any persistent press set by [`ActionState::press_persistent`].
     #[inline]
     pub fn release(&mut self, action: &A) {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
@@ -818,6 +1201,88 @@ impl<A: Actionlike> ActionState<A> {
 
         action_data.state.release();
         action_data.value = 0.0;
+        action_data.persistent = false;
+
+        action_data.set_directly = false;
+    }
+
+    /// Consumes the `action`, so that it is no longer [`just_pressed`](ActionState::just_pressed)
+    /// for the rest of the frame.
+    ///
+    /// Returns `true` if the `action` was just pressed before being consumed.
+    ///
+    /// This is useful when several systems react to the same action,
+    /// but only the first of them should handle the press:
+    /// for example, a menu that closes on `Jump` should stop the player from also jumping.
+    /// Order the consuming system before the others, and they will see the `action` as not just pressed.
+    ///
+    /// Unlike [`ActionState::release`], this does not affect [`pressed`](ActionState::pressed):
+    /// the `action` stays pressed as long as its inputs are held,
+    /// and it won't be just pressed again until it is released and pressed anew.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(&Action::Jump);
+    ///
+    /// assert!(action_state.consume(&Action::Jump));
+    /// assert!(!action_state.just_pressed(&Action::Jump));
+    /// assert!(action_state.pressed(&Action::Jump));
+    ///
+    /// // Each press can only be consumed once
+    /// assert!(!action_state.consume(&Action::Jump));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn consume(&mut self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        let just_pressed = self.just_pressed(action);
+        self.clear_just_pressed(action);
+        just_pressed
+    }
+
+    /// Clears the [`just_pressed`](ActionState::just_pressed) edge of the `action`, leaving everything else untouched.
+    ///
+    /// If the `action` was just pressed, it stays [`pressed`](ActionState::pressed), as if it had been ticked.
+    /// Otherwise, this does nothing: in particular, a [`just_released`](ActionState::just_released) edge is kept.
+    /// Other actions and the hold duration of the `action` are not affected.
+    ///
+    /// This is the same as [`ActionState::consume`], without reporting whether there was a press to consume.
+    #[inline]
+    #[track_caller]
+    pub fn clear_just_pressed(&mut self, action: &A) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        if let Some(button_data) = self.button_data_mut(action) {
+            if button_data.state.just_pressed() {
+                button_data.state.tick();
+            }
+        }
+    }
+
+    /// Clears the [`just_released`](ActionState::just_released) edge of the `action`, leaving everything else untouched.
+    ///
+    /// If the `action` was just released, it stays [`released`](ActionState::released), as if it had been ticked.
+    /// Otherwise, this does nothing: in particular, a [`just_pressed`](ActionState::just_pressed) edge is kept.
+    /// Other actions are not affected.
+    #[inline]
+    #[track_caller]
+    pub fn clear_just_released(&mut self, action: &A) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        if let Some(button_data) = self.button_data_mut(action) {
+            if button_data.state.just_released() {
+                button_data.state.tick();
+            }
+        }
     }
 
     /// Resets an action to its default state.
@@ -850,6 +1315,91 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Releases every [`Buttonlike`](crate::user_input::Buttonlike) action that is currently pressed,
+    /// as if the player let go of everything at once.
+    ///
+    /// Each of these actions will be [`just_released`](ActionState::just_released) until the next tick,
+    /// just like after calling [`ActionState::release`] on them individually.
+    /// Unlike [`ActionState::reset_all`], actions that are already released are left as is,
+    /// so they don't fire [`just_released`](ActionState::just_released) again,
+    /// and axislike actions keep their values.
+    pub fn release_all(&mut self) {
+        // Collect out to avoid angering the borrow checker
+        let pressed_actions = self
+            .action_data
+            .iter()
+            .filter(|(_, action_data)| match &action_data.kind_data {
+                ActionKindData::Button(button_data) => button_data.state.pressed(),
+                _ => false,
+            })
+            .map(|(action, _)| action.clone())
+            .collect::<Vec<A>>();
+
+        for action in pressed_actions {
+            self.release(&action);
+        }
+    }
+
+    /// Presses the buttonlike action matching the eight-way direction of `axis_pair`,
+    /// releasing the actions for all other directions.
+    ///
+    /// The `direction_action` function maps each [`CompassOctant`] to its action,
+    /// and `axis_pair` is snapped to the nearest of the eight directions.
+    /// If the length of `axis_pair` is less than or equal to `deadzone`,
+    /// all directional actions are released.
+    ///
+    /// This is useful for driving discrete, grid-based movement from an analog stick,
+    /// typically using the value of a [`DualAxislike`](crate::user_input::DualAxislike) action.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy::math::CompassOctant;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Step {
+    ///     Direction(CompassOctant),
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Step>::default();
+    /// action_state.set_directional(Step::Direction, Vec2::new(0.7, 0.7), 0.1);
+    ///
+    /// assert!(action_state.pressed(&Step::Direction(CompassOctant::NorthEast)));
+    /// assert!(action_state.released(&Step::Direction(CompassOctant::North)));
+    /// ```
+    pub fn set_directional(
+        &mut self,
+        direction_action: impl Fn(CompassOctant) -> A,
+        axis_pair: Vec2,
+        deadzone: f32,
+    ) {
+        let active_octant = Dir2::new(axis_pair)
+            .ok()
+            .filter(|_| axis_pair.length() > deadzone)
+            .map(CompassOctant::from);
+
+        for octant in [
+            CompassOctant::North,
+            CompassOctant::NorthEast,
+            CompassOctant::East,
+            CompassOctant::SouthEast,
+            CompassOctant::South,
+            CompassOctant::SouthWest,
+            CompassOctant::West,
+            CompassOctant::NorthWest,
+        ] {
+            let action = direction_action(octant);
+            if Some(octant) == active_octant {
+                if !self.button_data(&action).is_some_and(ButtonData::pressed) {
+                    self.press(&action);
+                }
+            } else if !self.button_data(&action).is_some_and(ButtonData::released) {
+                self.release(&action);
+            }
+        }
+    }
+
     /// Is the entire [`ActionState`] currently disabled?
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -941,16 +1491,19 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
-    /// Was this `action` pressed since the last time [tick](ActionState::tick) was called?
+    /// Is this `action` currently pressed because it was set directly on this [`ActionState`]
+    /// (e.g. via [`ActionState::press`] or [`ActionState::set_button_value`]),
+    /// rather than by input passed through [`ActionState::update`]?
     ///
-    /// # Warning
-    ///
-    /// This value will be `false` by default,
-    /// even if the action is not a buttonlike action.
+    /// This is useful in tests to check that an action fired due to a direct write,
+    /// and not because of input leaking in.
+    /// Note that inputs mocked on the [`World`](bevy::prelude::World), such as with [`Buttonlike::press`](crate::user_input::Buttonlike::press),
+    /// are read like real input, so actions pressed by them are not considered pressed directly.
+    /// Mocked and real input can't be told apart, as both are read from the same input resources.
     #[inline]
     #[must_use]
     #[track_caller]
-    pub fn just_pressed(&self, action: &A) -> bool {
+    pub fn pressed_directly(&self, action: &A) -> bool {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
         if self.action_disabled(action) {
@@ -958,12 +1511,36 @@ impl<A: Actionlike> ActionState<A> {
         }
 
         match self.button_data(action) {
-            Some(button_data) => button_data.just_pressed(),
+            Some(button_data) => button_data.pressed() && button_data.set_directly,
             None => false,
         }
     }
 
-    /// Is this `action` currently released?
+    /// Was this `action` pressed since the last time [tick](ActionState::tick) was called?
+    ///
+    /// This will be `false` for the rest of the frame once the `action` has been [consumed](ActionState::consume).
+    ///
+    /// # Warning
+    ///
+    /// This value will be `false` by default,
+    /// even if the action is not a buttonlike action.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn just_pressed(&self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        if self.action_disabled(action) {
+            return false;
+        }
+
+        match self.button_data(action) {
+            Some(button_data) => button_data.just_pressed(),
+            None => false,
+        }
+    }
+
+    /// Is this `action` currently released?
     ///
     /// This is always the logical negation of [pressed](ActionState::pressed)
     ///
@@ -1021,6 +1598,40 @@ impl<A: Actionlike> ActionState<A> {
             .collect()
     }
 
+    #[must_use]
+    /// Is any buttonlike action currently pressed?
+    ///
+    /// Unlike [`ActionState::get_pressed`], this does not allocate.
+    /// To check for any raw input regardless of the bound actions,
+    /// use [`CentralInputStore::any_input_active`](crate::user_input::updating::CentralInputStore::any_input_active).
+    pub fn any_action_pressed(&self) -> bool {
+        self.action_data
+            .keys()
+            .filter(|action| action.input_control_kind() == InputControlKind::Button)
+            .any(|action| self.pressed(action))
+    }
+
+    /// Is any of the given `actions` currently pressed?
+    ///
+    /// This is handy for grouping actions, such as checking for any movement input.
+    /// Returns `false` if `actions` is empty.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn any_pressed(&self, actions: &[A]) -> bool {
+        actions.iter().any(|action| self.pressed(action))
+    }
+
+    /// Are all of the given `actions` currently pressed?
+    ///
+    /// Returns `true` if `actions` is empty.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn all_pressed(&self, actions: &[A]) -> bool {
+        actions.iter().all(|action| self.pressed(action))
+    }
+
     #[must_use]
     /// Which actions were just pressed?
     pub fn get_just_pressed(&self) -> Vec<A> {
@@ -1108,6 +1719,148 @@ impl<A: Actionlike> ActionState<A> {
             .unwrap_or_default()
     }
 
+    /// The number of fixed steps for which the action has been held or released,
+    /// as counted by [`ActionState::tick_fixed`]
+    ///
+    /// This will be `0` if the action was never pressed or released.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn current_ticks(&self, action: &A) -> u32 {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|data| data.timing.current_ticks)
+            .unwrap_or_default()
+    }
+
+    /// The number of fixed steps for which the action was last held or released
+    ///
+    /// This is a snapshot of the [`ActionState::current_ticks`] state at the time
+    /// the action was last pressed or released.
+    ///
+    /// This will be `0` if the action was never pressed or released.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn previous_ticks(&self, action: &A) -> u32 {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|data| data.timing.previous_ticks)
+            .unwrap_or_default()
+    }
+
+    /// How charged up the `action` is, as the ratio of [`ActionState::current_duration`] to `max_duration`,
+    /// clamped to `[0.0, 1.0]`.
+    ///
+    /// This is handy for charged attacks and other hold-to-power-up mechanics.
+    /// Actions that are not currently pressed have no charge.
+    /// If `max_duration` is zero, any pressed action is fully charged.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn charge(&self, action: &A, max_duration: Duration) -> f32 {
+        if !self.pressed(action) {
+            return 0.0;
+        }
+
+        if max_duration.is_zero() {
+            return 1.0;
+        }
+
+        let current_duration = self.current_duration(action);
+        (current_duration.as_secs_f32() / max_duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Was the `action` just released after being held for less than `max_tap_duration`?
+    ///
+    /// This is only `true` on the frame of release, and compares the [`ActionState::previous_duration`]
+    /// of the press against `max_tap_duration`.
+    /// This makes it easy to tell taps apart from holds, such as tapping to jump and holding to glide.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn was_tap(&self, action: &A, max_tap_duration: Duration) -> bool {
+        self.just_released(action) && self.previous_duration(action) < max_tap_duration
+    }
+
+    /// Did the `action` just cross the `threshold` while being held?
+    ///
+    /// Unlike checking [`ActionState::current_duration`] directly, this is only `true` for the single
+    /// [`ActionState::tick`] during which the hold duration reached the `threshold`,
+    /// making it easy to fire one-off long press events, such as opening a context menu.
+    /// A `threshold` of zero behaves like [`ActionState::just_pressed`].
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn just_long_pressed(&self, action: &A, threshold: Duration) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        let Some(button_data) = self.button_data(action) else {
+            return false;
+        };
+
+        if !button_data.state.pressed() {
+            return false;
+        }
+
+        // The action hasn't been ticked since it was pressed
+        let Some(instant_started) = button_data.timing.instant_started else {
+            return threshold.is_zero();
+        };
+
+        let Some(frame_started) = self.frame_started else {
+            return false;
+        };
+
+        // How long the action had been held as of the previous tick
+        let previous_hold = frame_started.saturating_duration_since(instant_started);
+        previous_hold < threshold && threshold <= button_data.timing.current_duration
+    }
+
+    /// Records the `timestamp` of the input event that pressed the `action`,
+    /// so that [`ActionState::press_offset`] can report when the press happened within its frame.
+    ///
+    /// Bevy's input events do not carry timestamps,
+    /// so this should be called with timestamps from your own event source, after the `action` is pressed.
+    /// The offset is measured from the start of the frame covered by the latest [`ActionState::tick`],
+    /// during which the input event is expected to have happened,
+    /// and is [`Duration::ZERO`] for earlier timestamps.
+    ///
+    /// Timestamps for actions that are not currently pressed are ignored,
+    /// as are those recorded before [`ActionState::tick`] was ever called.
+    #[cfg(feature = "timing")]
+    #[track_caller]
+    pub fn set_press_timestamp(&mut self, action: &A, timestamp: Instant) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        let Some(frame_started) = self.frame_started else {
+            return;
+        };
+
+        if let Some(button_data) = self.button_data_mut(action) {
+            if button_data.state.pressed() {
+                button_data.timing.press_offset =
+                    Some(timestamp.saturating_duration_since(frame_started));
+            }
+        }
+    }
+
+    /// How long after the start of its frame the `action` was pressed,
+    /// based on the timestamp recorded with [`ActionState::set_press_timestamp`].
+    ///
+    /// This is handy for rhythm games, which need to judge presses more precisely than the frame rate.
+    /// This will be [`None`] if the action is not pressed, or no timestamp was recorded for the current press.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn press_offset(&self, action: &A) -> Option<Duration> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)?.timing.press_offset
+    }
+
     /// Applies an [`ActionDiff`] (usually received over the network) to the [`ActionState`].
     ///
     /// This lets you reconstruct an [`ActionState`] from a stream of [`ActionDiff`]s
@@ -1263,6 +2016,597 @@ mod tests {
         assert!(!action_state.just_released(&Action::Two));
     }
 
+    #[test]
+    fn any_action_pressed() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            One,
+            Two,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        assert!(!action_state.any_action_pressed());
+
+        action_state.press(&Action::Two);
+        assert!(action_state.any_action_pressed());
+
+        action_state.release(&Action::Two);
+        assert!(!action_state.any_action_pressed());
+    }
+
+    #[test]
+    fn any_and_all_pressed() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Up,
+            Down,
+            Left,
+            Right,
+        }
+
+        let movement = [Action::Up, Action::Down, Action::Left];
+        let mut action_state = ActionState::<Action>::default();
+        assert!(!action_state.any_pressed(&movement));
+        assert!(!action_state.all_pressed(&movement));
+
+        // Partially pressed
+        action_state.press(&Action::Left);
+        action_state.press(&Action::Right);
+        assert!(action_state.any_pressed(&movement));
+        assert!(!action_state.all_pressed(&movement));
+
+        // Fully pressed
+        action_state.press(&Action::Up);
+        action_state.press(&Action::Down);
+        assert!(action_state.any_pressed(&movement));
+        assert!(action_state.all_pressed(&movement));
+
+        // Disabled actions don't count as pressed
+        action_state.disable_action(&Action::Down);
+        assert!(action_state.any_pressed(&movement));
+        assert!(!action_state.all_pressed(&movement));
+
+        // Empty groups
+        assert!(!action_state.any_pressed(&[]));
+        assert!(action_state.all_pressed(&[]));
+    }
+
+    #[test]
+    fn release_all() {
+        use bevy::utils::{Duration, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Run,
+            Crouch,
+            #[actionlike(Axis)]
+            Throttle,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.press_persistent(&Action::Run);
+        action_state.release(&Action::Crouch);
+        action_state.set_value(&Action::Throttle, 0.5);
+
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_millis(100);
+        action_state.tick(t1, t0);
+
+        action_state.release_all();
+        assert!(action_state.just_released(&Action::Jump));
+        assert!(action_state.just_released(&Action::Run));
+        assert!(!action_state.just_released(&Action::Crouch));
+        assert!(action_state.released(&Action::Crouch));
+        assert_eq!(action_state.value(&Action::Throttle), 0.5);
+
+        // Releasing again in the same frame doesn't change anything
+        action_state.release_all();
+        assert_eq!(action_state.get_just_released().len(), 2);
+
+        // The release is only reported once
+        let t2 = t1 + Duration::from_millis(100);
+        action_state.tick(t2, t1);
+        action_state.release_all();
+        assert!(action_state.get_just_released().is_empty());
+        assert!(action_state.released(&Action::Jump));
+        assert!(action_state.released(&Action::Run));
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{Duration, HashMap};
+        use serde::{Deserialize, Serialize};
+
+        #[derive(
+            Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect, Serialize, Deserialize,
+        )]
+        enum Action {
+            Jump,
+            Fireball,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_cooldown(&Action::Fireball, Duration::from_secs(2));
+        action_state.update(UpdatedActions(HashMap::from_iter([
+            (Action::Jump, UpdatedValue::Button(true)),
+            (Action::Fireball, UpdatedValue::Button(false)),
+            (Action::Zoom, UpdatedValue::Axis(-0.5)),
+            (Action::Move, UpdatedValue::DualAxis(Vec2::new(0.25, 1.0))),
+            (
+                Action::Fly,
+                UpdatedValue::TripleAxis(Vec3::new(1.0, 0.0, -2.0)),
+            ),
+        ])));
+        action_state.disable_action(&Action::Fireball);
+
+        let serialized = serde_json::to_string(&action_state).unwrap();
+        let deserialized: ActionState<Action> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, action_state);
+        assert!(deserialized.pressed(&Action::Jump));
+        assert_eq!(deserialized.value(&Action::Zoom), -0.5);
+        assert_eq!(deserialized.axis_pair(&Action::Move), Vec2::new(0.25, 1.0));
+        assert_eq!(
+            deserialized.axis_triple(&Action::Fly),
+            Vec3::new(1.0, 0.0, -2.0)
+        );
+        assert!(deserialized.action_disabled(&Action::Fireball));
+    }
+
+    #[test]
+    fn deserialize_earlier_format() {
+        use bevy::utils::Duration;
+        use serde::Deserialize;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect, Deserialize)]
+        enum Action {
+            Jump,
+            #[actionlike(DualAxis)]
+            Move,
+        }
+
+        // Action states saved before fields such as cooldowns were added can still be loaded
+        let saved = r#"{
+            "disabled": false,
+            "action_data": {
+                "Jump": {
+                    "disabled": false,
+                    "kind_data": {
+                        "Button": {
+                            "state": "JustPressed",
+                            "update_state": "Released",
+                            "fixed_update_state": "Released",
+                            "value": 1.0,
+                            "update_value": 0.0,
+                            "fixed_update_value": 0.0,
+                            "timing": {
+                                "current_duration": { "secs": 0, "nanos": 0 },
+                                "previous_duration": { "secs": 0, "nanos": 0 }
+                            }
+                        }
+                    }
+                },
+                "Move": {
+                    "disabled": false,
+                    "kind_data": {
+                        "DualAxis": {
+                            "pair": [0.25, 1.0],
+                            "update_pair": [0.0, 0.0],
+                            "fixed_update_pair": [0.0, 0.0]
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let action_state: ActionState<Action> = serde_json::from_str(saved).unwrap();
+        assert!(action_state.just_pressed(&Action::Jump));
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::ZERO
+        );
+        assert_eq!(action_state.axis_pair(&Action::Move), Vec2::new(0.25, 1.0));
+    }
+
+    #[test]
+    fn set_directional() {
+        use bevy::math::CompassOctant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Move(CompassOctant),
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_directional(Action::Move, Vec2::new(0.6, 0.65), 0.2);
+        assert_eq!(
+            action_state.get_pressed(),
+            vec![Action::Move(CompassOctant::NorthEast)]
+        );
+
+        action_state.set_directional(Action::Move, Vec2::new(-0.9, 0.1), 0.2);
+        assert_eq!(
+            action_state.get_pressed(),
+            vec![Action::Move(CompassOctant::West)]
+        );
+        assert!(action_state.just_released(&Action::Move(CompassOctant::NorthEast)));
+
+        // Inside the deadzone, nothing is pressed
+        action_state.set_directional(Action::Move, Vec2::new(0.1, 0.1), 0.2);
+        assert!(action_state.get_pressed().is_empty());
+    }
+
+    #[test]
+    fn axis_activation_transitions() {
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(DualAxis)]
+            Move,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut activations = 0;
+        let mut deactivations = 0;
+
+        for pair in [
+            Vec2::ZERO,
+            Vec2::new(0.5, 0.0),
+            Vec2::new(0.8, 0.3),
+            Vec2::new(0.8, 0.3),
+            Vec2::ZERO,
+            Vec2::ZERO,
+        ] {
+            action_state.set_axis_pair(&Action::Move, pair);
+            activations += action_state.axis_just_activated(&Action::Move) as usize;
+            deactivations += action_state.axis_just_deactivated(&Action::Move) as usize;
+            action_state.tick(Instant::now(), Instant::now());
+        }
+
+        assert_eq!(activations, 1);
+        assert_eq!(deactivations, 1);
+    }
+
+    #[test]
+    fn axis_pairs() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(DualAxis)]
+            Look,
+            #[actionlike(DualAxis)]
+            Strafe,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_axis_pair(&Action::Move, Vec2::new(0.5, 0.0));
+        action_state.set_axis_pair(&Action::Look, Vec2::new(-0.25, 1.0));
+
+        let pairs: Vec<Vec2> = action_state
+            .axis_pairs(&[Action::Look, Action::Strafe, Action::Move])
+            .iter()
+            .map(|data| data.pair)
+            .collect();
+        assert_eq!(
+            pairs,
+            [Vec2::new(-0.25, 1.0), Vec2::ZERO, Vec2::new(0.5, 0.0)]
+        );
+
+        // Disabled actions report neutral values
+        action_state.disable_action(&Action::Move);
+        let pairs = action_state.axis_pairs(&[Action::Move, Action::Look]);
+        assert_eq!(pairs[0].pair, Vec2::ZERO);
+        assert_eq!(pairs[1].pair, Vec2::new(-0.25, 1.0));
+    }
+
+    #[test]
+    fn aim_rotation() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(DualAxis)]
+            Aim,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(action_state.aim_rotation(&Action::Aim), None);
+
+        // A stick pointing east faces the positive X axis
+        action_state.set_axis_pair(&Action::Aim, Vec2::new(1.0, 0.0));
+        let rotation = action_state.aim_rotation(&Action::Aim).unwrap();
+        assert!(rotation.as_radians().abs() < 1e-6);
+
+        // A stick pointing north is a quarter turn counterclockwise
+        action_state.set_axis_pair(&Action::Aim, Vec2::new(0.0, 0.8));
+        let rotation = action_state.aim_rotation(&Action::Aim).unwrap();
+        assert!((rotation.as_degrees() - 90.0).abs() < 1e-4);
+
+        // A released stick has no direction
+        action_state.set_axis_pair(&Action::Aim, Vec2::ZERO);
+        assert_eq!(action_state.aim_rotation(&Action::Aim), None);
+
+        // Nor does a disabled action
+        action_state.set_axis_pair(&Action::Aim, Vec2::new(-1.0, 0.0));
+        action_state.disable_action(&Action::Aim);
+        assert_eq!(action_state.aim_rotation(&Action::Aim), None);
+    }
+
+    #[test]
+    fn scripted_presses() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Transient,
+            Persistent,
+        }
+
+        let released_by_input = || {
+            UpdatedActions(HashMap::from([
+                (Action::Transient, UpdatedValue::Button(false)),
+                (Action::Persistent, UpdatedValue::Button(false)),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Transient);
+        action_state.press_persistent(&Action::Persistent);
+        assert!(action_state.just_pressed(&Action::Transient));
+        assert!(action_state.just_pressed(&Action::Persistent));
+
+        // The next input update overrides the transient press, but not the persistent one
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(released_by_input());
+        assert!(action_state.just_released(&Action::Transient));
+        assert!(action_state.pressed(&Action::Persistent));
+
+        // Until it is explicitly released
+        action_state.release(&Action::Persistent);
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.press(&Action::Persistent);
+        action_state.update(released_by_input());
+        assert!(action_state.released(&Action::Persistent));
+    }
+
+    #[test]
+    fn cooldowns() {
+        use bevy::utils::{Duration, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Fireball,
+        }
+
+        let start = Instant::now();
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_cooldown(&Action::Fireball, Duration::from_secs(1));
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Fireball),
+            Duration::ZERO
+        );
+
+        // Firing starts the cooldown
+        action_state.press(&Action::Fireball);
+        assert!(action_state.just_pressed(&Action::Fireball));
+        action_state.tick(start + Duration::from_millis(100), start);
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Fireball),
+            Duration::from_millis(900)
+        );
+
+        // Pressing again during the cooldown is suppressed
+        action_state.release(&Action::Fireball);
+        action_state.tick(
+            start + Duration::from_millis(200),
+            start + Duration::from_millis(100),
+        );
+        action_state.press(&Action::Fireball);
+        assert!(action_state.pressed(&Action::Fireball));
+        assert!(!action_state.just_pressed(&Action::Fireball));
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Fireball),
+            Duration::from_millis(800)
+        );
+
+        // Once the cooldown has elapsed, the action can fire again
+        action_state.release(&Action::Fireball);
+        action_state.tick(
+            start + Duration::from_millis(1500),
+            start + Duration::from_millis(200),
+        );
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Fireball),
+            Duration::ZERO
+        );
+        action_state.press(&Action::Fireball);
+        assert!(action_state.just_pressed(&Action::Fireball));
+    }
+
+    #[test]
+    fn clear_just_pressed_and_released() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Run,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.press(&Action::Run);
+
+        action_state.clear_just_pressed(&Action::Jump);
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert!(action_state.pressed(&Action::Jump));
+        // Other actions keep their edges
+        assert!(action_state.just_pressed(&Action::Run));
+
+        // Clearing a missing edge does nothing
+        action_state.clear_just_released(&Action::Run);
+        assert!(action_state.just_pressed(&Action::Run));
+
+        action_state.release(&Action::Jump);
+        action_state.clear_just_pressed(&Action::Jump);
+        assert!(action_state.just_released(&Action::Jump));
+
+        action_state.clear_just_released(&Action::Jump);
+        assert!(!action_state.just_released(&Action::Jump));
+        assert!(action_state.released(&Action::Jump));
+    }
+
+    #[test]
+    fn query_non_copy_action_by_reference() {
+        #[derive(Actionlike, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Cast(String),
+            Recast(String),
+        }
+
+        let fireball = Action::Cast("fireball".to_string());
+        let frostbolt = Action::Cast("frostbolt".to_string());
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&fireball);
+        assert!(action_state.pressed(&fireball));
+        assert!(action_state.just_pressed(&fireball));
+        assert!(action_state.released(&frostbolt));
+
+        // Data can be moved between actions without cloning them
+        let recast = Action::Recast("fireball".to_string());
+        let data = action_state.button_data(&fireball).unwrap().clone();
+        action_state.set_button_data(&recast, data);
+        assert!(action_state.pressed(&recast));
+
+        action_state.release(&fireball);
+        assert!(action_state.just_released(&fireball));
+        assert!(action_state.pressed(&recast));
+    }
+
+    #[test]
+    fn last_tick_duration() {
+        use bevy::utils::{Duration, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Fireball,
+        }
+
+        let start = Instant::now();
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(action_state.last_tick_duration(), Duration::ZERO);
+
+        action_state.tick(start + Duration::from_millis(16), start);
+        assert_eq!(action_state.last_tick_duration(), Duration::from_millis(16));
+
+        action_state.tick(
+            start + Duration::from_millis(50),
+            start + Duration::from_millis(16),
+        );
+        assert_eq!(action_state.last_tick_duration(), Duration::from_millis(34));
+
+        // Fixed ticks don't measure time
+        action_state.tick_fixed(1);
+        assert_eq!(action_state.last_tick_duration(), Duration::from_millis(34));
+
+        // Ticks during the `FixedMain` schedule don't overwrite the duration of the `Update` schedule
+        action_state.swap_to_fixed_update_state();
+        assert_eq!(action_state.last_tick_duration(), Duration::ZERO);
+        action_state.tick(
+            start + Duration::from_millis(60),
+            start + Duration::from_millis(50),
+        );
+        assert_eq!(action_state.last_tick_duration(), Duration::from_millis(10));
+        action_state.swap_to_update_state();
+        assert_eq!(action_state.last_tick_duration(), Duration::from_millis(34));
+
+        // The duration isn't serialized, so it doesn't affect equality either
+        assert_eq!(action_state, ActionState::default());
+    }
+
+    #[test]
+    fn update_with_changes() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Run,
+            Jump,
+            #[actionlike(Axis)]
+            Throttle,
+        }
+
+        let updated_actions = |run, jump| {
+            UpdatedActions(HashMap::from([
+                (Action::Run, UpdatedValue::Button(run)),
+                (Action::Jump, UpdatedValue::Button(jump)),
+                (Action::Throttle, UpdatedValue::Axis(0.5)),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut changed_actions = Vec::new();
+
+        // Newly pressed actions are reported
+        action_state.update_with_changes(updated_actions(true, false), &mut changed_actions);
+        assert_eq!(changed_actions, vec![Action::Run]);
+
+        // Held actions are not
+        changed_actions.clear();
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update_with_changes(updated_actions(true, true), &mut changed_actions);
+        assert_eq!(changed_actions, vec![Action::Jump]);
+
+        // Newly released actions are reported too
+        changed_actions.clear();
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update_with_changes(updated_actions(false, false), &mut changed_actions);
+        changed_actions.sort_by_key(|action| *action == Action::Jump);
+        assert_eq!(changed_actions, vec![Action::Run, Action::Jump]);
+
+        changed_actions.clear();
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update_with_changes(updated_actions(false, false), &mut changed_actions);
+        assert!(changed_actions.is_empty());
+    }
+
+    #[test]
+    fn pressed_directly() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::HashMap;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Direct,
+            Input,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Direct);
+        action_state.update(UpdatedActions(HashMap::from([(
+            Action::Input,
+            UpdatedValue::Button(true),
+        )])));
+
+        assert!(action_state.pressed(&Action::Direct));
+        assert!(action_state.pressed_directly(&Action::Direct));
+
+        assert!(action_state.pressed(&Action::Input));
+        assert!(!action_state.pressed_directly(&Action::Input));
+
+        action_state.release(&Action::Direct);
+        assert!(!action_state.pressed_directly(&Action::Direct));
+    }
+
     #[cfg(feature = "keyboard")]
     #[test]
     #[ignore = "Clashing inputs for non-buttonlike inputs is broken."]
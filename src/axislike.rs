@@ -1,6 +1,6 @@
 //! Tools for working with directional axis-like user inputs (game sticks, D-Pads and emulated equivalents)
 
-use bevy::prelude::{Reflect, Vec2};
+use bevy::prelude::{Reflect, Vec2, Vec3};
 use serde::{Deserialize, Serialize};
 
 /// The directions for single-axis inputs.
@@ -178,3 +178,53 @@ impl DualAxisDirection {
         self.axis_direction().is_active(axis_value, threshold)
     }
 }
+
+/// How the values of several bindings of the same axislike action are combined into a single value.
+///
+/// This is set for each action with [`InputMap::set_axis_combine`](crate::input_map::InputMap::set_axis_combine),
+/// such as to choose between a gamepad stick and a virtual D-pad bound to the same dual-axis action.
+///
+/// By default, actions use [`CombinePolicy::Sum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize, Default)]
+pub enum CombinePolicy {
+    /// The values of all bindings are added together.
+    #[default]
+    Sum,
+
+    /// The value of the binding with the greatest magnitude is used,
+    /// preferring earlier bindings in case of a tie.
+    MaxMagnitude,
+
+    /// The values of all bindings are added together,
+    /// and the result is clamped to a magnitude of at most `1.0`.
+    SumAndClamp,
+
+    /// The value of the binding that most recently left zero is used.
+    ///
+    /// Once that binding returns to zero, the first binding that is still active is used instead.
+    LastActiveWins,
+}
+
+impl CombinePolicy {
+    /// Combines the `values` of the bindings of an action,
+    /// where `last_active` is the index of the binding that most recently left zero.
+    ///
+    /// Values of single-axis and dual-axis bindings are stored in the leading components of each [`Vec3`].
+    #[must_use]
+    pub(crate) fn combine(&self, values: &[Vec3], last_active: Option<usize>) -> Vec3 {
+        match self {
+            Self::Sum => values.iter().sum(),
+            Self::MaxMagnitude => values.iter().fold(Vec3::ZERO, |max, value| {
+                if value.length_squared() > max.length_squared() {
+                    *value
+                } else {
+                    max
+                }
+            }),
+            Self::SumAndClamp => values.iter().sum::<Vec3>().clamp_length_max(1.0),
+            Self::LastActiveWins => last_active
+                .and_then(|index| values.get(index).copied())
+                .unwrap_or(Vec3::ZERO),
+        }
+    }
+}
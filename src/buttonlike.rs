@@ -122,3 +122,37 @@ impl ButtonState {
         *self == ButtonState::JustReleased
     }
 }
+
+/// Controls when a [`Buttonlike`](crate::user_input::Buttonlike) action fires
+/// as the state of its inputs changes.
+///
+/// This is mostly useful for buttons backed by analog inputs,
+/// such as a [`GamepadControlDirection`](crate::prelude::GamepadControlDirection) crossing its threshold,
+/// which would otherwise hold the action for as long as the stick is pushed.
+///
+/// By default, actions use [`EdgeMode::Level`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, Default)]
+pub enum EdgeMode {
+    /// The action is pressed for as long as its inputs are pressed.
+    #[default]
+    Level,
+    /// The action is pressed for a single update when its inputs become pressed.
+    RisingEdge,
+    /// The action is pressed for a single update when its inputs become pressed,
+    /// and again for a single update when they become released.
+    BothEdges,
+}
+
+impl EdgeMode {
+    /// Should the action be pressed, given whether its inputs were pressed on the `previous` update
+    /// and whether they are pressed on the `current` one?
+    #[inline]
+    #[must_use]
+    pub fn fires(&self, previous: bool, current: bool) -> bool {
+        match self {
+            EdgeMode::Level => current,
+            EdgeMode::RisingEdge => current && !previous,
+            EdgeMode::BothEdges => current != previous,
+        }
+    }
+}
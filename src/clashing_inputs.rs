@@ -5,8 +5,9 @@
 //! If the user presses `Ctrl + S`, the input manager should not also trigger the `S` action.
 
 use std::cmp::Ordering;
+use std::sync::OnceLock;
 
-use bevy::prelude::{Entity, Resource};
+use bevy::prelude::{Entity, Reflect, ReflectResource, Resource};
 use serde::{Deserialize, Serialize};
 
 use crate::input_map::{InputMap, UpdatedActions};
@@ -28,7 +29,8 @@ use crate::{Actionlike, InputControlKind};
 /// This strategy is only used when assessing the actions and input holistically,
 /// in [`InputMap::process_actions`], using [`InputMap::handle_clashes`].
 #[non_exhaustive]
-#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, Default)]
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
 pub enum ClashStrategy {
     /// All matching inputs will always be pressed
     PressAll,
@@ -168,8 +170,6 @@ impl BasicInputs {
 
 impl<A: Actionlike> InputMap<A> {
     /// Resolve clashing button-like inputs, removing action presses that have been overruled
-    ///
-    /// The `usize` stored in `pressed_actions` corresponds to `Actionlike::index`
     pub fn handle_clashes(
         &self,
         updated_actions: &mut UpdatedActions<A>,
@@ -186,19 +186,23 @@ impl<A: Actionlike> InputMap<A> {
         }
     }
 
-    /// Updates the cache of possible input clashes
-    pub(crate) fn possible_clashes(&self) -> Vec<Clash<A>> {
-        let mut clashes = Vec::default();
-
-        for action_a in self.buttonlike_actions() {
-            for action_b in self.buttonlike_actions() {
-                if let Some(clash) = self.possible_clash(action_a, action_b) {
-                    clashes.push(clash);
+    /// Returns the possible input clashes between [`Buttonlike`] actions.
+    ///
+    /// These are computed on first use and cached until the bindings are modified.
+    pub(crate) fn possible_clashes(&self) -> &[Clash<A>] {
+        self.clash_cache().get_or_compute(|| {
+            let mut clashes = Vec::default();
+
+            for action_a in self.buttonlike_actions() {
+                for action_b in self.buttonlike_actions() {
+                    if let Some(clash) = self.possible_clash(action_a, action_b) {
+                        clashes.push(clash);
+                    }
                 }
             }
-        }
 
-        clashes
+            clashes
+        })
     }
 
     /// Gets the set of clashing action-input pairs
@@ -222,7 +226,7 @@ impl<A: Actionlike> InputMap<A> {
             // This is not strictly necessary, but saves work
             if pressed_a && pressed_b {
                 // Check if the potential clash occurred based on the pressed inputs
-                if let Some(clash) = check_clash(&clash, input_store, gamepad) {
+                if let Some(clash) = check_clash(clash, input_store, gamepad) {
                     clashes.push(clash)
                 }
             }
@@ -291,6 +295,47 @@ impl<A: Actionlike> InputMap<A> {
     }
 }
 
+/// A lazily computed cache of the possible clashes in an [`InputMap`].
+///
+/// The cache is emptied whenever the [`Buttonlike`] bindings of the map change,
+/// and recomputed the next time the clashes are needed.
+/// It never affects equality, so two maps with the same bindings are equal whether or not it is filled.
+#[derive(Debug, Clone)]
+pub(crate) struct ClashCache<A: Actionlike>(OnceLock<Vec<Clash<A>>>);
+
+impl<A: Actionlike> ClashCache<A> {
+    /// Returns the cached clashes, computing them with `compute` if needed.
+    fn get_or_compute(&self, compute: impl FnOnce() -> Vec<Clash<A>>) -> &[Clash<A>] {
+        self.0.get_or_init(compute)
+    }
+
+    /// Has the cache been computed since it was last invalidated?
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn is_computed(&self) -> bool {
+        self.0.get().is_some()
+    }
+
+    /// Empties the cache, forcing the clashes to be recomputed on next use.
+    pub(crate) fn invalidate(&mut self) {
+        self.0.take();
+    }
+}
+
+impl<A: Actionlike> Default for ClashCache<A> {
+    fn default() -> Self {
+        Self(OnceLock::new())
+    }
+}
+
+impl<A: Actionlike> PartialEq for ClashCache<A> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A: Actionlike> Eq for ClashCache<A> {}
+
 /// A user-input clash, which stores the actions that are being clashed on,
 /// as well as the corresponding user inputs
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -576,6 +621,27 @@ mod tests {
                 .is_some());
         }
 
+        #[test]
+        fn possible_clashes_are_cached() {
+            let mut input_map = test_input_map();
+            assert!(!input_map.clash_cache().is_computed());
+
+            // Repeated queries reuse the same cached clashes
+            let first = input_map.possible_clashes().as_ptr();
+            assert!(input_map.clash_cache().is_computed());
+            assert_eq!(first, input_map.possible_clashes().as_ptr());
+            let clash_count = input_map.possible_clashes().len();
+
+            // Modifying the bindings invalidates the cache
+            input_map.insert(Action::Two, ButtonlikeChord::new([Digit1, Digit3]));
+            assert!(!input_map.clash_cache().is_computed());
+            assert!(input_map.possible_clashes().len() > clash_count);
+
+            input_map.clear_action(&Action::Two);
+            assert!(!input_map.clash_cache().is_computed());
+            assert!(input_map.possible_clashes().len() < clash_count);
+        }
+
         #[test]
         fn resolve_prioritize_longest() {
             let mut app = App::new();
@@ -0,0 +1,106 @@
+//! Stacks of [`InputMap`]s, used to switch between input contexts such as gameplay, menus and dialogue.
+
+use bevy::prelude::{ReflectResource, Resource};
+use bevy::reflect::Reflect;
+
+use crate::input_map::InputMap;
+use crate::Actionlike;
+
+/// A stack of [`InputMap`]s, where only the topmost map is active.
+///
+/// When present and non-empty, the active map is used in place of the [`InputMap`] resource
+/// to update the [`ActionState`](crate::action_state::ActionState) resource.
+/// Pushing a new context replaces the current bindings,
+/// and popping it restores the bindings that were active before.
+///
+/// Only the resources are affected: entities with an [`InputMap`] component keep using that map,
+/// so switching their contexts is done by replacing the component.
+///
+/// # Examples
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+/// enum Action {
+///     Confirm,
+/// }
+///
+/// let gameplay = InputMap::new([(Action::Confirm, KeyCode::KeyE)]);
+/// let menu = InputMap::new([(Action::Confirm, KeyCode::Enter)]);
+///
+/// let mut contexts = InputContextStack::new(gameplay.clone());
+/// contexts.push(menu.clone());
+/// assert_eq!(contexts.active(), Some(&menu));
+///
+/// // Leaving the menu restores the gameplay bindings
+/// assert_eq!(contexts.pop(), Some(menu));
+/// assert_eq!(contexts.active(), Some(&gameplay));
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct InputContextStack<A: Actionlike> {
+    /// The stacked input maps, with the active one last.
+    stack: Vec<InputMap<A>>,
+}
+
+impl<A: Actionlike> Default for InputContextStack<A> {
+    fn default() -> Self {
+        Self { stack: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> InputContextStack<A> {
+    /// Creates a new [`InputContextStack`] with the provided `input_map` as its base context.
+    #[inline]
+    pub fn new(input_map: InputMap<A>) -> Self {
+        Self {
+            stack: vec![input_map],
+        }
+    }
+
+    /// Pushes a new context onto the stack, making its `input_map` the active one.
+    #[inline]
+    pub fn push(&mut self, input_map: InputMap<A>) -> &mut Self {
+        self.stack.push(input_map);
+        self
+    }
+
+    /// Removes the active context from the stack and returns its [`InputMap`],
+    /// re-activating the previous context.
+    ///
+    /// Returns [`None`] if the stack is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<InputMap<A>> {
+        self.stack.pop()
+    }
+
+    /// Returns the active [`InputMap`], if any.
+    #[must_use]
+    #[inline]
+    pub fn active(&self) -> Option<&InputMap<A>> {
+        self.stack.last()
+    }
+
+    /// Returns a mutable reference to the active [`InputMap`], if any.
+    #[must_use]
+    #[inline]
+    pub fn active_mut(&mut self) -> Option<&mut InputMap<A>> {
+        self.stack.last_mut()
+    }
+
+    /// Returns the number of contexts on the stack.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if the stack contains no contexts.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+}
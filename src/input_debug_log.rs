@@ -0,0 +1,172 @@
+//! A rolling log of recent input-to-action decisions, for debugging.
+//!
+//! When the `debug_log` feature is enabled, each [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+//! adds an [`InputDebugLog`] resource for its action type.
+//! Whenever a [`Buttonlike`] action is pressed, released or suppressed by [clash resolution](crate::clashing_inputs),
+//! an [`InputDebugEntry`] is recorded, helping to answer "why did my action fire?".
+//! Decisions are recorded once they are applied to the [`ActionState`](crate::action_state::ActionState),
+//! after any [`InputDelay`](crate::plugin::InputDelay), along with the releases caused by disconnecting a gamepad.
+//!
+//! Only the most recent entries are kept, up to the [capacity](InputDebugLog::capacity) of the log.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::{Entity, Resource};
+use bevy::utils::HashMap;
+
+use crate::{user_input::Buttonlike, Actionlike};
+
+/// A single input-to-action decision recorded in an [`InputDebugLog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputDebugEntry<A: Actionlike> {
+    /// The entity whose [`InputMap`](crate::input_map::InputMap) made the decision,
+    /// or [`None`] for the [`InputMap`](crate::input_map::InputMap) resource.
+    pub entity: Option<Entity>,
+    /// The [`Buttonlike`] action that was decided upon.
+    pub action: A,
+    /// The binding of the `action` that pressed it, or that was pressed but suppressed by clash resolution, if any.
+    ///
+    /// This is [`None`] for actions pressed without any of their bindings, such as inverted actions.
+    pub input: Option<Box<dyn Buttonlike>>,
+    /// Whether the `action` was pressed after clash resolution.
+    pub pressed: bool,
+    /// Whether the `input` was pressed, but clash resolution released the `action`.
+    pub suppressed_by_clash: bool,
+}
+
+/// A bounded, rolling log of recent decisions about [`Buttonlike`] actions, for debugging.
+///
+/// An [`InputDebugEntry`] is recorded each time an action is pressed, released,
+/// or suppressed by [clash resolution](crate::clashing_inputs).
+/// Once the log is full, the oldest entries are discarded.
+///
+/// This resource is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+/// when the `debug_log` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::input_debug_log::InputDebugLog;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn explain_jumps(log: Res<InputDebugLog<Action>>) {
+///     for entry in log.iter().filter(|entry| entry.action == Action::Jump) {
+///         info!("{entry:?}");
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputDebugLog<A: Actionlike> {
+    entries: VecDeque<InputDebugEntry<A>>,
+    capacity: usize,
+    /// The last recorded `pressed` and `suppressed_by_clash` state of each action,
+    /// used to only record changes.
+    last_decisions: HashMap<(Option<Entity>, A), (bool, bool)>,
+}
+
+impl<A: Actionlike> InputDebugLog<A> {
+    /// The number of entries kept by default.
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    /// Creates an empty log that keeps at most `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            last_decisions: HashMap::default(),
+        }
+    }
+
+    /// Returns the maximum number of entries kept by this log.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries currently in this log.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if this log has no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries in this log, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &InputDebugEntry<A>> {
+        self.entries.iter()
+    }
+
+    /// Returns the most recent entry in this log, if any.
+    #[inline]
+    #[must_use]
+    pub fn latest(&self) -> Option<&InputDebugEntry<A>> {
+        self.entries.back()
+    }
+
+    /// Removes all entries from this log.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Records the decision made for the [`Buttonlike`] `action` of the given `entity`,
+    /// if it differs from the last decision recorded for it.
+    pub(crate) fn record(
+        &mut self,
+        entity: Option<Entity>,
+        action: &A,
+        input: Option<&dyn Buttonlike>,
+        pressed: bool,
+        suppressed_by_clash: bool,
+    ) {
+        let decision = (pressed, suppressed_by_clash);
+        let last_decision = self
+            .last_decisions
+            .insert((entity, action.clone()), decision)
+            .unwrap_or_default();
+        if last_decision == decision {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        if self.capacity > 0 {
+            self.entries.push_back(InputDebugEntry {
+                entity,
+                action: action.clone(),
+                input: input.map(dyn_clone::clone_box),
+                pressed,
+                suppressed_by_clash,
+            });
+        }
+    }
+
+    /// Forgets the last decisions recorded for entities that don't satisfy the `predicate`,
+    /// such as those that have been despawned.
+    ///
+    /// Entries already in the log are kept.
+    pub(crate) fn retain_entities(&mut self, mut predicate: impl FnMut(Option<Entity>) -> bool) {
+        self.last_decisions
+            .retain(|(entity, _), _| predicate(*entity));
+    }
+}
+
+impl<A: Actionlike> Default for InputDebugLog<A> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
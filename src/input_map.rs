@@ -6,7 +6,7 @@ use std::hash::Hash;
 #[cfg(feature = "asset")]
 use bevy::asset::Asset;
 use bevy::prelude::{Component, Deref, DerefMut, Entity, Gamepad, Query, Reflect, Resource, With};
-use bevy::utils::HashMap;
+use bevy::utils::{Duration, HashMap, HashSet};
 use bevy::{log::error, prelude::ReflectComponent};
 use bevy::{
     math::{Vec2, Vec3},
@@ -15,10 +15,18 @@ use bevy::{
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::clashing_inputs::ClashStrategy;
+use crate::axislike::CombinePolicy;
+use crate::buttonlike::EdgeMode;
+use crate::clashing_inputs::{BasicInputs, ClashCache, ClashStrategy};
+use crate::input_processing::{
+    AxisProcessor, CircleBounds, DualAxisInverted, DualAxisProcessor,
+    WithAxisProcessingPipelineExt, WithDualAxisProcessingPipelineExt,
+};
 use crate::prelude::updating::CentralInputStore;
 use crate::prelude::UserInputWrapper;
-use crate::user_input::{Axislike, Buttonlike, DualAxislike, TripleAxislike};
+use crate::user_input::{
+    Axislike, Buttonlike, ButtonlikeChord, DeviceClass, DualAxislike, TripleAxislike, UserInput,
+};
 use crate::{Actionlike, InputControlKind};
 
 #[cfg(feature = "gamepad")]
@@ -60,6 +68,18 @@ fn find_gamepad(_: Option<Query<Entity, With<Gamepad>>>) -> Entity {
 ///
 /// This behavior can be customized using the [`ClashStrategy`] resource.
 ///
+/// # Gamepad Scoping
+///
+/// Each [`InputMap`] computes its [`ActionState`](crate::action_state::ActionState)
+/// only from its own bindings, reading gamepad inputs from a single gamepad:
+/// the one set with [`InputMap::set_gamepad`], or else the first connected gamepad.
+///
+/// For local multiplayer, such as split-screen games with one [`InputMap`] per player entity,
+/// associate each map with its player's gamepad so that the players' inputs are isolated,
+/// even when their bindings overlap.
+/// Keyboard and mouse inputs are shared by all maps that bind them,
+/// as are actions configured with [`InputMap::set_any_gamepad`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -119,6 +139,87 @@ pub struct InputMap<A: Actionlike> {
 
     /// The specified gamepad from which this map exclusively accepts input.
     associated_gamepad: Option<Entity>,
+
+    /// The cooldowns applied to [`Buttonlike`] actions after they fire.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    cooldowns: HashMap<A, Duration>,
+
+    /// The [`EdgeMode`]s configured for [`Buttonlike`] actions.
+    ///
+    /// [`EdgeMode::Level`] is stored explicitly once set,
+    /// so that it replaces any previous mode on each [`ActionState`](crate::action_state::ActionState).
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    edge_modes: HashMap<A, EdgeMode>,
+
+    /// The actions that currently read the bindings of another action, mapped to that action.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    aliases: HashMap<A, A>,
+
+    /// The [`Buttonlike`] actions that accept input from any connected gamepad,
+    /// reporting which gamepad triggered them.
+    #[serde(
+        default = "HashSet::default",
+        skip_serializing_if = "HashSet::is_empty"
+    )]
+    any_gamepad_actions: HashSet<A>,
+
+    /// The [`Buttonlike`] actions that are pressed while none of their bindings are.
+    #[serde(
+        default = "HashSet::default",
+        skip_serializing_if = "HashSet::is_empty"
+    )]
+    inverted_actions: HashSet<A>,
+
+    /// The [`ClashStrategy`] used by this map, overriding the global [`ClashStrategy`] resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clash_strategy: Option<ClashStrategy>,
+
+    /// The groups of mutually exclusive [`Buttonlike`] actions, each ordered from highest to lowest priority.
+    #[serde(default = "Vec::default", skip_serializing_if = "Vec::is_empty")]
+    exclusive_groups: Vec<Vec<A>>,
+
+    /// The processors applied to the combined value of all bindings of each [`Axislike`] action.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    axis_processors: HashMap<A, Vec<AxisProcessor>>,
+
+    /// The processors applied to the combined value of all bindings of each [`DualAxislike`] action.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    dual_axis_processors: HashMap<A, Vec<DualAxisProcessor>>,
+
+    /// The [`CombinePolicy`]s of axislike actions that don't use [`CombinePolicy::Sum`].
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    axis_combine_policies: HashMap<A, CombinePolicy>,
+
+    /// The axislike actions that are inverted while a [`Buttonlike`] action is pressed,
+    /// mapped to that action and the inverted axes.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    conditional_inversions: HashMap<A, (A, DualAxisInverted)>,
+
+    /// The cached possible clashes between [`Buttonlike`] actions.
+    #[serde(skip, default = "ClashCache::default")]
+    #[reflect(ignore)]
+    clash_cache: ClashCache<A>,
 }
 
 impl<A: Actionlike> Default for InputMap<A> {
@@ -129,12 +230,31 @@ impl<A: Actionlike> Default for InputMap<A> {
             dual_axislike_map: HashMap::default(),
             triple_axislike_map: HashMap::default(),
             associated_gamepad: None,
+            cooldowns: HashMap::default(),
+            edge_modes: HashMap::default(),
+            aliases: HashMap::default(),
+            any_gamepad_actions: HashSet::default(),
+            inverted_actions: HashSet::default(),
+            clash_strategy: None,
+            exclusive_groups: Vec::default(),
+            axis_processors: HashMap::default(),
+            dual_axis_processors: HashMap::default(),
+            axis_combine_policies: HashMap::default(),
+            conditional_inversions: HashMap::default(),
+            clash_cache: ClashCache::default(),
         }
     }
 }
 
 // Constructors
 impl<A: Actionlike> InputMap<A> {
+    /// Creates an [`InputMapBuilder`], to fluently bind inputs to actions
+    /// along with the processors applied to each input.
+    #[inline]
+    pub fn builder() -> InputMapBuilder<A> {
+        InputMapBuilder::default()
+    }
+
     /// Creates an [`InputMap`] from an iterator over [`Buttonlike`] action-input bindings.
     /// Note that all elements within the iterator must be of the same type (homogeneous).
     ///
@@ -221,6 +341,261 @@ impl<A: Actionlike> InputMap<A> {
         self.insert_multiple(bindings);
         self
     }
+
+    /// Binds the `action` to a [`ButtonlikeChord`] of the given [`Buttonlike`] `inputs`,
+    /// which is only pressed while all of them are held.
+    ///
+    /// Inputs of different types can be mixed by boxing them.
+    ///
+    /// This method ensures idempotence, meaning that adding the same chord
+    /// for the same action multiple times will only result in a single binding being created.
+    #[inline(always)]
+    pub fn with_chord(
+        mut self,
+        action: A,
+        inputs: impl IntoIterator<Item = impl Into<Box<dyn Buttonlike>>>,
+    ) -> Self {
+        self.insert_chord(action, inputs);
+        self
+    }
+
+    /// Splits a [`GamepadAxis`](bevy::input::gamepad::GamepadAxis) into two [`Buttonlike`] halves,
+    /// binding its negative side to `negative_action` and its positive side to `positive_action`.
+    ///
+    /// Each side is only considered pressed once the axis value moves past `threshold` in that direction.
+    ///
+    /// # Requirements
+    ///
+    /// - `threshold` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    #[cfg(feature = "gamepad")]
+    #[inline(always)]
+    pub fn with_split_axis(
+        mut self,
+        negative_action: A,
+        positive_action: A,
+        axis: bevy::input::gamepad::GamepadAxis,
+        threshold: f32,
+    ) -> Self {
+        self.insert_split_axis(negative_action, positive_action, axis, threshold);
+        self
+    }
+}
+
+/// A fluent builder for [`InputMap`]s, created by [`InputMap::builder`],
+/// which keeps each binding together with the processors applied to its input.
+///
+/// Each `bind` method returns a [`PendingBinding`], which can be configured with
+/// the same processing pipeline methods as the input itself
+/// (such as [`WithDualAxisProcessingPipelineExt::with_processor`]),
+/// before binding the next input or building the [`InputMap`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+/// enum Action {
+///     Jump,
+///     #[actionlike(DualAxis)]
+///     Move,
+/// }
+///
+/// let input_map = InputMap::builder()
+///     .bind(Action::Jump, KeyCode::Space)
+///     .bind_dual_axis(Action::Move, GamepadStick::LEFT)
+///     .with_processor(CircleDeadZone::new(0.1))
+///     .build();
+///
+/// let expected = InputMap::default()
+///     .with(Action::Jump, KeyCode::Space)
+///     .with_dual_axis(Action::Move, GamepadStick::LEFT.with_circle_deadzone(0.1));
+/// assert_eq!(input_map, expected);
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct InputMapBuilder<A: Actionlike> {
+    input_map: InputMap<A>,
+}
+
+impl<A: Actionlike> Default for InputMapBuilder<A> {
+    fn default() -> Self {
+        Self {
+            input_map: InputMap::default(),
+        }
+    }
+}
+
+impl<A: Actionlike> InputMapBuilder<A> {
+    /// Starts binding the `action` to a [`Buttonlike`] `button`.
+    #[inline]
+    pub fn bind<U: Buttonlike>(self, action: A, button: U) -> PendingBinding<A, U> {
+        PendingBinding::new(self, action, button, |input_map, action, button| {
+            input_map.insert(action, button);
+        })
+    }
+
+    /// Starts binding the `action` to an [`Axislike`] `axis`.
+    #[inline]
+    pub fn bind_axis<U: Axislike>(self, action: A, axis: U) -> PendingBinding<A, U> {
+        PendingBinding::new(self, action, axis, |input_map, action, axis| {
+            input_map.insert_axis(action, axis);
+        })
+    }
+
+    /// Starts binding the `action` to a [`DualAxislike`] `dual_axis`.
+    #[inline]
+    pub fn bind_dual_axis<U: DualAxislike>(self, action: A, dual_axis: U) -> PendingBinding<A, U> {
+        PendingBinding::new(self, action, dual_axis, |input_map, action, dual_axis| {
+            input_map.insert_dual_axis(action, dual_axis);
+        })
+    }
+
+    /// Starts binding the `action` to a [`TripleAxislike`] `triple_axis`.
+    #[inline]
+    pub fn bind_triple_axis<U: TripleAxislike>(
+        self,
+        action: A,
+        triple_axis: U,
+    ) -> PendingBinding<A, U> {
+        PendingBinding::new(
+            self,
+            action,
+            triple_axis,
+            |input_map, action, triple_axis| {
+                input_map.insert_triple_axis(action, triple_axis);
+            },
+        )
+    }
+
+    /// Returns the built [`InputMap`].
+    #[inline]
+    pub fn build(self) -> InputMap<A> {
+        self.input_map
+    }
+}
+
+/// A binding started by one of the `bind` methods of [`InputMapBuilder`],
+/// whose `input` can still be configured before it is inserted into the [`InputMap`].
+#[derive(Debug)]
+#[must_use]
+pub struct PendingBinding<A: Actionlike, U> {
+    builder: InputMapBuilder<A>,
+    action: A,
+    input: U,
+    insert: fn(&mut InputMap<A>, A, U),
+}
+
+impl<A: Actionlike, U> PendingBinding<A, U> {
+    #[inline]
+    fn new(
+        builder: InputMapBuilder<A>,
+        action: A,
+        input: U,
+        insert: fn(&mut InputMap<A>, A, U),
+    ) -> Self {
+        Self {
+            builder,
+            action,
+            input,
+            insert,
+        }
+    }
+
+    /// Inserts the pending binding, returning the [`InputMapBuilder`].
+    #[inline]
+    fn finish(self) -> InputMapBuilder<A> {
+        let mut builder = self.builder;
+        (self.insert)(&mut builder.input_map, self.action, self.input);
+        builder
+    }
+
+    /// Finishes this binding, and starts binding the `action` to a [`Buttonlike`] `button`.
+    #[inline]
+    pub fn bind<V: Buttonlike>(self, action: A, button: V) -> PendingBinding<A, V> {
+        self.finish().bind(action, button)
+    }
+
+    /// Finishes this binding, and starts binding the `action` to an [`Axislike`] `axis`.
+    #[inline]
+    pub fn bind_axis<V: Axislike>(self, action: A, axis: V) -> PendingBinding<A, V> {
+        self.finish().bind_axis(action, axis)
+    }
+
+    /// Finishes this binding, and starts binding the `action` to a [`DualAxislike`] `dual_axis`.
+    #[inline]
+    pub fn bind_dual_axis<V: DualAxislike>(self, action: A, dual_axis: V) -> PendingBinding<A, V> {
+        self.finish().bind_dual_axis(action, dual_axis)
+    }
+
+    /// Finishes this binding, and starts binding the `action` to a [`TripleAxislike`] `triple_axis`.
+    #[inline]
+    pub fn bind_triple_axis<V: TripleAxislike>(
+        self,
+        action: A,
+        triple_axis: V,
+    ) -> PendingBinding<A, V> {
+        self.finish().bind_triple_axis(action, triple_axis)
+    }
+
+    /// Finishes this binding, and returns the built [`InputMap`].
+    #[inline]
+    pub fn build(self) -> InputMap<A> {
+        self.finish().build()
+    }
+}
+
+impl<A: Actionlike, U: WithAxisProcessingPipelineExt> WithAxisProcessingPipelineExt
+    for PendingBinding<A, U>
+{
+    #[inline]
+    fn reset_processing_pipeline(mut self) -> Self {
+        self.input = self.input.reset_processing_pipeline();
+        self
+    }
+
+    #[inline]
+    fn replace_processing_pipeline(
+        mut self,
+        processors: impl IntoIterator<Item = AxisProcessor>,
+    ) -> Self {
+        self.input = self.input.replace_processing_pipeline(processors);
+        self
+    }
+
+    #[inline]
+    fn with_processor(mut self, processor: impl Into<AxisProcessor>) -> Self {
+        self.input = self.input.with_processor(processor);
+        self
+    }
+}
+
+impl<A: Actionlike, U: WithDualAxisProcessingPipelineExt> WithDualAxisProcessingPipelineExt
+    for PendingBinding<A, U>
+{
+    #[inline]
+    fn reset_processing_pipeline(mut self) -> Self {
+        self.input = self.input.reset_processing_pipeline();
+        self
+    }
+
+    #[inline]
+    fn replace_processing_pipeline(
+        mut self,
+        processors: impl IntoIterator<Item = DualAxisProcessor>,
+    ) -> Self {
+        self.input = self.input.replace_processing_pipeline(processors);
+        self
+    }
+
+    #[inline]
+    fn with_processor(mut self, processor: impl Into<DualAxisProcessor>) -> Self {
+        self.input = self.input.with_processor(processor);
+        self
+    }
 }
 
 #[inline(always)]
@@ -238,6 +613,22 @@ where
     }
 }
 
+#[inline(always)]
+fn swap_entries<K, V>(map: &mut HashMap<K, Vec<V>>, key_a: &K, key_b: &K)
+where
+    K: Clone + Eq + Hash,
+{
+    let bindings_a = map.remove(key_a);
+    let bindings_b = map.remove(key_b);
+
+    if let Some(bindings) = bindings_b {
+        map.insert(key_a.clone(), bindings);
+    }
+    if let Some(bindings) = bindings_a {
+        map.insert(key_b.clone(), bindings);
+    }
+}
+
 // Insertion
 impl<A: Actionlike> InputMap<A> {
     /// Inserts a binding between an `action` and a specific [`Buttonlike`] `input`.
@@ -266,6 +657,7 @@ impl<A: Actionlike> InputMap<A> {
         }
 
         insert_unique(&mut self.buttonlike_map, &action, Box::new(button));
+        self.clash_cache.invalidate();
         self
     }
 
@@ -360,7 +752,7 @@ impl<A: Actionlike> InputMap<A> {
     /// Inserts bindings between the same `action` and multiple [`Buttonlike`] `inputs` provided by an iterator.
     /// Note that all elements within the iterator must be of the same type (homogeneous).
     ///
-    /// To insert a chord, such as Control + A, use a [`ButtonlikeChord`](crate::user_input::ButtonlikeChord).
+    /// To insert a chord, such as Control + A, use [`InputMap::insert_chord`].
     ///
     /// This method ensures idempotence, meaning that adding the same input
     /// for the same action multiple times will only result in a single binding being created.
@@ -383,9 +775,146 @@ impl<A: Actionlike> InputMap<A> {
             self.buttonlike_map
                 .insert(action, inputs.unique().collect());
         }
+        self.clash_cache.invalidate();
+        self
+    }
+
+    /// Binds the `action` to a [`ButtonlikeChord`] of the given [`Buttonlike`] `inputs`,
+    /// which is only pressed while all of them are held.
+    ///
+    /// Inputs of different types can be mixed by boxing them, as in the example below.
+    ///
+    /// This method ensures idempotence, meaning that adding the same chord
+    /// for the same action multiple times will only result in a single binding being created.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
+    /// enum Action {
+    ///     Save,
+    ///     Select,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map.insert_chord(Action::Save, [KeyCode::ControlLeft, KeyCode::KeyS]);
+    /// input_map.insert_chord(
+    ///     Action::Select,
+    ///     [
+    ///         Box::new(KeyCode::ShiftLeft) as Box<dyn Buttonlike>,
+    ///         MouseButton::Left.into(),
+    ///     ],
+    /// );
+    ///
+    /// // Equivalent to inserting the chord directly
+    /// let expected = InputMap::default()
+    ///     .with(Action::Save, ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]));
+    /// assert_eq!(input_map.get_buttonlike(&Action::Save), expected.get_buttonlike(&Action::Save));
+    /// ```
+    #[inline(always)]
+    #[track_caller]
+    pub fn insert_chord(
+        &mut self,
+        action: A,
+        inputs: impl IntoIterator<Item = impl Into<Box<dyn Buttonlike>>>,
+    ) -> &mut Self {
+        let chord: ButtonlikeChord = inputs.into_iter().map(Into::into).collect();
+        self.insert(action, chord)
+    }
+
+    /// Splits a [`GamepadAxis`](bevy::input::gamepad::GamepadAxis) into two [`Buttonlike`] halves,
+    /// binding its negative side to `negative_action` and its positive side to `positive_action`.
+    ///
+    /// Each side is only considered pressed once the axis value moves past `threshold` in that direction.
+    /// This is a shorthand for inserting a pair of [`GamepadControlDirection`](crate::user_input::GamepadControlDirection)s.
+    ///
+    /// # Requirements
+    ///
+    /// - `threshold` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    #[cfg(feature = "gamepad")]
+    #[inline(always)]
+    pub fn insert_split_axis(
+        &mut self,
+        negative_action: A,
+        positive_action: A,
+        axis: bevy::input::gamepad::GamepadAxis,
+        threshold: f32,
+    ) -> &mut Self {
+        use crate::user_input::GamepadControlDirection;
+
+        self.insert(
+            negative_action,
+            GamepadControlDirection::negative(axis).threshold(threshold),
+        );
+        self.insert(
+            positive_action,
+            GamepadControlDirection::positive(axis).threshold(threshold),
+        );
         self
     }
 
+    /// Updates the thresholds of the [`GamepadControlDirection`](crate::user_input::GamepadControlDirection)s
+    /// bound to the `action` in place, such as for an accessibility setting controlling how far sticks must be pushed.
+    ///
+    /// Bindings in the positive direction use the `positive_threshold`,
+    /// while bindings in the negative direction use the `negative_threshold`.
+    /// Other bindings of the `action`, including directions nested inside chords, are left untouched.
+    ///
+    /// Returns the number of bindings that were updated.
+    ///
+    /// # Requirements
+    ///
+    /// - `positive_threshold` >= `0.0`.
+    /// - `negative_threshold` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[cfg(feature = "gamepad")]
+    pub fn set_axis_threshold(
+        &mut self,
+        action: &A,
+        positive_threshold: f32,
+        negative_threshold: f32,
+    ) -> usize {
+        use crate::axislike::AxisDirection;
+        use crate::user_input::GamepadControlDirection;
+
+        assert!(positive_threshold >= 0.0);
+        assert!(negative_threshold >= 0.0);
+
+        let Some(bindings) = self.buttonlike_map.get_mut(action) else {
+            return 0;
+        };
+
+        let mut updated = 0;
+        for binding in bindings.iter_mut() {
+            let Some(control_direction) = binding
+                .as_mut()
+                .as_reflect_mut()
+                .downcast_mut::<GamepadControlDirection>()
+            else {
+                continue;
+            };
+
+            control_direction.threshold = match control_direction.direction {
+                AxisDirection::Positive => positive_threshold,
+                AxisDirection::Negative => negative_threshold,
+            };
+            updated += 1;
+        }
+
+        if updated > 0 {
+            self.clash_cache.invalidate();
+        }
+        updated
+    }
+
     /// Inserts multiple action-input [`Buttonlike`] bindings provided by an iterator.
     /// Note that all elements within the iterator must be of the same type (homogeneous).
     ///
@@ -416,6 +945,7 @@ impl<A: Actionlike> InputMap<A> {
                 insert_unique(&mut self.buttonlike_map, other_action, other_input);
             }
         }
+        self.clash_cache.invalidate();
 
         for (other_action, other_inputs) in other.iter_axislike() {
             for other_input in other_inputs.iter().cloned() {
@@ -435,6 +965,55 @@ impl<A: Actionlike> InputMap<A> {
             }
         }
 
+        for (other_action, other_cooldown) in other.cooldowns.iter() {
+            self.cooldowns.insert(other_action.clone(), *other_cooldown);
+        }
+
+        for (other_action, other_edge_mode) in other.edge_modes.iter() {
+            self.edge_modes
+                .insert(other_action.clone(), *other_edge_mode);
+        }
+
+        for (other_action, other_policy) in other.axis_combine_policies.iter() {
+            self.axis_combine_policies
+                .insert(other_action.clone(), *other_policy);
+        }
+
+        for (other_action, (other_gate, other_inverted)) in other.conditional_inversions.iter() {
+            self.set_conditional_inversion(
+                other_action.clone(),
+                other_gate.clone(),
+                *other_inverted,
+            );
+        }
+
+        for (other_action, other_target) in other.aliases.iter() {
+            self.alias(other_action.clone(), other_target.clone());
+        }
+
+        self.any_gamepad_actions
+            .extend(other.any_gamepad_actions.iter().cloned());
+        self.inverted_actions
+            .extend(other.inverted_actions.iter().cloned());
+
+        if other.clash_strategy.is_some() {
+            self.clash_strategy = other.clash_strategy;
+        }
+
+        for other_group in other.exclusive_groups.iter() {
+            self.set_exclusive_group(other_group);
+        }
+
+        for (other_action, other_processors) in other.axis_processors.iter() {
+            self.axis_processors
+                .insert(other_action.clone(), other_processors.clone());
+        }
+
+        for (other_action, other_processors) in other.dual_axis_processors.iter() {
+            self.dual_axis_processors
+                .insert(other_action.clone(), other_processors.clone());
+        }
+
         self
     }
 }
@@ -478,6 +1057,8 @@ impl<A: Actionlike> InputMap<A> {
     ///
     /// Because of this robust fallback behavior,
     /// this method can typically be ignored when writing single-player games.
+    /// In local multiplayer games, call it on every player's map:
+    /// a map without an associated gamepad may read the inputs of another player's gamepad.
     #[inline]
     pub fn set_gamepad(&mut self, gamepad: Entity) -> &mut Self {
         self.associated_gamepad = Some(gamepad);
@@ -490,96 +1071,996 @@ impl<A: Actionlike> InputMap<A> {
         self.associated_gamepad = None;
         self
     }
-}
 
-// Check whether actions are pressed
-impl<A: Actionlike> InputMap<A> {
-    /// Checks if the `action` are currently pressed by any of the associated [`Buttonlike`]s.
+    /// Fetches the [`ClashStrategy`] that overrides the global [`ClashStrategy`] resource for this map.
     ///
-    /// Accounts for clashing inputs according to the [`ClashStrategy`] and remove conflicting actions.
+    /// If this is [`None`], the global [`ClashStrategy`] resource will be used.
     #[must_use]
-    pub fn pressed(
-        &self,
-        action: &A,
-        input_store: &CentralInputStore,
-        clash_strategy: ClashStrategy,
-    ) -> bool {
-        let processed_actions = self.process_actions(None, input_store, clash_strategy);
-
-        let Some(updated_value) = processed_actions.get(action) else {
-            return false;
-        };
+    #[inline]
+    pub const fn clash_strategy(&self) -> Option<ClashStrategy> {
+        self.clash_strategy
+    }
 
-        match updated_value {
-            UpdatedValue::Button(state) => *state,
-            _ => false,
-        }
+    /// Overrides the global [`ClashStrategy`] resource for this map, as in [`InputMap::set_clash_strategy`].
+    #[inline]
+    pub fn with_clash_strategy(mut self, clash_strategy: Option<ClashStrategy>) -> Self {
+        self.set_clash_strategy(clash_strategy);
+        self
     }
 
-    /// Determines the correct state for each action according to provided [`CentralInputStore`].
+    /// Overrides the global [`ClashStrategy`] resource for this map.
     ///
-    /// This method uses the input bindings for each action to determine how to parse the input data,
-    /// and generates corresponding [`ButtonData`](crate::action_state::ButtonData),
-    /// [`AxisData`](crate::action_state::AxisData) and [`DualAxisData`](crate::action_state::DualAxisData).
+    /// Passing [`ClashStrategy::PressAll`] disables clash resolution for this map entirely,
+    /// pressing every action whose inputs match,
+    /// while passing [`None`] falls back to the global [`ClashStrategy`] resource again.
+    #[inline]
+    pub fn set_clash_strategy(&mut self, clash_strategy: Option<ClashStrategy>) -> &mut Self {
+        self.clash_strategy = clash_strategy;
+        self
+    }
+
+    /// Returns an iterator over the groups of mutually exclusive actions,
+    /// each ordered from highest to lowest priority.
+    #[inline]
+    pub fn iter_exclusive_groups(&self) -> impl Iterator<Item = &[A]> {
+        self.exclusive_groups.iter().map(Vec::as_slice)
+    }
+
+    /// Makes the [`Buttonlike`] `actions` mutually exclusive, such as the weapon-select actions of a weapon wheel.
     ///
-    /// For [`Buttonlike`] actions, this accounts for clashing inputs according to the [`ClashStrategy`] and removes conflicting actions.
+    /// When several actions of the group are pressed at once, only the one declared first stays pressed.
+    /// This is applied after clashes are resolved according to the [`ClashStrategy`].
     ///
-    /// [`Buttonlike`] inputs will be pressed if any of the associated inputs are pressed.
-    /// [`Axislike`] and [`DualAxislike`] inputs will be the sum of all associated inputs.
-    #[must_use]
-    pub fn process_actions(
+    /// An action belongs to at most one group: the `actions` are removed from any group they were already part of.
+    #[track_caller]
+    pub fn set_exclusive_group(&mut self, actions: &[A]) -> &mut Self {
+        for action in actions {
+            debug_assert!(
+                action.input_control_kind() == InputControlKind::Button,
+                "Cannot add action {:?} of kind {:?} to an exclusive group",
+                action,
+                action.input_control_kind()
+            );
+
+            if action.input_control_kind() != InputControlKind::Button {
+                error!(
+                    "Cannot add action {:?} of kind {:?} to an exclusive group",
+                    action,
+                    action.input_control_kind()
+                );
+
+                return self;
+            }
+        }
+
+        let mut group: Vec<A> = Vec::with_capacity(actions.len());
+        for action in actions {
+            if !group.contains(action) {
+                group.push(action.clone());
+            }
+        }
+
+        for existing_group in self.exclusive_groups.iter_mut() {
+            existing_group.retain(|action| !group.contains(action));
+        }
+        self.exclusive_groups
+            .retain(|existing_group| existing_group.len() > 1);
+
+        if group.len() > 1 {
+            self.exclusive_groups.push(group);
+        }
+        self
+    }
+
+    /// Makes the [`Buttonlike`] `actions` mutually exclusive, as in [`InputMap::set_exclusive_group`].
+    #[inline]
+    pub fn with_exclusive_group(mut self, actions: &[A]) -> Self {
+        self.set_exclusive_group(actions);
+        self
+    }
+
+    /// Removes all groups of mutually exclusive actions.
+    #[inline]
+    pub fn clear_exclusive_groups(&mut self) -> &mut Self {
+        self.exclusive_groups.clear();
+        self
+    }
+
+    /// Fetches the cooldown of the `action`, if one has been set.
+    #[must_use]
+    #[inline]
+    pub fn cooldown(&self, action: &A) -> Option<Duration> {
+        self.cooldowns.get(action).copied()
+    }
+
+    /// Returns an iterator over all configured action cooldowns.
+    #[inline]
+    pub fn iter_cooldowns(&self) -> impl Iterator<Item = (&A, &Duration)> {
+        self.cooldowns.iter()
+    }
+
+    /// Sets the cooldown of the [`Buttonlike`] `action`.
+    ///
+    /// After the `action` fires, [`ActionState::just_pressed`](crate::action_state::ActionState::just_pressed)
+    /// is suppressed until the cooldown elapses.
+    /// The time left can be read with [`ActionState::cooldown_remaining`](crate::action_state::ActionState::cooldown_remaining).
+    ///
+    /// Setting a cooldown of [`Duration::ZERO`] disables it.
+    #[track_caller]
+    pub fn set_cooldown(&mut self, action: A, cooldown: Duration) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == InputControlKind::Button,
+            "Cannot set a cooldown for action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() != InputControlKind::Button {
+            error!(
+                "Cannot set a cooldown for action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        self.cooldowns.insert(action, cooldown);
+        self
+    }
+
+    /// Sets the cooldown of the [`Buttonlike`] `action`, as in [`InputMap::set_cooldown`].
+    #[inline]
+    pub fn with_cooldown(mut self, action: A, cooldown: Duration) -> Self {
+        self.set_cooldown(action, cooldown);
+        self
+    }
+
+    /// Clears the cooldown of the `action`, so that it can fire again right away.
+    ///
+    /// Returns the cooldown that was set, if any.
+    #[inline]
+    pub fn clear_cooldown(&mut self, action: &A) -> Option<Duration> {
+        self.cooldowns.remove(action)
+    }
+
+    /// Fetches the [`EdgeMode`] of the `action`, which is [`EdgeMode::Level`] unless set otherwise.
+    #[must_use]
+    #[inline]
+    pub fn edge_mode(&self, action: &A) -> EdgeMode {
+        self.edge_modes.get(action).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over all actions with a configured [`EdgeMode`].
+    #[inline]
+    pub fn iter_edge_modes(&self) -> impl Iterator<Item = (&A, &EdgeMode)> {
+        self.edge_modes.iter()
+    }
+
+    /// Sets the [`EdgeMode`] of the [`Buttonlike`] `action`,
+    /// controlling whether it fires while its inputs are pressed, or only as they change.
+    ///
+    /// This is mostly useful for actions bound to analog inputs crossing a threshold,
+    /// such as [`GamepadControlDirection`](crate::prelude::GamepadControlDirection).
+    #[track_caller]
+    pub fn set_edge_mode(&mut self, action: A, edge_mode: EdgeMode) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == InputControlKind::Button,
+            "Cannot set an edge mode for action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() != InputControlKind::Button {
+            error!(
+                "Cannot set an edge mode for action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        self.edge_modes.insert(action, edge_mode);
+        self
+    }
+
+    /// Sets the [`EdgeMode`] of the [`Buttonlike`] `action`, as in [`InputMap::set_edge_mode`].
+    #[inline]
+    pub fn with_edge_mode(mut self, action: A, edge_mode: EdgeMode) -> Self {
+        self.set_edge_mode(action, edge_mode);
+        self
+    }
+
+    /// Checks if the [`Buttonlike`] `action` is inverted, as configured by [`InputMap::set_inverted`].
+    #[must_use]
+    #[inline]
+    pub fn is_inverted(&self, action: &A) -> bool {
+        self.inverted_actions.contains(action)
+    }
+
+    /// Returns an iterator over all inverted [`Buttonlike`] actions.
+    #[inline]
+    pub fn iter_inverted(&self) -> impl Iterator<Item = &A> {
+        self.inverted_actions.iter()
+    }
+
+    /// Configures whether the [`Buttonlike`] `action` is inverted,
+    /// so that it is pressed while none of its bindings are, and released while any of them is.
+    ///
+    /// This is handy for accessibility options such as switching between "hold to talk" and "hold to mute",
+    /// without changing the bindings or the game logic reading the [`ActionState`](crate::action_state::ActionState).
+    /// The pressed state is negated after [clashes](crate::clashing_inputs) are resolved,
+    /// so [`just_pressed`](crate::action_state::ActionState::just_pressed) fires as the bindings are released
+    /// and [`just_released`](crate::action_state::ActionState::just_released) as they are pressed.
+    ///
+    /// Only actions with at least one binding are affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Talk,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Talk, KeyCode::KeyT)]);
+    ///
+    /// // Talk unless T is held
+    /// input_map.set_inverted(Action::Talk, true);
+    /// assert!(input_map.is_inverted(&Action::Talk));
+    /// ```
+    #[track_caller]
+    pub fn set_inverted(&mut self, action: A, inverted: bool) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == InputControlKind::Button,
+            "Cannot invert action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() != InputControlKind::Button {
+            error!(
+                "Cannot invert action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        if inverted {
+            self.inverted_actions.insert(action);
+        } else {
+            self.inverted_actions.remove(&action);
+        }
+        self
+    }
+
+    /// Configures whether the [`Buttonlike`] `action` is inverted, as in [`InputMap::set_inverted`].
+    #[inline]
+    pub fn with_inverted(mut self, action: A, inverted: bool) -> Self {
+        self.set_inverted(action, inverted);
+        self
+    }
+
+    /// Fetches the [`CombinePolicy`] of the `action`, which is [`CombinePolicy::Sum`] unless set otherwise.
+    #[must_use]
+    #[inline]
+    pub fn axis_combine(&self, action: &A) -> CombinePolicy {
+        self.axis_combine_policies
+            .get(action)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sets how the values of the bindings of the axislike `action` are combined,
+    /// such as when both a gamepad stick and a virtual D-pad are bound to it.
+    ///
+    /// The [`CombinePolicy`] is applied before any of the action's processors.
+    #[track_caller]
+    pub fn set_axis_combine(&mut self, action: A, policy: CombinePolicy) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() != InputControlKind::Button,
+            "Cannot set a combine policy for action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() == InputControlKind::Button {
+            error!(
+                "Cannot set a combine policy for action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        if policy == CombinePolicy::Sum {
+            self.axis_combine_policies.remove(&action);
+        } else {
+            self.axis_combine_policies.insert(action, policy);
+        }
+        self
+    }
+
+    /// Sets how the values of the bindings of the axislike `action` are combined, as in [`InputMap::set_axis_combine`].
+    #[inline]
+    pub fn with_axis_combine(mut self, action: A, policy: CombinePolicy) -> Self {
+        self.set_axis_combine(action, policy);
+        self
+    }
+
+    /// Fetches the [`Buttonlike`] action that inverts the axislike `action` while pressed, along with the inverted axes.
+    #[must_use]
+    #[inline]
+    pub fn conditional_inversion(&self, action: &A) -> Option<(&A, DualAxisInverted)> {
+        self.conditional_inversions
+            .get(action)
+            .map(|(gate, inverted)| (gate, *inverted))
+    }
+
+    /// Inverts the axislike `action` while the [`Buttonlike`] `gate` action is pressed,
+    /// such as to let players flip the Y axis of the camera by holding a modifier.
+    ///
+    /// This is applied after the processors of the `action`, and after clashes, aliases and exclusive groups are resolved,
+    /// so a `gate` with bindings only counts as pressed if they press it during the same update.
+    /// A `gate` without any bindings is read from the [`ActionState`](crate::action_state::ActionState) instead
+    /// by [`update_action_state`](crate::systems::update_action_state),
+    /// so it can be pressed with [`ActionState::press`](crate::action_state::ActionState::press).
+    /// For [`Axislike`] actions, the value is inverted if `inverted` inverts the X axis.
+    ///
+    /// Replaces any conditional inversion previously set for the `action`.
+    #[track_caller]
+    pub fn set_conditional_inversion(
+        &mut self,
+        action: A,
+        gate: A,
+        inverted: DualAxisInverted,
+    ) -> &mut Self {
+        let valid = matches!(
+            action.input_control_kind(),
+            InputControlKind::Axis | InputControlKind::DualAxis
+        ) && gate.input_control_kind() == InputControlKind::Button;
+
+        debug_assert!(
+            valid,
+            "Cannot invert action {:?} of kind {:?} while action {:?} of kind {:?} is pressed",
+            action,
+            action.input_control_kind(),
+            gate,
+            gate.input_control_kind()
+        );
+
+        if !valid {
+            error!(
+                "Cannot invert action {:?} of kind {:?} while action {:?} of kind {:?} is pressed",
+                action,
+                action.input_control_kind(),
+                gate,
+                gate.input_control_kind()
+            );
+
+            return self;
+        }
+
+        self.conditional_inversions.insert(action, (gate, inverted));
+        self
+    }
+
+    /// Inverts the axislike `action` while the `gate` action is pressed, as in [`InputMap::set_conditional_inversion`].
+    #[inline]
+    pub fn with_conditional_inversion(
+        mut self,
+        action: A,
+        gate: A,
+        inverted: DualAxisInverted,
+    ) -> Self {
+        self.set_conditional_inversion(action, gate, inverted);
+        self
+    }
+
+    /// Clears the conditional inversion of the `action`.
+    ///
+    /// Returns the action that gated it and the inverted axes, if any.
+    #[inline]
+    pub fn clear_conditional_inversion(&mut self, action: &A) -> Option<(A, DualAxisInverted)> {
+        self.conditional_inversions.remove(action)
+    }
+
+    /// Fetches the action whose bindings the `action` is aliased to, if any.
+    #[must_use]
+    #[inline]
+    pub fn alias_target(&self, action: &A) -> Option<&A> {
+        self.aliases.get(action)
+    }
+
+    /// Returns an iterator over all aliased actions, along with the actions whose bindings they read.
+    #[inline]
+    pub fn iter_aliases(&self) -> impl Iterator<Item = (&A, &A)> {
+        self.aliases.iter()
+    }
+
+    /// Aliases the `action` to the bindings of the `target` action, until [`InputMap::clear_alias`] is called.
+    ///
+    /// While aliased, the `action` takes on the value of the `target` after processing,
+    /// ignoring its own bindings, which are kept untouched.
+    /// Those bindings take no part in clash resolution,
+    /// while exclusive groups see the value taken from the `target`.
+    /// This is useful for control scheme presets, where several actions temporarily share the same inputs.
+    ///
+    /// Aliasing to an action that is itself aliased reads the bindings of its final target.
+    /// The `action` and the `target` must be of the same [`InputControlKind`],
+    /// and an action can't be aliased to itself.
+    #[track_caller]
+    pub fn alias(&mut self, action: A, target: A) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == target.input_control_kind(),
+            "Cannot alias action {:?} of kind {:?} to action {:?} of kind {:?}",
+            action,
+            action.input_control_kind(),
+            target,
+            target.input_control_kind()
+        );
+
+        if action.input_control_kind() != target.input_control_kind() {
+            error!(
+                "Cannot alias action {:?} of kind {:?} to action {:?} of kind {:?}",
+                action,
+                action.input_control_kind(),
+                target,
+                target.input_control_kind()
+            );
+
+            return self;
+        }
+
+        let target = self.aliases.get(&target).cloned().unwrap_or(target);
+        if target == action {
+            error!("Cannot alias action {:?} to itself", action);
+            return self;
+        }
+
+        // Actions reading the bindings of `action` now read those of its target instead
+        for other_target in self.aliases.values_mut() {
+            if *other_target == action {
+                *other_target = target.clone();
+            }
+        }
+
+        self.aliases.insert(action, target);
+        self
+    }
+
+    /// Aliases the `action` to the bindings of the `target` action, as in [`InputMap::alias`].
+    #[inline]
+    pub fn with_alias(mut self, action: A, target: A) -> Self {
+        self.alias(action, target);
+        self
+    }
+
+    /// Clears the alias of the `action`, so that it reads its own bindings again.
+    ///
+    /// Returns the action it was aliased to, if any.
+    #[inline]
+    pub fn clear_alias(&mut self, action: &A) -> Option<A> {
+        self.aliases.remove(action)
+    }
+
+    /// Returns the processors applied to the [`Axislike`] `action`, after combining the values of its bindings.
+    #[inline]
+    pub fn axis_processors(&self, action: &A) -> &[AxisProcessor] {
+        self.axis_processors
+            .get(action)
+            .map_or(&[], |processors| processors.as_slice())
+    }
+
+    /// Replaces the processors applied to the [`Axislike`] `action`.
+    ///
+    /// Unlike the processors of each input, these are applied to the summed value of all bindings of the `action`,
+    /// and are kept when its bindings are cleared or rebound.
+    /// Passing no processors removes them.
+    #[track_caller]
+    pub fn set_axis_processors(
+        &mut self,
+        action: A,
+        processors: impl IntoIterator<Item = AxisProcessor>,
+    ) -> &mut Self {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Axis);
+
+        let processors: Vec<AxisProcessor> = processors.into_iter().collect();
+        if processors.is_empty() {
+            self.axis_processors.remove(&action);
+        } else {
+            self.axis_processors.insert(action, processors);
+        }
+        self
+    }
+
+    /// Replaces the processors applied to the [`Axislike`] `action`, as in [`InputMap::set_axis_processors`].
+    #[inline]
+    pub fn with_axis_processors(
+        mut self,
+        action: A,
+        processors: impl IntoIterator<Item = AxisProcessor>,
+    ) -> Self {
+        self.set_axis_processors(action, processors);
+        self
+    }
+
+    /// Returns the processors applied to the [`DualAxislike`] `action`, after combining the values of its bindings.
+    #[inline]
+    pub fn dual_axis_processors(&self, action: &A) -> &[DualAxisProcessor] {
+        self.dual_axis_processors
+            .get(action)
+            .map_or(&[], |processors| processors.as_slice())
+    }
+
+    /// Replaces the processors applied to the [`DualAxislike`] `action`.
+    ///
+    /// Unlike the processors of each input, these are applied to the summed value of all bindings of the `action`,
+    /// and are kept when its bindings are cleared or rebound.
+    /// Passing no processors removes them.
+    #[track_caller]
+    pub fn set_dual_axis_processors(
+        &mut self,
+        action: A,
+        processors: impl IntoIterator<Item = DualAxisProcessor>,
+    ) -> &mut Self {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+        let processors: Vec<DualAxisProcessor> = processors.into_iter().collect();
+        if processors.is_empty() {
+            self.dual_axis_processors.remove(&action);
+        } else {
+            self.dual_axis_processors.insert(action, processors);
+        }
+        self
+    }
+
+    /// Replaces the processors applied to the [`DualAxislike`] `action`, as in [`InputMap::set_dual_axis_processors`].
+    #[inline]
+    pub fn with_dual_axis_processors(
+        mut self,
+        action: A,
+        processors: impl IntoIterator<Item = DualAxisProcessor>,
+    ) -> Self {
+        self.set_dual_axis_processors(action, processors);
+        self
+    }
+
+    /// Checks if the [`Buttonlike`] `action` accepts input from any connected gamepad,
+    /// as configured by [`InputMap::set_any_gamepad`].
+    #[must_use]
+    #[inline]
+    pub fn accepts_any_gamepad(&self, action: &A) -> bool {
+        self.any_gamepad_actions.contains(action)
+    }
+
+    /// Returns an iterator over all [`Buttonlike`] actions that accept input from any connected gamepad.
+    #[inline]
+    pub fn iter_any_gamepad_actions(&self) -> impl Iterator<Item = &A> {
+        self.any_gamepad_actions.iter()
+    }
+
+    /// Lets the [`Buttonlike`] `action` be pressed from any connected gamepad,
+    /// rather than only the [associated gamepad](InputMap::gamepad).
+    ///
+    /// The gamepad that triggered the `action` can then be read with
+    /// [`ActionState::triggering_gamepad`](crate::action_state::ActionState::triggering_gamepad),
+    /// which is useful for "press Start to join" screens.
+    #[track_caller]
+    pub fn set_any_gamepad(&mut self, action: A) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == InputControlKind::Button,
+            "Cannot accept any gamepad for action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() != InputControlKind::Button {
+            error!(
+                "Cannot accept any gamepad for action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        self.any_gamepad_actions.insert(action);
+        self
+    }
+
+    /// Lets the [`Buttonlike`] `action` be pressed from any connected gamepad, as in [`InputMap::set_any_gamepad`].
+    #[inline]
+    pub fn with_any_gamepad(mut self, action: A) -> Self {
+        self.set_any_gamepad(action);
+        self
+    }
+
+    /// Finds the first of the `gamepads` whose own input is currently pressing the `action`.
+    ///
+    /// Bindings that are pressed regardless of the gamepad, such as keyboard keys, are ignored.
+    #[must_use]
+    pub fn triggering_gamepad(
+        &self,
+        action: &A,
+        gamepads: impl IntoIterator<Item = Entity>,
+        input_store: &CentralInputStore,
+    ) -> Option<Entity> {
+        let bindings = self.get_buttonlike(action)?;
+
+        // Gamepad inputs are never pressed for the placeholder entity,
+        // so any binding that is must come from another device.
+        gamepads.into_iter().find(|gamepad| {
+            bindings.iter().any(|binding| {
+                binding.pressed(input_store, *gamepad)
+                    && !binding.pressed(input_store, Entity::PLACEHOLDER)
+            })
+        })
+    }
+
+    /// Finds the index of the first [`Buttonlike`] binding of the `action` that is currently pressed,
+    /// reading gamepad inputs from the given `gamepad`.
+    ///
+    /// The index refers to the list returned by [`InputMap::get_buttonlike`].
+    /// This only reads the bindings themselves: [`InputMap::process_actions`] also accounts for
+    /// clashes, exclusive groups and inversion, reporting the result in [`ActionState::triggering_binding_index`](crate::action_state::ActionState::triggering_binding_index).
+    #[must_use]
+    pub fn triggering_binding_index(
+        &self,
+        action: &A,
+        gamepad: Entity,
+        input_store: &CentralInputStore,
+    ) -> Option<usize> {
+        self.get_buttonlike(action)?
+            .iter()
+            .position(|binding| binding.pressed(input_store, gamepad))
+    }
+
+    /// Returns the gamepad whose inputs are read by this map:
+    /// the [associated gamepad](InputMap::gamepad) if any, or else the first of the `gamepads`.
+    #[must_use]
+    pub(crate) fn resolve_gamepad(&self, gamepads: Option<Query<Entity, With<Gamepad>>>) -> Entity {
+        self.associated_gamepad.unwrap_or(find_gamepad(gamepads))
+    }
+}
+
+// Check whether actions are pressed
+impl<A: Actionlike> InputMap<A> {
+    /// Checks if the `action` are currently pressed by any of the associated [`Buttonlike`]s.
+    ///
+    /// Accounts for clashing inputs according to the [`ClashStrategy`] and remove conflicting actions.
+    #[must_use]
+    pub fn pressed(
+        &self,
+        action: &A,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+    ) -> bool {
+        let processed_actions = self.process_actions(None, input_store, clash_strategy);
+
+        let Some(updated_value) = processed_actions.get(action) else {
+            return false;
+        };
+
+        match updated_value {
+            UpdatedValue::Button(state) => *state,
+            _ => false,
+        }
+    }
+
+    /// Determines the correct state for each action according to provided [`CentralInputStore`].
+    ///
+    /// This method uses the input bindings for each action to determine how to parse the input data,
+    /// and generates corresponding [`ButtonData`](crate::action_state::ButtonData),
+    /// [`AxisData`](crate::action_state::AxisData) and [`DualAxisData`](crate::action_state::DualAxisData).
+    ///
+    /// For [`Buttonlike`] actions, this accounts for clashing inputs according to the [`ClashStrategy`] and removes conflicting actions.
+    /// If this map has its own [`ClashStrategy`] set, that is used in place of the `clash_strategy` passed in.
+    ///
+    /// [`Buttonlike`] inputs will be pressed if any of the associated inputs are pressed.
+    /// [`Axislike`] and [`DualAxislike`] inputs will be the sum of all associated inputs.
+    ///
+    /// This has no memory of earlier calls, so actions using [`CombinePolicy::LastActiveWins`]
+    /// take the value of their first active binding.
+    /// The bindings that most recently became active are tracked per entity by [`update_action_state`](crate::systems::update_action_state).
+    #[must_use]
+    pub fn process_actions(
         &self,
         gamepads: Option<Query<Entity, With<Gamepad>>>,
         input_store: &CentralInputStore,
         clash_strategy: ClashStrategy,
     ) -> UpdatedActions<A> {
+        self.process_actions_with_history(
+            gamepads,
+            input_store,
+            clash_strategy,
+            &mut LastActiveBindings::default(),
+            |_| false,
+        )
+        .0
+    }
+
+    /// Determines the correct state for each action as in [`InputMap::process_actions`],
+    /// recording which bindings most recently became active in `last_active_bindings`
+    /// and returning which binding pressed each [`Buttonlike`] action.
+    ///
+    /// Gates of conditional inversions that have no bindings of their own
+    /// count as pressed when `unbound_gate_pressed` returns `true` for them.
+    pub(crate) fn process_actions_with_history(
+        &self,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+        last_active_bindings: &mut LastActiveBindings<A>,
+        unbound_gate_pressed: impl Fn(&A) -> bool,
+    ) -> (UpdatedActions<A>, TriggeringBindings<A>) {
         let mut updated_actions = UpdatedActions::default();
-        let gamepad = self.associated_gamepad.unwrap_or(find_gamepad(gamepads));
+        let mut triggering_bindings = TriggeringBindings::default();
+        let connected_gamepads: Vec<Entity> = match (&gamepads, self.any_gamepad_actions.is_empty())
+        {
+            (Some(gamepads), false) => gamepads.iter().collect(),
+            _ => Vec::new(),
+        };
+        let gamepad = self.resolve_gamepad(gamepads);
 
-        // Generate the base action data for each action
+        // Generate the base action data for each action,
+        // leaving aliased actions out until they take on the value of their targets
         for (action, _input_bindings) in self.iter_buttonlike() {
-            let mut final_state = false;
-            for binding in _input_bindings {
-                if binding.pressed(input_store, gamepad) {
-                    final_state = true;
-                    break;
-                }
+            if self.aliases.contains_key(action) {
+                continue;
+            }
+
+            let mut triggering_binding =
+                self.triggering_binding_index(action, gamepad, input_store);
+
+            // Actions pressed from any gamepad read their bindings from the gamepad that pressed them
+            if triggering_binding.is_none() && self.accepts_any_gamepad(action) {
+                triggering_binding = self
+                    .triggering_gamepad(action, connected_gamepads.iter().copied(), input_store)
+                    .and_then(|gamepad| {
+                        self.triggering_binding_index(action, gamepad, input_store)
+                    });
+            }
+
+            let final_state = triggering_binding.is_some();
+            if let Some(index) = triggering_binding {
+                triggering_bindings.pressed.insert(action.clone(), index);
             }
 
             updated_actions.insert(action.clone(), UpdatedValue::Button(final_state));
         }
 
         for (action, _input_bindings) in self.iter_axislike() {
-            let mut final_value = 0.0;
-            for binding in _input_bindings {
-                final_value += binding.value(input_store, gamepad);
+            if self.aliases.contains_key(action) {
+                continue;
             }
 
+            let values: Vec<Vec3> = _input_bindings
+                .iter()
+                .map(|binding| Vec3::new(binding.value(input_store, gamepad), 0.0, 0.0))
+                .collect();
+            let mut final_value = self
+                .combine_axis_values(action, &values, last_active_bindings)
+                .x;
+
+            final_value = self
+                .axis_processors(action)
+                .iter()
+                .fold(final_value, |value, processor| {
+                    processor.process_with_delta(value, input_store.delta_secs())
+                });
+
             updated_actions.insert(action.clone(), UpdatedValue::Axis(final_value));
         }
 
         for (action, _input_bindings) in self.iter_dual_axislike() {
-            let mut final_value = Vec2::ZERO;
-            for binding in _input_bindings {
-                final_value += binding.axis_pair(input_store, gamepad);
+            if self.aliases.contains_key(action) {
+                continue;
             }
 
+            let values: Vec<Vec3> = _input_bindings
+                .iter()
+                .map(|binding| binding.axis_pair(input_store, gamepad).extend(0.0))
+                .collect();
+            let mut final_value = self
+                .combine_axis_values(action, &values, last_active_bindings)
+                .truncate();
+
+            final_value = self
+                .dual_axis_processors(action)
+                .iter()
+                .fold(final_value, |value, processor| {
+                    processor.process_with_delta(value, input_store.delta_secs())
+                });
+
             updated_actions.insert(action.clone(), UpdatedValue::DualAxis(final_value));
         }
 
         for (action, _input_bindings) in self.iter_triple_axislike() {
-            let mut final_value = Vec3::ZERO;
-            for binding in _input_bindings {
-                final_value += binding.axis_triple(input_store, gamepad);
+            if self.aliases.contains_key(action) {
+                continue;
             }
 
+            let values: Vec<Vec3> = _input_bindings
+                .iter()
+                .map(|binding| binding.axis_triple(input_store, gamepad))
+                .collect();
+            let final_value = self.combine_axis_values(action, &values, last_active_bindings);
+
             updated_actions.insert(action.clone(), UpdatedValue::TripleAxis(final_value));
         }
 
         // Handle clashing inputs, possibly removing some pressed actions from the list
+        let clash_strategy = self.clash_strategy.unwrap_or(clash_strategy);
         self.handle_clashes(&mut updated_actions, input_store, clash_strategy, gamepad);
 
-        updated_actions
+        // Actions released by clash resolution remember the binding that was pressing them
+        let TriggeringBindings {
+            pressed,
+            suppressed_by_clash,
+        } = &mut triggering_bindings;
+        pressed.retain(|action, index| {
+            let kept = updated_actions.contains_key(action);
+            if !kept {
+                suppressed_by_clash.insert(action.clone(), *index);
+            }
+            kept
+        });
+
+        // Inverted actions are pressed while their bindings aren't
+        for action in self.inverted_actions.iter() {
+            if let Some(UpdatedValue::Button(pressed)) = updated_actions.get_mut(action) {
+                *pressed = !*pressed;
+            }
+        }
+
+        // Aliased buttonlike actions take on the value of their targets,
+        // before exclusive groups and conditional inversions read them
+        self.apply_aliases(&mut updated_actions, |kind| {
+            kind == InputControlKind::Button
+        });
+
+        // Only the highest-priority pressed action of each exclusive group stays pressed
+        for group in self.exclusive_groups.iter() {
+            let mut found_pressed = false;
+            for action in group {
+                if let Some(UpdatedValue::Button(pressed)) = updated_actions.get_mut(action) {
+                    if found_pressed {
+                        *pressed = false;
+                    } else {
+                        found_pressed = *pressed;
+                    }
+                }
+            }
+        }
+
+        // Conditionally inverted actions are flipped while their gate is pressed
+        for (action, (gate, inverted)) in self.conditional_inversions.iter() {
+            let gate_pressed = match updated_actions.get(gate) {
+                Some(UpdatedValue::Button(pressed)) => *pressed,
+                _ => unbound_gate_pressed(gate),
+            };
+            if !gate_pressed {
+                continue;
+            }
+
+            match updated_actions.get_mut(action) {
+                Some(UpdatedValue::Axis(value)) if inverted.inverted().x => *value = -*value,
+                Some(UpdatedValue::DualAxis(pair)) => *pair = inverted.invert(*pair),
+                _ => {}
+            }
+        }
+
+        // Aliased axislike actions take on the final value of their targets
+        self.apply_aliases(&mut updated_actions, |kind| {
+            kind != InputControlKind::Button
+        });
+
+        // Only actions that are still pressed by their own bindings keep their triggering binding
+        triggering_bindings.pressed.retain(|action, _| {
+            updated_actions.pressed(action)
+                && !self.is_inverted(action)
+                && !self.aliases.contains_key(action)
+        });
+
+        (updated_actions, triggering_bindings)
+    }
+
+    /// Copies the value of the target of each aliased action whose kind matches the `filter` onto the action.
+    fn apply_aliases(
+        &self,
+        updated_actions: &mut UpdatedActions<A>,
+        filter: impl Fn(InputControlKind) -> bool,
+    ) {
+        for (action, target) in self.aliases.iter() {
+            let kind = action.input_control_kind();
+            if !filter(kind) {
+                continue;
+            }
+
+            let value = updated_actions.get(target).copied().unwrap_or(match kind {
+                InputControlKind::Button => UpdatedValue::Button(false),
+                InputControlKind::Axis => UpdatedValue::Axis(0.0),
+                InputControlKind::DualAxis => UpdatedValue::DualAxis(Vec2::ZERO),
+                InputControlKind::TripleAxis => UpdatedValue::TripleAxis(Vec3::ZERO),
+            });
+            updated_actions.insert(action.clone(), value);
+        }
+    }
+
+    /// Combines the `values` of the bindings of the axislike `action` according to its [`CombinePolicy`].
+    fn combine_axis_values(
+        &self,
+        action: &A,
+        values: &[Vec3],
+        last_active_bindings: &mut LastActiveBindings<A>,
+    ) -> Vec3 {
+        let policy = self.axis_combine(action);
+        let last_active = match policy {
+            CombinePolicy::LastActiveWins => last_active_bindings.update(action, values),
+            _ => None,
+        };
+
+        policy.combine(values, last_active)
+    }
+}
+
+/// Remembers which binding of each axislike action most recently left zero,
+/// for actions using [`CombinePolicy::LastActiveWins`].
+///
+/// This is kept separately for each [`InputMap`] by [`update_action_state`](crate::systems::update_action_state),
+/// as it depends on the inputs of earlier frames rather than on the map itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastActiveBindings<A: Actionlike>(HashMap<A, LastActiveBinding>);
+
+/// Which bindings of a single action were active when it was last processed,
+/// and which of them most recently became active.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct LastActiveBinding {
+    active: Vec<bool>,
+    index: Option<usize>,
+}
+
+impl<A: Actionlike> LastActiveBindings<A> {
+    /// Records the `values` of the bindings of the `action`,
+    /// returning the index of the binding that most recently left zero and is still active.
+    fn update(&mut self, action: &A, values: &[Vec3]) -> Option<usize> {
+        let binding = self.0.entry(action.clone()).or_default();
+        binding.active.resize(values.len(), false);
+
+        let mut newly_active = None;
+        for (index, value) in values.iter().enumerate() {
+            let active = *value != Vec3::ZERO;
+            if active && !binding.active[index] {
+                newly_active.get_or_insert(index);
+            }
+            binding.active[index] = active;
+        }
+
+        let still_active = binding
+            .index
+            .is_some_and(|index| binding.active.get(index).copied().unwrap_or(false));
+        if newly_active.is_some() {
+            binding.index = newly_active;
+        } else if !still_active {
+            binding.index = binding.active.iter().position(|active| *active);
+        }
+
+        binding.index
+    }
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Actionlike> Default for LastActiveBindings<A> {
+    fn default() -> Self {
+        Self(HashMap::default())
     }
 }
 
@@ -596,6 +2077,19 @@ impl<A: Actionlike> UpdatedActions<A> {
             _ => false,
         }
     }
+
+    /// Clamps the values of [`Axislike`] actions to `[-1.0, 1.0]`,
+    /// and those of [`DualAxislike`] actions to a magnitude of `1.0`, as with [`CircleBounds::new(1.0)`](CircleBounds::new).
+    pub fn clamp_axis_values(&mut self) {
+        let bounds = CircleBounds::new(1.0);
+        for value in self.0.values_mut() {
+            match value {
+                UpdatedValue::Axis(value) => *value = value.clamp(-1.0, 1.0),
+                UpdatedValue::DualAxis(value) => *value = bounds.clamp(*value),
+                UpdatedValue::Button(_) | UpdatedValue::TripleAxis(_) => {}
+            }
+        }
+    }
 }
 
 /// An enum representing the updated value of an action.
@@ -619,6 +2113,30 @@ impl<A: Actionlike> Default for UpdatedActions<A> {
     }
 }
 
+/// Which binding pressed each [`Buttonlike`] action, as determined alongside the [`UpdatedActions`]
+/// by [`InputMap::process_actions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggeringBindings<A: Actionlike> {
+    /// The index of the binding that is pressing each pressed action,
+    /// as reported by [`ActionState::triggering_binding_index`](crate::action_state::ActionState::triggering_binding_index).
+    ///
+    /// Actions that are pressed without any of their bindings being pressed,
+    /// such as inverted or aliased actions, have no entry.
+    pub(crate) pressed: HashMap<A, usize>,
+    /// The index of the pressed binding of each action that was released by [clash resolution](crate::clashing_inputs).
+    pub(crate) suppressed_by_clash: HashMap<A, usize>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Actionlike> Default for TriggeringBindings<A> {
+    fn default() -> Self {
+        Self {
+            pressed: HashMap::default(),
+            suppressed_by_clash: HashMap::default(),
+        }
+    }
+}
+
 // Utilities
 impl<A: Actionlike> InputMap<A> {
     /// Returns an iterator over all registered [`Buttonlike`] actions with their input bindings.
@@ -741,6 +2259,47 @@ impl<A: Actionlike> InputMap<A> {
         }
     }
 
+    /// Returns an iterator over the bindings of all actions that read from a device of the given class,
+    /// as determined by [`UserInput::reads_from`].
+    ///
+    /// This is useful to list the bindings of each device separately, such as in settings menus.
+    /// Bindings that combine several devices, such as a chord of a key and a mouse button, are listed for each of them.
+    ///
+    /// # Warning
+    ///
+    /// Like [`InputMap::get`], this method clones the inputs into [`UserInputWrapper`]s.
+    pub fn bindings_for_device(
+        &self,
+        device: DeviceClass,
+    ) -> impl Iterator<Item = (&A, UserInputWrapper)> {
+        let buttonlike = self.iter_buttonlike().flat_map(|(action, inputs)| {
+            inputs
+                .iter()
+                .map(move |input| (action, UserInputWrapper::Button(input.clone())))
+        });
+        let axislike = self.iter_axislike().flat_map(|(action, inputs)| {
+            inputs
+                .iter()
+                .map(move |input| (action, UserInputWrapper::Axis(input.clone())))
+        });
+        let dual_axislike = self.iter_dual_axislike().flat_map(|(action, inputs)| {
+            inputs
+                .iter()
+                .map(move |input| (action, UserInputWrapper::DualAxis(input.clone())))
+        });
+        let triple_axislike = self.iter_triple_axislike().flat_map(|(action, inputs)| {
+            inputs
+                .iter()
+                .map(move |input| (action, UserInputWrapper::TripleAxis(input.clone())))
+        });
+
+        buttonlike
+            .chain(axislike)
+            .chain(dual_axislike)
+            .chain(triple_axislike)
+            .filter(move |(_, input)| input.reads_from(device))
+    }
+
     /// Returns a reference to the [`Buttonlike`] inputs associated with the given `action`.
     #[must_use]
     pub fn get_buttonlike(&self, action: &A) -> Option<&Vec<Box<dyn Buttonlike>>> {
@@ -750,6 +2309,7 @@ impl<A: Actionlike> InputMap<A> {
     /// Returns a mutable reference to the [`Buttonlike`] inputs mapped to `action`
     #[must_use]
     pub fn get_buttonlike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn Buttonlike>>> {
+        self.clash_cache.invalidate();
         self.buttonlike_map.get_mut(action)
     }
 
@@ -792,6 +2352,126 @@ impl<A: Actionlike> InputMap<A> {
         self.triple_axislike_map.get_mut(action)
     }
 
+    /// Returns the actions that already have a binding using the given `input`,
+    /// either directly or as part of a composite input such as a chord or a virtual D-pad.
+    ///
+    /// This is useful for warning players when rebinding, e.g. "Space is already bound to Jump".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    ///     Run,
+    /// }
+    ///
+    /// let input_map = InputMap::new([(Action::Jump, KeyCode::Space), (Action::Run, KeyCode::ShiftLeft)]);
+    ///
+    /// assert_eq!(input_map.conflicts(&KeyCode::Space), vec![Action::Jump]);
+    /// assert!(input_map.conflicts(&KeyCode::KeyE).is_empty());
+    /// ```
+    #[must_use]
+    pub fn conflicts(&self, input: &dyn Buttonlike) -> Vec<A> {
+        let queried = input.decompose().inputs();
+        let uses_input = |basic_inputs: BasicInputs| {
+            basic_inputs
+                .inputs()
+                .iter()
+                .any(|basic_input| queried.contains(basic_input))
+        };
+
+        let mut conflicting_actions = Vec::new();
+        let mut add_if_conflicting = |action: &A, conflicting: bool| {
+            if conflicting && !conflicting_actions.contains(action) {
+                conflicting_actions.push(action.clone());
+            }
+        };
+
+        for (action, binding) in self.buttonlike_bindings() {
+            add_if_conflicting(action, uses_input(binding.decompose()));
+        }
+        for (action, binding) in self.axislike_bindings() {
+            add_if_conflicting(action, uses_input(binding.decompose()));
+        }
+        for (action, binding) in self.dual_axislike_bindings() {
+            add_if_conflicting(action, uses_input(binding.decompose()));
+        }
+        for (action, binding) in self.triple_axislike_bindings() {
+            add_if_conflicting(action, uses_input(binding.decompose()));
+        }
+
+        conflicting_actions
+    }
+
+    /// Exchanges all input bindings of `action_a` with those of `action_b`.
+    ///
+    /// If only one of the actions has bindings, they are moved to the other action.
+    /// Both actions must share the same [`InputControlKind`]; otherwise nothing is swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    ///     Run,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Jump, KeyCode::Space), (Action::Run, KeyCode::ShiftLeft)]);
+    /// input_map.swap_bindings(&Action::Jump, &Action::Run);
+    ///
+    /// assert_eq!(input_map.conflicts(&KeyCode::Space), vec![Action::Run]);
+    /// assert_eq!(input_map.conflicts(&KeyCode::ShiftLeft), vec![Action::Jump]);
+    /// ```
+    #[track_caller]
+    pub fn swap_bindings(&mut self, action_a: &A, action_b: &A) -> &mut Self {
+        debug_assert_eq!(
+            action_a.input_control_kind(),
+            action_b.input_control_kind(),
+            "Cannot swap the bindings of actions {:?} and {:?} of different kinds",
+            action_a,
+            action_b
+        );
+
+        if action_a.input_control_kind() != action_b.input_control_kind() {
+            error!(
+                "Cannot swap the bindings of actions {:?} and {:?} of different kinds",
+                action_a, action_b
+            );
+
+            return self;
+        }
+
+        match action_a.input_control_kind() {
+            InputControlKind::Button => {
+                swap_entries(&mut self.buttonlike_map, action_a, action_b);
+                self.clash_cache.invalidate();
+            }
+            InputControlKind::Axis => swap_entries(&mut self.axislike_map, action_a, action_b),
+            InputControlKind::DualAxis => {
+                swap_entries(&mut self.dual_axislike_map, action_a, action_b)
+            }
+            InputControlKind::TripleAxis => {
+                swap_entries(&mut self.triple_axislike_map, action_a, action_b)
+            }
+        }
+
+        self
+    }
+
+    /// Returns the cache of possible clashes between [`Buttonlike`] actions.
+    #[inline]
+    pub(crate) fn clash_cache(&self) -> &ClashCache<A> {
+        &self.clash_cache
+    }
+
     /// Count the total number of registered input bindings.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -813,12 +2493,43 @@ impl<A: Actionlike> InputMap<A> {
     }
 
     /// Clears the map, removing all action-input bindings.
+    ///
+    /// This is the same as [`InputMap::clear_bindings`]:
+    /// the processors set for each action and other settings are kept.
+    #[inline]
     pub fn clear(&mut self) {
+        self.clear_bindings();
+    }
+
+    /// Removes all action-input bindings, along with the processors attached to each input.
+    ///
+    /// The processors set for each action with [`InputMap::set_axis_processors`]
+    /// and [`InputMap::set_dual_axis_processors`] are kept,
+    /// and will apply again once the actions are rebound.
+    pub fn clear_bindings(&mut self) {
         self.buttonlike_map.clear();
+        self.clash_cache.invalidate();
         self.axislike_map.clear();
         self.dual_axislike_map.clear();
         self.triple_axislike_map.clear();
     }
+
+    /// Removes the processors set for each action with [`InputMap::set_axis_processors`]
+    /// and [`InputMap::set_dual_axis_processors`], keeping all bindings.
+    ///
+    /// Processors attached to individual inputs are part of their bindings, and are kept as well.
+    pub fn clear_processors(&mut self) {
+        self.axis_processors.clear();
+        self.dual_axis_processors.clear();
+    }
+
+    /// Removes all action-input bindings and the processors set for each action.
+    ///
+    /// Other settings, such as cooldowns and the associated gamepad, are kept.
+    pub fn clear_all(&mut self) {
+        self.clear_bindings();
+        self.clear_processors();
+    }
 }
 
 // Removing
@@ -828,6 +2539,7 @@ impl<A: Actionlike> InputMap<A> {
         match action.input_control_kind() {
             InputControlKind::Button => {
                 self.buttonlike_map.remove(action);
+                self.clash_cache.invalidate();
             }
             InputControlKind::Axis => {
                 self.axislike_map.remove(action);
@@ -854,6 +2566,7 @@ impl<A: Actionlike> InputMap<A> {
                 let input_bindings = self.buttonlike_map.get_mut(action)?;
                 if input_bindings.len() > index {
                     input_bindings.remove(index);
+                    self.clash_cache.invalidate();
                     Some(())
                 } else {
                     None
@@ -897,8 +2610,41 @@ impl<A: Actionlike> InputMap<A> {
         let boxed_input: Box<dyn Buttonlike> = Box::new(input);
         let index = bindings.iter().position(|input| input == &boxed_input)?;
         bindings.remove(index);
+        self.clash_cache.invalidate();
         Some(index)
     }
+
+    /// Retains only the bindings for which the predicate `f` returns `true`,
+    /// such as removing every gamepad binding when switching to keyboard-only controls.
+    ///
+    /// Actions left without any bindings are removed entirely.
+    ///
+    /// # Note
+    ///
+    /// Each input is cloned into a [`UserInputWrapper`] before being passed to `f`,
+    /// due to the lack of [trait upcasting coercion](https://github.com/rust-lang/rust/issues/65991).
+    pub fn retain(&mut self, f: impl Fn(&A, &UserInputWrapper) -> bool) {
+        self.buttonlike_map.retain(|action, inputs| {
+            inputs.retain(|input| f(action, &UserInputWrapper::Button(input.clone())));
+            !inputs.is_empty()
+        });
+        self.clash_cache.invalidate();
+
+        self.axislike_map.retain(|action, inputs| {
+            inputs.retain(|input| f(action, &UserInputWrapper::Axis(input.clone())));
+            !inputs.is_empty()
+        });
+
+        self.dual_axislike_map.retain(|action, inputs| {
+            inputs.retain(|input| f(action, &UserInputWrapper::DualAxis(input.clone())));
+            !inputs.is_empty()
+        });
+
+        self.triple_axislike_map.retain(|action, inputs| {
+            inputs.retain(|input| f(action, &UserInputWrapper::TripleAxis(input.clone())));
+            !inputs.is_empty()
+        });
+    }
 }
 
 impl<A: Actionlike, U: Buttonlike> From<HashMap<A, Vec<U>>> for InputMap<A> {
@@ -947,6 +2693,88 @@ impl<A: Actionlike, U: Buttonlike> FromIterator<(A, U)> for InputMap<A> {
     }
 }
 
+impl<A: Actionlike, U: Buttonlike> FromIterator<(A, Vec<U>)> for InputMap<A> {
+    /// Creates an [`InputMap`] from pairs of actions and the multiple [`Buttonlike`]s bound to each,
+    /// which is convenient when loading bindings from a data-driven table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// let input_map: InputMap<Action> = [
+    ///     (Action::Run, vec![KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+    ///     (Action::Jump, vec![KeyCode::Space]),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// ```
+    fn from_iter<T: IntoIterator<Item = (A, Vec<U>)>>(iter: T) -> Self {
+        let mut input_map = Self::default();
+        for (action, inputs) in iter.into_iter() {
+            input_map.insert_one_to_many(action, inputs);
+        }
+        input_map
+    }
+}
+
+/// Lists the bindings of each action on its own line, as `Action: Input, Input, ...`, for logging and debugging.
+///
+/// Actions are sorted by name, and their bindings are kept in the order they were inserted.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+/// enum Action {
+///     Run,
+///     Jump,
+/// }
+///
+/// let input_map = InputMap::new([(Action::Run, KeyCode::ShiftLeft), (Action::Jump, KeyCode::Space)])
+///     .with(Action::Jump, MouseButton::Right);
+///
+/// assert_eq!(input_map.to_string(), "Jump: Space, Right\nRun: ShiftLeft\n");
+/// ```
+impl<A: Actionlike> std::fmt::Display for InputMap<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn describe<T: Debug + ?Sized>(inputs: &[Box<T>]) -> Vec<String> {
+            inputs.iter().map(|input| format!("{input:?}")).collect()
+        }
+
+        let mut lines: Vec<(String, Vec<String>)> = self
+            .iter_buttonlike()
+            .map(|(action, inputs)| (format!("{action:?}"), describe(inputs)))
+            .chain(
+                self.iter_axislike()
+                    .map(|(action, inputs)| (format!("{action:?}"), describe(inputs))),
+            )
+            .chain(
+                self.iter_dual_axislike()
+                    .map(|(action, inputs)| (format!("{action:?}"), describe(inputs))),
+            )
+            .chain(
+                self.iter_triple_axislike()
+                    .map(|(action, inputs)| (format!("{action:?}"), describe(inputs))),
+            )
+            .collect();
+        lines.sort();
+
+        for (action, inputs) in lines {
+            writeln!(f, "{action}: {}", inputs.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "keyboard")]
 mod tests {
     use bevy::prelude::Reflect;
@@ -1016,6 +2844,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_iter_of_grouped_inputs() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map: InputMap<Action> = [
+            (Action::Run, vec![KeyCode::KeyW, KeyCode::ShiftLeft]),
+            (Action::Jump, vec![KeyCode::Space]),
+        ]
+        .into_iter()
+        .collect();
+
+        let expected_run: Vec<Box<dyn Buttonlike>> =
+            vec![Box::new(KeyCode::KeyW), Box::new(KeyCode::ShiftLeft)];
+        let expected_jump: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::Space)];
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected_run));
+        assert_eq!(
+            input_map.get_buttonlike(&Action::Jump),
+            Some(&expected_jump)
+        );
+        assert_eq!(
+            input_map,
+            InputMap::new([(Action::Run, KeyCode::KeyW)])
+                .with(Action::Run, KeyCode::ShiftLeft)
+                .with(Action::Jump, KeyCode::Space)
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Jump, KeyCode::Space)
+            .with(
+                Action::Hide,
+                ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::Space]),
+            )
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let mut space_conflicts = input_map.conflicts(&KeyCode::Space);
+        space_conflicts.sort_by_key(|action| format!("{action:?}"));
+        assert_eq!(space_conflicts, vec![Action::Hide, Action::Jump]);
+
+        assert_eq!(input_map.conflicts(&KeyCode::KeyW), vec![Action::DualAxis]);
+        assert!(input_map.conflicts(&KeyCode::KeyE).is_empty());
+    }
+
     #[test]
     fn insertion_idempotency() {
         use bevy::input::keyboard::KeyCode;
@@ -1070,6 +2946,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn swapping_bindings() {
+        use bevy::input::{keyboard::KeyCode, InputPlugin};
+        use bevy::prelude::App;
+
+        use crate::plugin::CentralInputStorePlugin;
+
+        let mut input_map = InputMap::default();
+        input_map.insert(Action::Run, KeyCode::KeyR);
+        input_map.insert(Action::Jump, KeyCode::Space);
+
+        input_map.swap_bindings(&Action::Run, &Action::Jump);
+
+        let run: Box<dyn Buttonlike> = Box::new(KeyCode::Space);
+        let jump: Box<dyn Buttonlike> = Box::new(KeyCode::KeyR);
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&vec![run]));
+        assert_eq!(input_map.get_buttonlike(&Action::Jump), Some(&vec![jump]));
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, CentralInputStorePlugin));
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        let input_store = app.world().resource::<CentralInputStore>();
+        assert!(input_map.pressed(&Action::Run, input_store, ClashStrategy::PressAll));
+        assert!(!input_map.pressed(&Action::Jump, input_store, ClashStrategy::PressAll));
+
+        // Moving bindings to an unbound action leaves the source empty
+        input_map.swap_bindings(&Action::Run, &Action::Hide);
+        assert!(input_map.get_buttonlike(&Action::Run).is_none());
+        assert_eq!(input_map.get_buttonlike(&Action::Hide).unwrap().len(), 1);
+    }
+
     #[test]
     fn merging() {
         use bevy::input::keyboard::KeyCode;
@@ -1095,6 +3003,161 @@ mod tests {
         assert_eq!(input_map, default_keyboard_map);
     }
 
+    #[cfg(all(feature = "gamepad", feature = "keyboard"))]
+    #[test]
+    fn retaining() {
+        use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode};
+
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, GamepadButton::LeftTrigger)
+            .with(Action::Jump, GamepadButton::South)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+            .with_dual_axis(Action::DualAxis, GamepadStick::LEFT);
+
+        // Drop all gamepad bindings
+        input_map.retain(|_, input| match input {
+            UserInputWrapper::Button(button) => !Reflect::as_any(&**button).is::<GamepadButton>(),
+            UserInputWrapper::DualAxis(dual_axis) => {
+                !Reflect::as_any(&**dual_axis).is::<GamepadStick>()
+            }
+            _ => true,
+        });
+
+        let expected = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+        assert_eq!(input_map, expected);
+        assert!(input_map.get_buttonlike(&Action::Jump).is_none());
+    }
+
+    #[test]
+    fn clearing_bindings_keeps_processors() {
+        use crate::buttonlike::ButtonValue;
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::KeyD, ButtonValue::from_pressed(true));
+        input_store.update_buttonlike(KeyCode::ArrowRight, ButtonValue::from_pressed(true));
+        let axis_value = |input_map: &InputMap<Action>| {
+            let updated_actions =
+                input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+            match updated_actions.get(&Action::Axis) {
+                Some(UpdatedValue::Axis(value)) => *value,
+                _ => 0.0,
+            }
+        };
+
+        let mut input_map = InputMap::default()
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_axis_processors(Action::Axis, [AxisProcessor::Sensitivity(0.5)]);
+        assert_eq!(axis_value(&input_map), 0.5);
+
+        // Clearing the bindings keeps the processors of the action
+        input_map.clear_bindings();
+        assert!(input_map.is_empty());
+        assert_eq!(
+            input_map.axis_processors(&Action::Axis),
+            [AxisProcessor::Sensitivity(0.5)]
+        );
+
+        // So they apply to the new binding
+        input_map.insert_axis(Action::Axis, VirtualAxis::horizontal_arrow_keys());
+        assert_eq!(axis_value(&input_map), 0.5);
+
+        // Clearing the processors keeps the bindings
+        input_map.clear_processors();
+        assert!(input_map.axis_processors(&Action::Axis).is_empty());
+        assert_eq!(axis_value(&input_map), 1.0);
+
+        // Clearing everything leaves nothing behind
+        input_map.set_axis_processors(Action::Axis, [AxisProcessor::Inverted]);
+        input_map.clear_all();
+        assert!(input_map.is_empty());
+        assert!(input_map.axis_processors(&Action::Axis).is_empty());
+    }
+
+    #[test]
+    fn display() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Jump, KeyCode::Space)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let description = input_map.to_string();
+        let lines: Vec<&str> = description.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("DualAxis: VirtualDPad"));
+        assert!(lines[0].contains("KeyW"));
+        assert_eq!(lines[1], "Jump: Space");
+        assert_eq!(lines[2], "Run: ShiftLeft, KeyR");
+
+        assert_eq!(InputMap::<Action>::default().to_string(), "");
+    }
+
+    #[cfg(all(feature = "gamepad", feature = "keyboard", feature = "mouse"))]
+    #[test]
+    fn bindings_for_device() {
+        use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton};
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, GamepadButton::LeftThumb)
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Jump, GamepadButton::South)
+            .with(
+                Action::Hide,
+                ButtonlikeChord::new([KeyCode::ControlLeft]).with(MouseButton::Right),
+            )
+            .with_axis(Action::Axis, GamepadControlAxis::LEFT_X)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let mut keyboard_bindings: Vec<_> = input_map
+            .bindings_for_device(DeviceClass::Keyboard)
+            .collect();
+        keyboard_bindings.sort_by_key(|(action, _)| format!("{action:?}"));
+        assert_eq!(
+            keyboard_bindings,
+            [
+                (
+                    &Action::DualAxis,
+                    UserInputWrapper::DualAxis(Box::new(VirtualDPad::wasd()))
+                ),
+                (
+                    &Action::Hide,
+                    UserInputWrapper::Button(Box::new(
+                        ButtonlikeChord::new([KeyCode::ControlLeft]).with(MouseButton::Right)
+                    ))
+                ),
+                (
+                    &Action::Jump,
+                    UserInputWrapper::Button(Box::new(KeyCode::Space))
+                ),
+                (
+                    &Action::Run,
+                    UserInputWrapper::Button(Box::new(KeyCode::ShiftLeft))
+                ),
+            ]
+        );
+
+        let mut gamepad_actions: Vec<_> = input_map
+            .bindings_for_device(DeviceClass::Gamepad)
+            .map(|(action, _)| action.clone())
+            .collect();
+        gamepad_actions.sort_by_key(|action| format!("{action:?}"));
+        assert_eq!(gamepad_actions, [Action::Axis, Action::Jump, Action::Run]);
+
+        // Chords mixing devices are listed for each of them
+        let mouse_actions: Vec<_> = input_map
+            .bindings_for_device(DeviceClass::Mouse)
+            .map(|(action, _)| action)
+            .collect();
+        assert_eq!(mouse_actions, [&Action::Hide]);
+    }
+
     #[cfg(feature = "gamepad")]
     #[test]
     fn gamepad_swapping() {
@@ -0,0 +1,246 @@
+//! Undo and redo for [`InputMap`] edits, used to build rebinding menus.
+
+use bevy::prelude::{ReflectResource, Resource};
+use bevy::reflect::Reflect;
+
+use crate::input_map::InputMap;
+use crate::user_input::Buttonlike;
+use crate::Actionlike;
+
+/// Wraps an [`InputMap`], recording each edit so that it can be undone and redone.
+///
+/// Every mutation made through [`InputMapHistory::edit`] (or the shorthands built on it)
+/// that actually changes the map pushes the previous bindings onto the undo stack.
+/// Making a new edit after undoing discards the edits that could have been redone.
+///
+/// The wrapped map isn't read by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin),
+/// so copy [`InputMapHistory::input_map`] over the map in use once the player is done rebinding.
+///
+/// # Examples
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut history = InputMapHistory::new(InputMap::new([(Action::Jump, KeyCode::Space)]));
+/// history.rebind(Action::Jump, KeyCode::KeyW);
+/// assert_eq!(history.input_map(), &InputMap::new([(Action::Jump, KeyCode::KeyW)]));
+///
+/// assert!(history.undo());
+/// assert_eq!(history.input_map(), &InputMap::new([(Action::Jump, KeyCode::Space)]));
+///
+/// assert!(history.redo());
+/// assert_eq!(history.input_map(), &InputMap::new([(Action::Jump, KeyCode::KeyW)]));
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct InputMapHistory<A: Actionlike> {
+    /// The current bindings.
+    input_map: InputMap<A>,
+
+    /// The bindings before each recorded edit, with the most recent last.
+    undo_stack: Vec<InputMap<A>>,
+
+    /// The bindings that were undone, with the most recently undone last.
+    redo_stack: Vec<InputMap<A>>,
+}
+
+impl<A: Actionlike> Default for InputMapHistory<A> {
+    fn default() -> Self {
+        Self::new(InputMap::default())
+    }
+}
+
+impl<A: Actionlike> InputMapHistory<A> {
+    /// Creates a new [`InputMapHistory`] starting from the given `input_map`, with no recorded edits.
+    #[inline]
+    pub fn new(input_map: InputMap<A>) -> Self {
+        Self {
+            input_map,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    /// Returns the current [`InputMap`].
+    #[must_use]
+    #[inline]
+    pub fn input_map(&self) -> &InputMap<A> {
+        &self.input_map
+    }
+
+    /// Consumes the history, returning the current [`InputMap`].
+    #[must_use]
+    #[inline]
+    pub fn into_input_map(self) -> InputMap<A> {
+        self.input_map
+    }
+
+    /// Applies the `edit` to the current [`InputMap`], recording it so that it can be undone.
+    ///
+    /// Edits that leave the map unchanged are not recorded.
+    pub fn edit(&mut self, edit: impl FnOnce(&mut InputMap<A>)) -> &mut Self {
+        let previous = self.input_map.clone();
+        edit(&mut self.input_map);
+
+        if self.input_map != previous {
+            self.undo_stack.push(previous);
+            self.redo_stack.clear();
+        }
+        self
+    }
+
+    /// Binds the `action` to the `button` in addition to its existing bindings, recording the edit.
+    ///
+    /// See [`InputMap::insert`].
+    #[inline]
+    pub fn insert(&mut self, action: A, button: impl Buttonlike) -> &mut Self {
+        self.edit(|input_map| {
+            input_map.insert(action, button);
+        })
+    }
+
+    /// Removes the `button` from the bindings of the `action`, recording the edit.
+    ///
+    /// See [`InputMap::remove`].
+    #[inline]
+    pub fn remove(&mut self, action: &A, button: impl Buttonlike) -> &mut Self {
+        self.edit(|input_map| {
+            input_map.remove(action, button);
+        })
+    }
+
+    /// Clears all bindings of the `action`, recording the edit.
+    ///
+    /// See [`InputMap::clear_action`].
+    #[inline]
+    pub fn clear_action(&mut self, action: &A) -> &mut Self {
+        self.edit(|input_map| input_map.clear_action(action))
+    }
+
+    /// Replaces all bindings of the `action` with the `button`, recording it as a single edit.
+    #[inline]
+    pub fn rebind(&mut self, action: A, button: impl Buttonlike) -> &mut Self {
+        self.edit(|input_map| {
+            input_map.clear_action(&action);
+            input_map.insert(action, button);
+        })
+    }
+
+    /// Reverts the most recent edit.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo_stack.pop() else {
+            return false;
+        };
+
+        let current = std::mem::replace(&mut self.input_map, previous);
+        self.redo_stack.push(current);
+        true
+    }
+
+    /// Re-applies the most recently undone edit.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo_stack.pop() else {
+            return false;
+        };
+
+        let current = std::mem::replace(&mut self.input_map, next);
+        self.undo_stack.push(current);
+        true
+    }
+
+    /// Returns `true` if there is an edit that can be undone.
+    #[must_use]
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is an undone edit that can be redone.
+    #[must_use]
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Forgets all recorded edits, keeping the current [`InputMap`].
+    #[inline]
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate as leafwing_input_manager;
+
+    #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    enum Action {
+        Jump,
+        Run,
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn undo_and_redo_rebinds() {
+        let original = InputMap::new([
+            (Action::Jump, KeyCode::Space),
+            (Action::Run, KeyCode::ShiftLeft),
+        ]);
+        let mut history = InputMapHistory::new(original.clone());
+        assert!(!history.can_undo());
+        assert!(!history.undo());
+
+        history.rebind(Action::Jump, KeyCode::KeyW);
+        let rebound = history.input_map().clone();
+        history.insert(Action::Run, KeyCode::ShiftRight);
+        let extended = history.input_map().clone();
+        history.remove(&Action::Run, KeyCode::ShiftLeft);
+        let removed = history.input_map().clone();
+
+        assert_eq!(
+            removed,
+            InputMap::new([
+                (Action::Jump, KeyCode::KeyW),
+                (Action::Run, KeyCode::ShiftRight)
+            ])
+        );
+
+        // Edits that don't change anything aren't recorded
+        history.remove(&Action::Run, KeyCode::KeyQ);
+
+        // Undoing walks back through each prior state
+        assert!(history.undo());
+        assert_eq!(history.input_map(), &extended);
+        assert!(history.undo());
+        assert_eq!(history.input_map(), &rebound);
+        assert!(history.undo());
+        assert_eq!(history.input_map(), &original);
+        assert!(!history.undo());
+
+        // Redoing walks forward again
+        assert!(history.can_redo());
+        assert!(history.redo());
+        assert_eq!(history.input_map(), &rebound);
+
+        // A new edit discards the undone ones
+        history.clear_action(&Action::Run);
+        assert!(!history.can_redo());
+        assert!(!history.redo());
+        assert!(history.undo());
+        assert_eq!(history.input_map(), &rebound);
+    }
+}
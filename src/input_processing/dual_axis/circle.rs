@@ -9,7 +9,7 @@ use bevy::{
 };
 use serde::{Deserialize, Serialize};
 
-use super::DualAxisProcessor;
+use super::{DualAxisBounds, DualAxisProcessor};
 
 /// Specifies a circular region defining acceptable ranges for valid dual-axis inputs,
 /// with a radius defining the maximum threshold magnitude,
@@ -179,6 +179,24 @@ impl CircleExclusion {
         input_value.length_squared() <= self.radius_squared
     }
 
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone begins.
+    ///
+    /// As the exclusion is circular, both components are equal to the [`radius`](Self::radius).
+    #[must_use]
+    #[inline]
+    pub fn livezone_lower_bound(&self) -> Vec2 {
+        Vec2::splat(self.radius())
+    }
+
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone ends.
+    ///
+    /// As exclusions don't limit input values, this is always [`Vec2::INFINITY`].
+    #[must_use]
+    #[inline]
+    pub fn livezone_upper_bound(&self) -> Vec2 {
+        Vec2::INFINITY
+    }
+
     /// Creates a [`CircleDeadZone`] using `self` as the exclusion range.
     #[inline]
     pub fn scaled(self) -> CircleDeadZone {
@@ -334,6 +352,24 @@ impl CircleDeadZone {
         (self.radius, self.bounds().radius)
     }
 
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone begins.
+    ///
+    /// As the deadzone is circular, both components are equal to the inner radius of the live zone.
+    #[must_use]
+    #[inline]
+    pub fn livezone_lower_bound(&self) -> Vec2 {
+        Vec2::splat(self.livezone_min_max().0)
+    }
+
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone ends.
+    ///
+    /// As the deadzone is circular, both components are equal to the outer radius of the live zone.
+    #[must_use]
+    #[inline]
+    pub fn livezone_upper_bound(&self) -> Vec2 {
+        Vec2::splat(self.livezone_min_max().1)
+    }
+
     /// Is the given `input_value` within the exclusion range?
     #[must_use]
     #[inline]
@@ -398,6 +434,179 @@ impl Hash for CircleDeadZone {
     }
 }
 
+/// An anti-deadzone that counteracts a deadzone applied after processing, such as by the game engine,
+/// by lifting the magnitude of any non-zero input value to at least a minimum output.
+///
+/// Input magnitudes in `[0.0, 1.0]` are scaled linearly into `[min_output, 1.0]`,
+/// preserving the input direction, while larger magnitudes are restricted to `1.0`.
+/// Zero input values remain zero.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Lift any movement to a magnitude of at least 0.2
+/// let anti_deadzone = CircleAntiDeadZone::new(0.2);
+///
+/// for x in -300..300 {
+///     let x = x as f32 * 0.01;
+///     for y in -300..300 {
+///         let y = y as f32 * 0.01;
+///         let value = Vec2::new(x, y);
+///         let processed = anti_deadzone.lift(value);
+///
+///         // Zero values stay zero.
+///         if value == Vec2::ZERO {
+///             assert_eq!(processed, Vec2::ZERO);
+///         }
+///
+///         // Other values keep their direction, with a magnitude scaled into [0.2, 1.0].
+///         else {
+///             let expected = value.normalize() * 0.2.lerp(1.0, value.length().min(1.0));
+///             let delta = (processed - expected).abs();
+///             assert!(delta.x <= 0.00001);
+///             assert!(delta.y <= 0.00001);
+///         }
+///     }
+/// }
+/// ```
+#[doc(alias = "AntiDeadZone")]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct CircleAntiDeadZone {
+    /// The minimum magnitude of non-zero output values.
+    pub(crate) min_output: f32,
+}
+
+impl CircleAntiDeadZone {
+    /// Zero-size [`CircleAntiDeadZone`], only restricting values to a maximum magnitude of `1.0`.
+    pub const ZERO: Self = Self { min_output: 0.0 };
+
+    /// Creates a [`CircleAntiDeadZone`] that lifts non-zero input values to at least `min_output`.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `min_output` <= `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(min_output: f32) -> Self {
+        assert!((0.0..=1.0).contains(&min_output));
+        Self { min_output }
+    }
+
+    /// Returns the minimum magnitude of non-zero output values.
+    #[must_use]
+    #[inline]
+    pub fn min_output(&self) -> f32 {
+        self.min_output
+    }
+
+    /// Scales the magnitude of non-zero input values into `[min_output, 1.0]`, preserving their direction.
+    #[must_use]
+    pub fn lift(&self, input_value: Vec2) -> Vec2 {
+        let input_length = input_value.length();
+        if input_length == 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let clamped_input_length = input_length.min(1.0);
+        let output_length = self.min_output + (1.0 - self.min_output) * clamped_input_length;
+        input_value * (output_length / input_length)
+    }
+}
+
+impl Default for CircleAntiDeadZone {
+    /// Creates a [`CircleAntiDeadZone`] that lifts non-zero input values to at least `0.1`.
+    #[inline]
+    fn default() -> Self {
+        CircleAntiDeadZone::new(0.1)
+    }
+}
+
+impl From<CircleAntiDeadZone> for DualAxisProcessor {
+    fn from(value: CircleAntiDeadZone) -> Self {
+        Self::CircleAntiDeadZone(value)
+    }
+}
+
+impl Eq for CircleAntiDeadZone {}
+
+impl Hash for CircleAntiDeadZone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.min_output).hash(state);
+    }
+}
+
+/// A [`CircleDeadZone`] followed by [`DualAxisBounds`], fused into a single processing step.
+///
+/// This produces the same results as a pipeline of the two processors,
+/// but avoids the extra processing step, which matters for inputs processed in hot loops.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Exclude magnitudes less than or equal to 0.2, then restrict X to [-0.5, 0.5]
+/// let deadzone = CircleDeadZone::new(0.2);
+/// let bounds = DualAxisBounds::only_x(-0.5, 0.5);
+/// let fused = CircleDeadZoneThenBounds::new(deadzone, bounds);
+///
+/// for x in -300..300 {
+///     let x = x as f32 * 0.01;
+///     for y in -300..300 {
+///         let y = y as f32 * 0.01;
+///         let value = Vec2::new(x, y);
+///         assert_eq!(fused.process(value), bounds.clamp(deadzone.normalize(value)));
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct CircleDeadZoneThenBounds {
+    /// The circular deadzone applied first.
+    pub(crate) deadzone: CircleDeadZone,
+
+    /// The per-axis bounds applied to the normalized value.
+    pub(crate) bounds: DualAxisBounds,
+}
+
+impl CircleDeadZoneThenBounds {
+    /// Creates a [`CircleDeadZoneThenBounds`] from the given `deadzone` and `bounds`.
+    #[inline]
+    pub fn new(deadzone: CircleDeadZone, bounds: DualAxisBounds) -> Self {
+        Self { deadzone, bounds }
+    }
+
+    /// Returns the [`CircleDeadZone`] applied first.
+    #[inline]
+    pub fn deadzone(&self) -> CircleDeadZone {
+        self.deadzone
+    }
+
+    /// Returns the [`DualAxisBounds`] applied after the deadzone.
+    #[inline]
+    pub fn bounds(&self) -> DualAxisBounds {
+        self.bounds
+    }
+
+    /// Normalizes `input_value` into the live zone of the deadzone,
+    /// then clamps the result within the bounds.
+    #[must_use]
+    #[inline]
+    pub fn process(&self, input_value: Vec2) -> Vec2 {
+        self.bounds.clamp(self.deadzone.normalize(input_value))
+    }
+}
+
+impl From<CircleDeadZoneThenBounds> for DualAxisProcessor {
+    fn from(value: CircleDeadZoneThenBounds) -> Self {
+        Self::CircleDeadZoneThenBounds(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -480,6 +689,17 @@ mod tests {
         test_exclusion(exclusion, 0.5);
     }
 
+    #[test]
+    fn test_circle_livezone_bounds() {
+        let exclusion = CircleExclusion::new(0.25);
+        assert_eq!(exclusion.livezone_lower_bound(), Vec2::splat(0.25));
+        assert_eq!(exclusion.livezone_upper_bound(), Vec2::INFINITY);
+
+        let deadzone = CircleDeadZone::new(0.25);
+        assert_eq!(deadzone.livezone_lower_bound(), Vec2::splat(0.25));
+        assert_eq!(deadzone.livezone_upper_bound(), Vec2::ONE);
+    }
+
     #[test]
     fn test_circle_deadzone() {
         fn test_deadzone(deadzone: CircleDeadZone, radius: f32) {
@@ -536,4 +756,67 @@ mod tests {
         let deadzone = CircleDeadZone::new(0.5);
         test_deadzone(deadzone, 0.5);
     }
+
+    #[test]
+    fn test_circle_deadzone_then_bounds() {
+        let deadzone = CircleDeadZone::new(0.2);
+        let bounds = DualAxisBounds::new((-0.5, 0.75), (-0.25, 1.0));
+        let fused = CircleDeadZoneThenBounds::new(deadzone, bounds);
+        assert_eq!(fused.deadzone(), deadzone);
+        assert_eq!(fused.bounds(), bounds);
+
+        let processor = DualAxisProcessor::CircleDeadZoneThenBounds(fused);
+        assert_eq!(DualAxisProcessor::from(fused), processor);
+
+        let pipeline = [
+            DualAxisProcessor::CircleDeadZone(deadzone),
+            DualAxisProcessor::ValueBounds(bounds),
+        ];
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                let expected = pipeline
+                    .iter()
+                    .fold(value, |value, processor| processor.process(value));
+                let delta = (processor.process(value) - expected).abs();
+                assert!(delta.x <= f32::EPSILON);
+                assert!(delta.y <= f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_circle_anti_deadzone() {
+        let anti_deadzone = CircleAntiDeadZone::new(0.3);
+        assert_eq!(anti_deadzone.min_output(), 0.3);
+
+        let processor = DualAxisProcessor::CircleAntiDeadZone(anti_deadzone);
+        assert_eq!(DualAxisProcessor::from(anti_deadzone), processor);
+
+        // Zero input stays zero
+        assert_eq!(processor.process(Vec2::ZERO), Vec2::ZERO);
+
+        // Tiny input jumps to the minimum output, keeping its direction
+        let tiny = Vec2::new(0.001, -0.002);
+        let lifted = processor.process(tiny);
+        assert!(lifted.length() >= 0.3);
+        assert!((lifted.normalize() - tiny.normalize()).length() <= 0.00001);
+
+        // The rest of the range is scaled linearly, up to a magnitude of 1.0
+        let half = processor.process(Vec2::new(0.0, 0.5));
+        assert!((half - Vec2::new(0.0, 0.65)).length() <= 0.00001);
+        assert_eq!(
+            processor.process(Vec2::new(-1.0, 0.0)),
+            Vec2::new(-1.0, 0.0)
+        );
+        assert_eq!(processor.process(Vec2::new(3.0, 4.0)), Vec2::new(0.6, 0.8));
+
+        // A zero-size anti-deadzone only restricts the magnitude
+        let value = Vec2::new(0.1, 0.2);
+        assert_eq!(CircleAntiDeadZone::ZERO.lift(value), value);
+    }
 }
@@ -1,6 +1,7 @@
 //! Processors for dual-axis input values
 
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
 use bevy::{
     math::FloatOrd,
@@ -8,7 +9,10 @@ use bevy::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::input_processing::AxisProcessor;
+use crate::input_processing::{
+    single_axis::{per_second_factor, quantize},
+    AxisProcessor,
+};
 
 pub use self::circle::*;
 pub use self::custom::*;
@@ -55,15 +59,50 @@ pub enum DualAxisProcessor {
     /// A wrapper around [`DualAxisSensitivity`] to represent sensitivity.
     Sensitivity(DualAxisSensitivity),
 
+    /// A wrapper around [`DualAxisBandedSensitivity`] to represent magnitude-dependent sensitivity.
+    BandedSensitivity(DualAxisBandedSensitivity),
+
+    /// A wrapper around [`DualAxisRotation`] to represent a constant rotation.
+    Rotate(DualAxisRotation),
+
+    /// A wrapper around [`DualAxisQuantization`] to represent quantization.
+    Quantize(DualAxisQuantization),
+
+    /// Divides input values by the duration of the frame in seconds,
+    /// turning amounts accumulated over a frame (such as mouse motion) into rates
+    /// that don't depend on the frame rate.
+    ///
+    /// This needs the delta time of the frame, so it only takes effect in [`DualAxisProcessor::process_with_delta`],
+    /// which is used by the processing pipelines of all inputs and of the [`InputMap`](crate::input_map::InputMap).
+    /// [`DualAxisProcessor::process`] leaves values unchanged,
+    /// and so do [`apply_processor`], [`apply_pipeline`] and [`DualAxisProcessor::sample_grid`], which rely on it.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Vec2;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// // The same motion spread over twice as long a frame is half the rate
+    /// let processor = DualAxisProcessor::PerSecond;
+    /// assert_eq!(processor.process_with_delta(Vec2::new(2.0, -1.0), 0.5), Vec2::new(4.0, -2.0));
+    /// assert_eq!(processor.process_with_delta(Vec2::new(2.0, -1.0), 1.0), Vec2::new(2.0, -1.0));
+    /// ```
+    PerSecond,
+
     /// A wrapper around [`DualAxisBounds`] to represent value bounds.
     ValueBounds(DualAxisBounds),
 
     /// A wrapper around [`DualAxisExclusion`] to represent unscaled deadzone.
     Exclusion(DualAxisExclusion),
 
+    /// A wrapper around [`RectExclusion`] to represent an unscaled rectangular deadzone.
+    RectExclusion(RectExclusion),
+
     /// A wrapper around [`DualAxisDeadZone`] to represent scaled deadzone.
     DeadZone(DualAxisDeadZone),
 
+    /// A wrapper around [`DirectionalDeadZone`] to represent scaled deadzone that keeps the input direction.
+    DirectionalDeadZone(DirectionalDeadZone),
+
     /// A wrapper around [`CircleBounds`] to represent circular value bounds.
     CircleBounds(CircleBounds),
 
@@ -73,6 +112,17 @@ pub enum DualAxisProcessor {
     /// A wrapper around [`CircleDeadZone`] to represent scaled deadzone.
     CircleDeadZone(CircleDeadZone),
 
+    /// A wrapper around [`CircleAntiDeadZone`] to represent an anti-deadzone,
+    /// lifting non-zero input values to a minimum magnitude.
+    CircleAntiDeadZone(CircleAntiDeadZone),
+
+    /// A wrapper around [`CircleDeadZoneThenBounds`] to represent a scaled deadzone
+    /// followed by value bounds in a single step.
+    CircleDeadZoneThenBounds(CircleDeadZoneThenBounds),
+
+    /// A wrapper around [`DualAxisBlend`] to represent a weighted blend of two processors.
+    Blend(DualAxisBlend),
+
     /// A user-defined processor that implements [`CustomDualAxisProcessor`].
     Custom(Box<dyn CustomDualAxisProcessor>),
 }
@@ -89,15 +139,438 @@ impl DualAxisProcessor {
             ),
             Self::Inverted(inversion) => inversion.invert(input_value),
             Self::Sensitivity(sensitivity) => sensitivity.scale(input_value),
+            Self::BandedSensitivity(sensitivity) => sensitivity.scale(input_value),
+            Self::Rotate(rotation) => rotation.rotate(input_value),
+            Self::Quantize(quantization) => quantization.quantize(input_value),
+            Self::PerSecond => input_value,
             Self::ValueBounds(bounds) => bounds.clamp(input_value),
             Self::Exclusion(exclusion) => exclusion.exclude(input_value),
+            Self::RectExclusion(exclusion) => exclusion.exclude(input_value),
             Self::DeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::DirectionalDeadZone(deadzone) => deadzone.normalize(input_value),
             Self::CircleBounds(bounds) => bounds.clamp(input_value),
             Self::CircleExclusion(exclusion) => exclusion.exclude(input_value),
             Self::CircleDeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::CircleAntiDeadZone(anti_deadzone) => anti_deadzone.lift(input_value),
+            Self::CircleDeadZoneThenBounds(fused) => fused.process(input_value),
+            Self::Blend(blend) => blend.blend(input_value),
             Self::Custom(processor) => processor.process(input_value),
         }
     }
+
+    /// Computes the result by processing the `input_value` accumulated over a frame lasting `delta_secs` seconds.
+    ///
+    /// Unlike [`DualAxisProcessor::process`], this applies [`DualAxisProcessor::PerSecond`].
+    #[must_use]
+    #[inline]
+    pub fn process_with_delta(&self, input_value: Vec2, delta_secs: f32) -> Vec2 {
+        match self {
+            Self::PerSecond => input_value * per_second_factor(delta_secs),
+            Self::Blend(blend) => {
+                let a = blend.a.process_with_delta(input_value, delta_secs);
+                let b = blend.b.process_with_delta(input_value, delta_secs);
+                a.lerp(b, blend.t)
+            }
+            _ => self.process(input_value),
+        }
+    }
+
+    /// Describes the processing steps performed by this processor,
+    /// such as for displaying the configured pipeline in a settings menu.
+    ///
+    /// Fused processors like [`CircleDeadZoneThenBounds`] are flattened into one description per step,
+    /// so describing a whole pipeline is as simple as chaining the descriptions of each processor.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// let pipeline = [
+    ///     DualAxisProcessor::from(CircleDeadZone::new(0.1)),
+    ///     DualAxisProcessor::from(DualAxisSensitivity::all(2.0)),
+    ///     DualAxisProcessor::from(DualAxisInverted::ONLY_Y),
+    /// ];
+    ///
+    /// let steps: Vec<String> = pipeline
+    ///     .iter()
+    ///     .flat_map(DualAxisProcessor::describe)
+    ///     .map(|step| step.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     steps,
+    ///     [
+    ///         "CircleDeadZone(radius: 0.1)",
+    ///         "Sensitivity(x: 2, y: 2)",
+    ///         "Inverted(x: 0, y: 1)",
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> Vec<ProcessorDescription> {
+        let step = |name: &str, parameters: Vec<(&'static str, f32)>| ProcessorDescription {
+            name: name.to_string(),
+            parameters,
+        };
+
+        let description = match self {
+            Self::Digital => step("Digital", Vec::new()),
+            Self::Inverted(inversion) => {
+                let inverted = inversion.inverted();
+                step(
+                    "Inverted",
+                    vec![
+                        ("x", f32::from(u8::from(inverted.x))),
+                        ("y", f32::from(u8::from(inverted.y))),
+                    ],
+                )
+            }
+            Self::Sensitivity(sensitivity) => {
+                let Vec2 { x, y } = sensitivity.sensitivities();
+                step("Sensitivity", vec![("x", x), ("y", y)])
+            }
+            Self::BandedSensitivity(sensitivity) => step(
+                "BandedSensitivity",
+                sensitivity
+                    .bands()
+                    .iter()
+                    .flat_map(|&(min_magnitude, sensitivity)| {
+                        [
+                            ("min_magnitude", min_magnitude),
+                            ("sensitivity", sensitivity),
+                        ]
+                    })
+                    .collect(),
+            ),
+            Self::Rotate(rotation) => step("Rotate", vec![("radians", rotation.radians())]),
+            Self::Quantize(quantization) => {
+                let Vec2 { x, y } = quantization.steps();
+                step("Quantize", vec![("x", x), ("y", y)])
+            }
+            Self::PerSecond => step("PerSecond", Vec::new()),
+            Self::ValueBounds(bounds) => {
+                let (x, y) = bounds.bounds();
+                step("ValueBounds", describe_ranges(x.min_max(), y.min_max()))
+            }
+            Self::Exclusion(exclusion) => {
+                let (x, y) = exclusion.exclusions();
+                step("Exclusion", describe_ranges(x.min_max(), y.min_max()))
+            }
+            Self::RectExclusion(exclusion) => step(
+                "RectExclusion",
+                describe_ranges(exclusion.range_x().min_max(), exclusion.range_y().min_max()),
+            ),
+            Self::DeadZone(deadzone) => {
+                let (x, y) = deadzone.exclusion().exclusions();
+                step("DeadZone", describe_ranges(x.min_max(), y.min_max()))
+            }
+            Self::DirectionalDeadZone(deadzone) => {
+                let (x, y) = deadzone.deadzone().exclusion().exclusions();
+                step(
+                    "DirectionalDeadZone",
+                    describe_ranges(x.min_max(), y.min_max()),
+                )
+            }
+            Self::CircleBounds(bounds) => step("CircleBounds", vec![("radius", bounds.radius())]),
+            Self::CircleExclusion(exclusion) => {
+                step("CircleExclusion", vec![("radius", exclusion.radius())])
+            }
+            Self::CircleDeadZone(deadzone) => {
+                step("CircleDeadZone", vec![("radius", deadzone.radius())])
+            }
+            Self::CircleAntiDeadZone(anti_deadzone) => step(
+                "CircleAntiDeadZone",
+                vec![("min_output", anti_deadzone.min_output())],
+            ),
+            Self::CircleDeadZoneThenBounds(fused) => {
+                let mut steps = Self::CircleDeadZone(fused.deadzone()).describe();
+                steps.extend(Self::ValueBounds(fused.bounds()).describe());
+                return steps;
+            }
+            Self::Blend(blend) => step("Blend", vec![("t", blend.t)]),
+            Self::Custom(processor) => step(processor.reflect_short_type_path(), Vec::new()),
+        };
+
+        vec![description]
+    }
+
+    /// Checks a processing `pipeline` for steps that are most likely mistakes,
+    /// such as for warning players about a questionable configuration in a settings menu.
+    ///
+    /// The following issues are reported, in the order of the steps that cause them:
+    ///
+    /// - [`PipelineLint::RedundantDeadZone`]: more than one step removes small values,
+    ///   so the later deadzones act on already processed values.
+    /// - [`PipelineLint::RedundantBounds`]: more than one step clamps values,
+    ///   so only the tightest bounds have an effect.
+    /// - [`PipelineLint::ZeroSensitivity`]: a sensitivity of zero discards all movement along an axis.
+    ///
+    /// Fused processors like [`CircleDeadZoneThenBounds`] count as both a deadzone and bounds.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// let pipeline = [
+    ///     DualAxisProcessor::from(CircleDeadZone::new(0.1)),
+    ///     DualAxisProcessor::from(DualAxisSensitivity::all(2.0)),
+    ///     DualAxisProcessor::from(CircleDeadZone::new(0.2)),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     DualAxisProcessor::lint(&pipeline),
+    ///     [PipelineLint::RedundantDeadZone { first: 0, second: 2 }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn lint(pipeline: &[DualAxisProcessor]) -> Vec<PipelineLint> {
+        let mut lints = Vec::new();
+        let mut deadzone = None;
+        let mut bounds = None;
+
+        for (index, processor) in pipeline.iter().enumerate() {
+            let (is_deadzone, is_bounds) = match processor {
+                Self::Exclusion(_)
+                | Self::RectExclusion(_)
+                | Self::DeadZone(_)
+                | Self::DirectionalDeadZone(_)
+                | Self::CircleExclusion(_)
+                | Self::CircleDeadZone(_) => (true, false),
+                Self::ValueBounds(_) | Self::CircleBounds(_) => (false, true),
+                Self::CircleDeadZoneThenBounds(_) => (true, true),
+                Self::Sensitivity(sensitivity) => {
+                    if sensitivity.sensitivities().cmpeq(Vec2::ZERO).any() {
+                        lints.push(PipelineLint::ZeroSensitivity { index });
+                    }
+                    (false, false)
+                }
+                _ => (false, false),
+            };
+
+            if is_deadzone {
+                match deadzone {
+                    Some(first) => lints.push(PipelineLint::RedundantDeadZone {
+                        first,
+                        second: index,
+                    }),
+                    None => deadzone = Some(index),
+                }
+            }
+
+            if is_bounds {
+                match bounds {
+                    Some(first) => lints.push(PipelineLint::RedundantBounds {
+                        first,
+                        second: index,
+                    }),
+                    None => bounds = Some(index),
+                }
+            }
+        }
+
+        lints
+    }
+
+    /// Samples the output of this processor over an evenly spaced grid of inputs covering `[-1.0, 1.0]` on both axes,
+    /// such as for rendering the response of a deadzone on a calibration screen.
+    ///
+    /// The grid has `resolution` samples along each axis, including both ends,
+    /// and is returned row by row as `(input, output)` pairs, starting from `(-1.0, -1.0)`.
+    ///
+    /// # Requirements
+    ///
+    /// - `resolution` >= `2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// let deadzone = DualAxisProcessor::from(CircleDeadZone::new(0.2));
+    /// let grid = deadzone.sample_grid(3);
+    ///
+    /// assert_eq!(grid.len(), 9);
+    /// assert_eq!(grid[0].0, Vec2::NEG_ONE);
+    /// // The center of the grid lies within the deadzone
+    /// assert_eq!(grid[4], (Vec2::ZERO, Vec2::ZERO));
+    /// ```
+    ///
+    /// Samples are processed with [`DualAxisProcessor::process`], so [`DualAxisProcessor::PerSecond`] leaves them unchanged.
+    #[must_use]
+    pub fn sample_grid(&self, resolution: u32) -> Vec<(Vec2, Vec2)> {
+        assert!(resolution >= 2);
+
+        let step = 2.0 / (resolution - 1) as f32;
+        let coordinate = |index: u32| {
+            // Snap the last sample to the edge to avoid rounding errors
+            if index == resolution - 1 {
+                1.0
+            } else {
+                index as f32 * step - 1.0
+            }
+        };
+
+        (0..resolution)
+            .flat_map(|row| (0..resolution).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                let input = Vec2::new(coordinate(column), coordinate(row));
+                (input, self.process(input))
+            })
+            .collect()
+    }
+}
+
+/// Applies the given `processor` to an arbitrary `input_value`.
+///
+/// Processors don't depend on any input,
+/// so they can be reused for values that don't come from the player, such as the aim of an AI.
+/// There is no frame duration here, so [`DualAxisProcessor::PerSecond`] leaves the value unchanged.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let aim = apply_processor(DualAxisSensitivity::all(2.0), Vec2::new(0.25, -0.5));
+/// assert_eq!(aim, Vec2::new(0.5, -1.0));
+/// ```
+#[must_use]
+#[inline]
+pub fn apply_processor(processor: impl Into<DualAxisProcessor>, input_value: Vec2) -> Vec2 {
+    processor.into().process(input_value)
+}
+
+/// Applies each of the given `processors` in order to an arbitrary `input_value`,
+/// just like the processing pipeline of a dual-axis input.
+///
+/// Unlike that pipeline, this has no frame duration, so [`DualAxisProcessor::PerSecond`] steps leave the value unchanged.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let pipeline = [
+///     DualAxisProcessor::from(CircleDeadZone::new(0.1)),
+///     DualAxisProcessor::from(DualAxisInverted::ONLY_Y),
+/// ];
+///
+/// assert_eq!(apply_pipeline(&pipeline, Vec2::new(0.05, 0.05)), Vec2::ZERO);
+/// assert_eq!(apply_pipeline(&pipeline, Vec2::new(0.0, 1.0)), Vec2::new(0.0, -1.0));
+/// ```
+#[must_use]
+#[inline]
+pub fn apply_pipeline<'a>(
+    processors: impl IntoIterator<Item = &'a DualAxisProcessor>,
+    input_value: Vec2,
+) -> Vec2 {
+    processors
+        .into_iter()
+        .fold(input_value, |value, processor| processor.process(value))
+}
+
+/// Lists the `[min, max]` ranges of both axes as the parameters of a [`ProcessorDescription`].
+fn describe_ranges(
+    (x_min, x_max): (f32, f32),
+    (y_min, y_max): (f32, f32),
+) -> Vec<(&'static str, f32)> {
+    vec![
+        ("x_min", x_min),
+        ("x_max", x_max),
+        ("y_min", y_min),
+        ("y_max", y_max),
+    ]
+}
+
+/// A human-readable and machine-inspectable description of a single processing step,
+/// as returned by [`DualAxisProcessor::describe`].
+///
+/// Its [`Display`](std::fmt::Display) implementation formats it as `Name(parameter: value, ...)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessorDescription {
+    /// The name of the processing step, such as `"Sensitivity"`.
+    ///
+    /// Custom processors are named after their type.
+    pub name: String,
+
+    /// The named parameters of the processing step, in a stable order.
+    ///
+    /// Flags, such as which axes are inverted, are reported as `1.0` when set and `0.0` otherwise.
+    pub parameters: Vec<(&'static str, f32)>,
+}
+
+impl ProcessorDescription {
+    /// Returns the value of the first parameter with the given `name`, if any.
+    #[must_use]
+    #[inline]
+    pub fn parameter(&self, name: &str) -> Option<f32> {
+        self.parameters
+            .iter()
+            .find(|(parameter, _)| *parameter == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+impl std::fmt::Display for ProcessorDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.parameters.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        for (index, (name, value)) in self.parameters.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// A likely mistake in a processing pipeline, as reported by [`DualAxisProcessor::lint`].
+///
+/// Steps are identified by their index in the pipeline.
+/// Its [`Display`](std::fmt::Display) implementation describes the issue in plain English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelineLint {
+    /// The `second` step is a deadzone, but the `first` one already is.
+    RedundantDeadZone {
+        /// The index of the first deadzone.
+        first: usize,
+        /// The index of the redundant deadzone.
+        second: usize,
+    },
+
+    /// The `second` step clamps values, but the `first` one already does.
+    RedundantBounds {
+        /// The index of the first bounds.
+        first: usize,
+        /// The index of the redundant bounds.
+        second: usize,
+    },
+
+    /// The step at `index` has a sensitivity of zero along at least one axis.
+    ZeroSensitivity {
+        /// The index of the sensitivity.
+        index: usize,
+    },
+}
+
+impl std::fmt::Display for PipelineLint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RedundantDeadZone { first, second } => {
+                write!(f, "step {second} repeats the deadzone of step {first}")
+            }
+            Self::RedundantBounds { first, second } => {
+                write!(f, "step {second} repeats the bounds of step {first}")
+            }
+            Self::ZeroSensitivity { index } => {
+                write!(f, "step {index} has a sensitivity of zero")
+            }
+        }
+    }
 }
 
 /// Provides methods for configuring and manipulating the processing pipeline for dual-axis input.
@@ -121,6 +594,17 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(DualAxisProcessor::Digital)
     }
 
+    /// Appends a [`DualAxisProcessor::PerSecond`] processor as the next processing step,
+    /// dividing values accumulated over a frame by the frame's delta time.
+    ///
+    /// This is meant for inputs that accumulate over a frame, such as mouse motion and scrolling.
+    /// Values are only divided when the input is read from the [`CentralInputStore`](crate::user_input::updating::CentralInputStore),
+    /// using the delta time recorded there.
+    #[inline]
+    fn per_second(self) -> Self {
+        self.with_processor(DualAxisProcessor::PerSecond)
+    }
+
     /// Appends a [`DualAxisInverted::ALL`] processor as the next processing step,
     /// flipping the sign of values on both axes.
     #[inline]
@@ -163,6 +647,49 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(DualAxisSensitivity::only_y(sensitivity))
     }
 
+    /// Appends a [`DualAxisBandedSensitivity`] processor as the next processing step,
+    /// scaling the magnitude of values by the sensitivity of the band that it falls into.
+    ///
+    /// Each band is given as a `(min_magnitude, sensitivity)` pair.
+    #[inline]
+    fn banded_sensitivity(self, bands: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        self.with_processor(DualAxisBandedSensitivity::new(bands))
+    }
+
+    /// Appends a [`DualAxisRotation`] processor as the next processing step,
+    /// rotating values counterclockwise by the given angle in radians.
+    #[inline]
+    fn with_rotation(self, radians: f32) -> Self {
+        self.with_processor(DualAxisRotation::new(radians))
+    }
+
+    /// Appends a [`DualAxisQuantization`] processor as the next processing step,
+    /// rounding values on both axes to the nearest multiple of the given step.
+    #[inline]
+    fn quantized(self, step: f32) -> Self {
+        self.with_processor(DualAxisQuantization::all(step))
+    }
+
+    /// Appends a [`DualAxisBlend`] processor as the next processing step,
+    /// interpolating between the outputs of `a` and `b` by the weight `t`.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `t` <= `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn blended(
+        self,
+        a: impl Into<DualAxisProcessor>,
+        b: impl Into<DualAxisProcessor>,
+        t: f32,
+    ) -> Self {
+        self.with_processor(DualAxisBlend::new(a, b, t))
+    }
+
     /// Appends a [`DualAxisBounds`] processor as the next processing step,
     /// restricting values within the same range `[min, max]` on both axes.
     #[inline]
@@ -170,6 +697,22 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(DualAxisBounds::all(min, max))
     }
 
+    /// Appends a [`DualAxisBounds`] processor as the next processing step,
+    /// restricting values within independent ranges `[x_min, x_max]` on the X-axis
+    /// and `[y_min, y_max]` on the Y-axis.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` <= `max` on each axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_bounds_xy(self, (x_min, x_max): (f32, f32), (y_min, y_max): (f32, f32)) -> Self {
+        self.with_processor(DualAxisBounds::new((x_min, x_max), (y_min, y_max)))
+    }
+
     /// Appends a [`DualAxisBounds`] processor as the next processing step,
     /// restricting values within the same range `[-threshold, threshold]` on both axes.
     #[inline]
@@ -298,6 +841,23 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(DualAxisDeadZone::symmetric_all(threshold))
     }
 
+    /// Appends a [`DirectionalDeadZone`] processor as the next processing step,
+    /// excluding values within the dead zone range `[-threshold, threshold]` on both axes
+    /// like [`WithDualAxisProcessingPipelineExt::with_deadzone_symmetric`],
+    /// but keeping the direction of non-excluded input values.
+    ///
+    /// # Requirements
+    ///
+    /// - `threshold` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_directional_deadzone(self, threshold: f32) -> Self {
+        self.with_processor(DualAxisDeadZone::symmetric_all(threshold).preserving_direction())
+    }
+
     /// Appends a [`DualAxisDeadZone`] processor as the next processing step,
     /// only passing positive values that greater than `positive_min` on both axes
     /// and then normalizing them into the "live zone" range `[positive_min, 1.0]`.
@@ -484,6 +1044,41 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(CircleDeadZone::new(min))
     }
 
+    /// Appends a [`CircleAntiDeadZone`] processor as the next processing step,
+    /// lifting the magnitude of non-zero values to at least `min_output`
+    /// while preserving their direction.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `min_output` <= `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_anti_deadzone(self, min_output: f32) -> Self {
+        self.with_processor(CircleAntiDeadZone::new(min_output))
+    }
+
+    /// Appends a [`CircleDeadZoneThenBounds`] processor as the next processing step,
+    /// applying a [`CircleDeadZone`] with the given `min` magnitude
+    /// and then restricting the result within the given `bounds`, in a single step.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_circle_deadzone_then_bounds(self, min: f32, bounds: DualAxisBounds) -> Self {
+        self.with_processor(CircleDeadZoneThenBounds::new(
+            CircleDeadZone::new(min),
+            bounds,
+        ))
+    }
+
     /// Appends a [`DualAxisExclusion`] processor as the next processing step,
     /// ignoring values within the range `[negative_max, positive_min]` on both axes,
     /// treating them as zeros.
@@ -690,6 +1285,22 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
     fn with_circle_deadzone_unscaled(self, min: f32) -> Self {
         self.with_processor(CircleExclusion::new(min))
     }
+
+    /// Appends a [`RectExclusion`] processor as the next processing step,
+    /// ignoring values within the rectangle `[x_min, x_max] × [y_min, y_max]`, treating them as zeros.
+    ///
+    /// # Requirements
+    ///
+    /// - `x_min` <= `x_max`.
+    /// - `y_min` <= `y_max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_rect_deadzone_unscaled(self, x_min: f32, x_max: f32, y_min: f32, y_max: f32) -> Self {
+        self.with_processor(RectExclusion::new(x_min, x_max, y_min, y_max))
+    }
 }
 
 /// Flips the sign of dual-axis input values, resulting in a directional reversal of control.
@@ -780,6 +1391,11 @@ impl Hash for DualAxisInverted {
 /// let neg_double_y = DualAxisSensitivity::only_y(-2.0);
 /// assert_eq!(neg_double_y.scale(value).x, x);
 /// assert_eq!(neg_double_y.scale(value).y, -2.0 * y);
+///
+/// // Non-finite sensitivities are rejected by `try_new`, and ignored when scaling
+/// assert_eq!(DualAxisSensitivity::try_new(f32::NAN, 2.0), None);
+/// let bad_x = DualAxisSensitivity::new(f32::INFINITY, 2.0);
+/// assert_eq!(bad_x.scale(value), Vec2::new(x, 2.0 * y));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
 #[must_use]
@@ -787,11 +1403,22 @@ pub struct DualAxisSensitivity(pub(crate) Vec2);
 
 impl DualAxisSensitivity {
     /// Creates a [`DualAxisSensitivity`] with the given values for each axis separately.
+    ///
+    /// Non-finite values are ignored by [`DualAxisSensitivity::scale`].
+    /// Use [`DualAxisSensitivity::try_new`] to reject them instead, such as when loading a config.
     #[inline]
     pub const fn new(sensitivity_x: f32, sensitivity_y: f32) -> Self {
         Self(Vec2::new(sensitivity_x, sensitivity_y))
     }
 
+    /// Creates a [`DualAxisSensitivity`] with the given values for each axis separately,
+    /// returning [`None`] if either of them is infinite or NaN.
+    #[inline]
+    pub fn try_new(sensitivity_x: f32, sensitivity_y: f32) -> Option<Self> {
+        let sensitivity = Self::new(sensitivity_x, sensitivity_y);
+        sensitivity.is_finite().then_some(sensitivity)
+    }
+
     /// Creates a [`DualAxisSensitivity`] with the same value for both axes.
     #[inline]
     pub const fn all(sensitivity: f32) -> Self {
@@ -817,11 +1444,24 @@ impl DualAxisSensitivity {
         self.0
     }
 
+    /// Are the sensitivities on both axes neither infinite nor NaN?
+    #[must_use]
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
     /// Multiples the `input_value` by the specified sensitivity vector.
+    ///
+    /// To avoid corrupting the rest of the processing pipeline,
+    /// non-finite sensitivities leave their axis unscaled,
+    /// and non-finite results (such as from a NaN `input_value`) are replaced with `0.0`.
     #[must_use]
     #[inline]
     pub fn scale(&self, input_value: Vec2) -> Vec2 {
-        self.0 * input_value
+        let sensitivity = Vec2::select(self.0.is_finite_mask(), self.0, Vec2::ONE);
+        let scaled = sensitivity * input_value;
+        Vec2::select(scaled.is_finite_mask(), scaled, Vec2::ZERO)
     }
 }
 
@@ -840,10 +1480,413 @@ impl Hash for DualAxisSensitivity {
     }
 }
 
+/// Scales the magnitude of dual-axis input values by a multiplier that depends on how far they are from the center,
+/// while preserving their direction.
+///
+/// Each band is a `(min_magnitude, sensitivity)` pair,
+/// covering magnitudes from its `min_magnitude` up to the `min_magnitude` of the next band.
+/// The last band extends indefinitely, and values below the first band are left unchanged.
+/// Unlike an exponential response curve, the sensitivity is constant within each band.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Precise aiming near the center, fast turning near the edge
+/// let banded = DualAxisBandedSensitivity::new([(0.0, 0.5), (0.8, 2.0)]);
+///
+/// assert_eq!(banded.scale(Vec2::new(0.4, 0.0)), Vec2::new(0.2, 0.0));
+/// assert_eq!(banded.scale(Vec2::new(0.0, -0.9)), Vec2::new(0.0, -1.8));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DualAxisBandedSensitivity {
+    /// The `(min_magnitude, sensitivity)` pairs, sorted by `min_magnitude`.
+    pub(crate) bands: Vec<(f32, f32)>,
+}
+
+impl DualAxisBandedSensitivity {
+    /// Creates a [`DualAxisBandedSensitivity`] from `(min_magnitude, sensitivity)` pairs,
+    /// which may be provided in any order.
+    ///
+    /// # Requirements
+    ///
+    /// - Each `min_magnitude` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(bands: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut bands: Vec<(f32, f32)> = bands.into_iter().collect();
+        assert!(bands.iter().all(|(min_magnitude, _)| *min_magnitude >= 0.0));
+        bands.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self { bands }
+    }
+
+    /// Returns the `(min_magnitude, sensitivity)` pairs, sorted by `min_magnitude`.
+    #[must_use]
+    #[inline]
+    pub fn bands(&self) -> &[(f32, f32)] {
+        &self.bands
+    }
+
+    /// Returns the sensitivity applied to input values with the given `magnitude`.
+    ///
+    /// This is `1.0` if the `magnitude` is below the first band.
+    #[must_use]
+    #[inline]
+    pub fn sensitivity_at(&self, magnitude: f32) -> f32 {
+        self.bands
+            .iter()
+            .rev()
+            .find(|(min_magnitude, _)| magnitude >= *min_magnitude)
+            .map_or(1.0, |(_, sensitivity)| *sensitivity)
+    }
+
+    /// Multiplies the `input_value` by the sensitivity of the band that its magnitude falls into.
+    #[must_use]
+    #[inline]
+    pub fn scale(&self, input_value: Vec2) -> Vec2 {
+        self.sensitivity_at(input_value.length()) * input_value
+    }
+}
+
+impl From<DualAxisBandedSensitivity> for DualAxisProcessor {
+    fn from(value: DualAxisBandedSensitivity) -> Self {
+        Self::BandedSensitivity(value)
+    }
+}
+
+impl Eq for DualAxisBandedSensitivity {}
+
+impl Hash for DualAxisBandedSensitivity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (min_magnitude, sensitivity) in &self.bands {
+            FloatOrd(*min_magnitude).hash(state);
+            FloatOrd(*sensitivity).hash(state);
+        }
+    }
+}
+
+/// Rotates dual-axis input values counterclockwise by a constant angle,
+/// compensating for controllers or mounts that are physically rotated.
+///
+/// ```rust
+/// use std::f32::consts::FRAC_PI_2;
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // A quarter turn counterclockwise
+/// let rotation = DualAxisRotation::new(FRAC_PI_2);
+/// assert!(rotation.rotate(Vec2::X).abs_diff_eq(Vec2::Y, 1e-6));
+///
+/// // A quarter turn clockwise
+/// let rotation = DualAxisRotation::from_degrees(-90.0);
+/// assert!(rotation.rotate(Vec2::X).abs_diff_eq(Vec2::NEG_Y, 1e-6));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DualAxisRotation(pub(crate) f32);
+
+impl DualAxisRotation {
+    /// Creates a [`DualAxisRotation`] by the given angle in radians.
+    #[inline]
+    pub const fn new(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// Creates a [`DualAxisRotation`] by the given angle in degrees.
+    #[inline]
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self::new(degrees.to_radians())
+    }
+
+    /// Returns the angle of the rotation in radians.
+    #[must_use]
+    #[inline]
+    pub fn radians(&self) -> f32 {
+        self.0
+    }
+
+    /// Rotates the `input_value` counterclockwise by the specified angle.
+    #[must_use]
+    #[inline]
+    pub fn rotate(&self, input_value: Vec2) -> Vec2 {
+        Vec2::from_angle(self.0).rotate(input_value)
+    }
+}
+
+impl From<DualAxisRotation> for DualAxisProcessor {
+    fn from(value: DualAxisRotation) -> Self {
+        Self::Rotate(value)
+    }
+}
+
+impl Eq for DualAxisRotation {}
+
+impl Hash for DualAxisRotation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.0).hash(state);
+    }
+}
+
+/// Rounds dual-axis input values to the nearest multiple of a specified step along each axis,
+/// snapping them to a fixed grid so that they are reproducible across machines.
+///
+/// A step of `0.0` leaves values along that axis unchanged.
+/// Halfway values are rounded away from zero, so the result is symmetric around zero.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let quantization = DualAxisQuantization::all(0.5);
+/// assert_eq!(quantization.quantize(Vec2::new(0.3, -0.7)), Vec2::new(0.5, -0.5));
+///
+/// // Only snap the X-axis
+/// let only_x = DualAxisQuantization::only_x(0.5);
+/// assert_eq!(only_x.quantize(Vec2::new(0.3, -0.7)), Vec2::new(0.5, -0.7));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DualAxisQuantization(pub(crate) Vec2);
+
+impl DualAxisQuantization {
+    /// Creates a [`DualAxisQuantization`] with the given steps for each axis separately.
+    #[inline]
+    pub const fn new(step_x: f32, step_y: f32) -> Self {
+        Self(Vec2::new(step_x, step_y))
+    }
+
+    /// Creates a [`DualAxisQuantization`] with the same step for both axes.
+    #[inline]
+    pub const fn all(step: f32) -> Self {
+        Self::new(step, step)
+    }
+
+    /// Creates a [`DualAxisQuantization`] that only affects the X-axis using the given step.
+    #[inline]
+    pub const fn only_x(step: f32) -> Self {
+        Self::new(step, 0.0)
+    }
+
+    /// Creates a [`DualAxisQuantization`] that only affects the Y-axis using the given step.
+    #[inline]
+    pub const fn only_y(step: f32) -> Self {
+        Self::new(0.0, step)
+    }
+
+    /// Returns the step values.
+    #[must_use]
+    #[inline]
+    pub fn steps(&self) -> Vec2 {
+        self.0
+    }
+
+    /// Rounds each component of the `input_value` to the nearest multiple of the matching step.
+    #[must_use]
+    #[inline]
+    pub fn quantize(&self, input_value: Vec2) -> Vec2 {
+        Vec2::new(
+            quantize(input_value.x, self.0.x),
+            quantize(input_value.y, self.0.y),
+        )
+    }
+}
+
+impl From<DualAxisQuantization> for DualAxisProcessor {
+    fn from(value: DualAxisQuantization) -> Self {
+        Self::Quantize(value)
+    }
+}
+
+impl Eq for DualAxisQuantization {}
+
+impl Hash for DualAxisQuantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.0.x).hash(state);
+        FloatOrd(self.0.y).hash(state);
+    }
+}
+
+/// Linearly interpolates between the outputs of two [`DualAxisProcessor`]s,
+/// such as to fade between two sensitivity curves in an accessibility preset.
+///
+/// A weight of `0.0` yields the output of `a`, and a weight of `1.0` yields the output of `b`.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let slow = DualAxisSensitivity::all(1.0);
+/// let fast = DualAxisSensitivity::all(2.0);
+///
+/// let blend = DualAxisBlend::new(slow, fast, 0.25);
+/// assert_eq!(blend.blend(Vec2::new(1.0, -2.0)), Vec2::new(1.25, -2.5));
+///
+/// // The ends of the range yield each processor on its own
+/// assert_eq!(DualAxisBlend::new(slow, fast, 0.0).blend(Vec2::ONE), Vec2::ONE);
+/// assert_eq!(DualAxisBlend::new(slow, fast, 1.0).blend(Vec2::ONE), Vec2::splat(2.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DualAxisBlend {
+    /// The processor whose output is used when `t` is `0.0`.
+    pub(crate) a: Arc<DualAxisProcessor>,
+
+    /// The processor whose output is used when `t` is `1.0`.
+    pub(crate) b: Arc<DualAxisProcessor>,
+
+    /// The weight of `b` in the blend.
+    pub(crate) t: f32,
+}
+
+impl DualAxisBlend {
+    /// Creates a [`DualAxisBlend`] that interpolates between the outputs of `a` and `b` by the weight `t`.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `t` <= `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(a: impl Into<DualAxisProcessor>, b: impl Into<DualAxisProcessor>, t: f32) -> Self {
+        assert!((0.0..=1.0).contains(&t));
+        Self {
+            a: Arc::new(a.into()),
+            b: Arc::new(b.into()),
+            t,
+        }
+    }
+
+    /// Returns the processor whose output is used when the weight is `0.0`.
+    #[inline]
+    pub fn a(&self) -> &DualAxisProcessor {
+        &self.a
+    }
+
+    /// Returns the processor whose output is used when the weight is `1.0`.
+    #[inline]
+    pub fn b(&self) -> &DualAxisProcessor {
+        &self.b
+    }
+
+    /// Returns the weight of [`DualAxisBlend::b`] in the blend.
+    #[must_use]
+    #[inline]
+    pub fn t(&self) -> f32 {
+        self.t
+    }
+
+    /// Processes the `input_value` with both processors, interpolating between their outputs.
+    #[must_use]
+    #[inline]
+    pub fn blend(&self, input_value: Vec2) -> Vec2 {
+        self.a
+            .process(input_value)
+            .lerp(self.b.process(input_value), self.t)
+    }
+}
+
+impl From<DualAxisBlend> for DualAxisProcessor {
+    fn from(value: DualAxisBlend) -> Self {
+        Self::Blend(value)
+    }
+}
+
+impl Eq for DualAxisBlend {}
+
+impl Hash for DualAxisBlend {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        self.b.hash(state);
+        FloatOrd(self.t).hash(state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_lint() {
+        let deadzone = DualAxisProcessor::from(CircleDeadZone::new(0.1));
+        let bounds = DualAxisProcessor::from(CircleBounds::new(1.0));
+        let sensitivity = DualAxisProcessor::from(DualAxisSensitivity::all(2.0));
+
+        assert!(DualAxisProcessor::lint(&[]).is_empty());
+        assert!(
+            DualAxisProcessor::lint(&[deadzone.clone(), sensitivity.clone(), bounds.clone()])
+                .is_empty()
+        );
+
+        let lints = DualAxisProcessor::lint(&[
+            deadzone.clone(),
+            DualAxisProcessor::from(DualAxisDeadZone::symmetric_all(0.2)),
+        ]);
+        assert_eq!(
+            lints,
+            [PipelineLint::RedundantDeadZone {
+                first: 0,
+                second: 1
+            }]
+        );
+        assert_eq!(
+            lints[0].to_string(),
+            "step 1 repeats the deadzone of step 0"
+        );
+
+        // Fused processors count as both a deadzone and bounds
+        let fused = DualAxisProcessor::from(CircleDeadZoneThenBounds::new(
+            CircleDeadZone::new(0.1),
+            DualAxisBounds::all(-1.0, 1.0),
+        ));
+        assert_eq!(
+            DualAxisProcessor::lint(&[deadzone, bounds, fused]),
+            [
+                PipelineLint::RedundantDeadZone {
+                    first: 0,
+                    second: 2
+                },
+                PipelineLint::RedundantBounds {
+                    first: 1,
+                    second: 2
+                },
+            ]
+        );
+
+        let zero = DualAxisProcessor::from(DualAxisSensitivity::only_x(0.0));
+        assert_eq!(
+            DualAxisProcessor::lint(&[sensitivity, zero]),
+            [PipelineLint::ZeroSensitivity { index: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_sample_grid() {
+        let processor = DualAxisProcessor::from(CircleDeadZone::new(0.2));
+        let grid = processor.sample_grid(5);
+        assert_eq!(grid.len(), 25);
+
+        for corner in [
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(1.0, 1.0),
+        ] {
+            let (_, output) = grid.iter().find(|(input, _)| *input == corner).unwrap();
+            assert_eq!(*output, processor.process(corner));
+        }
+
+        for (input, output) in grid {
+            assert!(input.abs().max_element() <= 1.0);
+            assert_eq!(output, processor.process(input));
+        }
+    }
+
     #[test]
     fn test_dual_axis_inverted() {
         let all = DualAxisInverted::ALL;
@@ -931,4 +1974,272 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_dual_axis_banded_sensitivity() {
+        let banded = DualAxisBandedSensitivity::new([(0.5, 2.0), (0.1, 0.5)]);
+        assert_eq!(banded.bands(), &[(0.1, 0.5), (0.5, 2.0)]);
+
+        let processor = DualAxisProcessor::BandedSensitivity(banded.clone());
+        assert_eq!(DualAxisProcessor::from(banded.clone()), processor);
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                assert_eq!(processor.process(value), banded.scale(value));
+
+                let magnitude = value.length();
+                if magnitude < 0.1 {
+                    assert_eq!(banded.scale(value), value);
+                } else if magnitude < 0.5 {
+                    assert_eq!(banded.scale(value), 0.5 * value);
+                } else {
+                    assert_eq!(banded.scale(value), 2.0 * value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dual_axis_rotation() {
+        let quarter_turn = DualAxisRotation::new(std::f32::consts::FRAC_PI_2);
+        let processor = DualAxisProcessor::Rotate(quarter_turn);
+        assert_eq!(DualAxisProcessor::from(quarter_turn), processor);
+        assert_eq!(quarter_turn.radians(), std::f32::consts::FRAC_PI_2);
+        assert_eq!(DualAxisRotation::from_degrees(90.0), quarter_turn);
+
+        assert!(processor.process(Vec2::X).abs_diff_eq(Vec2::Y, 1e-6));
+        assert!(processor.process(Vec2::Y).abs_diff_eq(Vec2::NEG_X, 1e-6));
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                assert_eq!(processor.process(value), quarter_turn.rotate(value));
+                assert!(quarter_turn
+                    .rotate(value)
+                    .abs_diff_eq(Vec2::new(-y, x), 1e-5));
+                assert_eq!(DualAxisRotation::new(0.0).rotate(value), value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dual_axis_blend() {
+        let identity = DualAxisSensitivity::all(1.0);
+        let double = DualAxisSensitivity::all(2.0);
+
+        let halfway = DualAxisBlend::new(identity, double, 0.5);
+        let processor = DualAxisProcessor::Blend(halfway.clone());
+        assert_eq!(DualAxisProcessor::from(halfway.clone()), processor);
+        assert_eq!(halfway.a(), &DualAxisProcessor::from(identity));
+        assert_eq!(halfway.b(), &DualAxisProcessor::from(double));
+        assert_eq!(halfway.t(), 0.5);
+
+        let only_a = DualAxisBlend::new(identity, double, 0.0);
+        let only_b = DualAxisBlend::new(identity, double, 1.0);
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                assert_eq!(processor.process(value), halfway.blend(value));
+                assert_eq!(halfway.blend(value), 1.5 * value);
+                assert_eq!(only_a.blend(value), value);
+                assert_eq!(only_b.blend(value), 2.0 * value);
+            }
+        }
+
+        // Frame-dependent processors are still given the delta time
+        let per_second = DualAxisBlend::new(DualAxisProcessor::PerSecond, identity, 0.5);
+        assert_eq!(
+            DualAxisProcessor::from(per_second).process_with_delta(Vec2::ONE, 0.5),
+            Vec2::splat(1.5)
+        );
+    }
+
+    #[test]
+    fn test_dual_axis_quantization() {
+        let all = DualAxisQuantization::all(0.25);
+        let processor = DualAxisProcessor::Quantize(all);
+        assert_eq!(DualAxisProcessor::from(all), processor);
+        assert_eq!(all.steps(), Vec2::splat(0.25));
+        assert_eq!(
+            processor.process(Vec2::new(0.3, -0.4)),
+            Vec2::new(0.25, -0.5)
+        );
+
+        let only_x = DualAxisQuantization::only_x(0.25);
+        assert_eq!(only_x.steps(), Vec2::new(0.25, 0.0));
+        let only_y = DualAxisQuantization::only_y(0.25);
+        assert_eq!(only_y.steps(), Vec2::new(0.0, 0.25));
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                assert_eq!(all.quantize(-value), -all.quantize(value));
+                assert_eq!(
+                    all.quantize(value),
+                    Vec2::new(
+                        AxisProcessor::Quantize(0.25).process(x),
+                        AxisProcessor::Quantize(0.25).process(y)
+                    )
+                );
+
+                assert_eq!(only_x.quantize(value).y, y);
+                assert_eq!(only_y.quantize(value).x, x);
+                assert_eq!(DualAxisQuantization::all(0.0).quantize(value), value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dual_axis_processor_describe() {
+        let pipeline = [
+            DualAxisProcessor::from(DualAxisDeadZone::symmetric_all(0.1)),
+            DualAxisProcessor::from(DualAxisSensitivity::all(2.0)),
+            DualAxisProcessor::from(DualAxisInverted::ONLY_Y),
+        ];
+
+        let descriptions: Vec<ProcessorDescription> = pipeline
+            .iter()
+            .flat_map(DualAxisProcessor::describe)
+            .collect();
+
+        assert_eq!(
+            descriptions,
+            [
+                ProcessorDescription {
+                    name: "DeadZone".to_string(),
+                    parameters: vec![
+                        ("x_min", -0.1),
+                        ("x_max", 0.1),
+                        ("y_min", -0.1),
+                        ("y_max", 0.1),
+                    ],
+                },
+                ProcessorDescription {
+                    name: "Sensitivity".to_string(),
+                    parameters: vec![("x", 2.0), ("y", 2.0)],
+                },
+                ProcessorDescription {
+                    name: "Inverted".to_string(),
+                    parameters: vec![("x", 0.0), ("y", 1.0)],
+                },
+            ]
+        );
+        assert_eq!(descriptions[1].parameter("y"), Some(2.0));
+        assert_eq!(descriptions[1].parameter("radius"), None);
+        assert_eq!(descriptions[1].to_string(), "Sensitivity(x: 2, y: 2)");
+        assert_eq!(
+            DualAxisProcessor::Digital.describe()[0].to_string(),
+            "Digital"
+        );
+
+        // Fused processors are flattened into one description per step
+        let fused = CircleDeadZoneThenBounds::new(
+            CircleDeadZone::new(0.2),
+            DualAxisBounds::symmetric_all(0.5),
+        );
+        let names: Vec<String> = DualAxisProcessor::from(fused)
+            .describe()
+            .into_iter()
+            .map(|description| description.name)
+            .collect();
+        assert_eq!(names, ["CircleDeadZone", "ValueBounds"]);
+    }
+
+    #[test]
+    fn test_dual_axis_sensitivity_non_finite() {
+        assert_eq!(DualAxisSensitivity::try_new(f32::NAN, 1.0), None);
+        assert_eq!(DualAxisSensitivity::try_new(1.0, f32::INFINITY), None);
+        assert_eq!(DualAxisSensitivity::try_new(f32::NEG_INFINITY, 1.0), None);
+        assert_eq!(
+            DualAxisSensitivity::try_new(2.0, -0.5),
+            Some(DualAxisSensitivity::new(2.0, -0.5))
+        );
+
+        // A NaN sensitivity is neutralized instead of corrupting the output
+        let nan = DualAxisSensitivity::new(f32::NAN, 2.0);
+        assert!(!nan.is_finite());
+        let processor = DualAxisProcessor::from(nan);
+        assert_eq!(processor.process(Vec2::new(0.5, 0.5)), Vec2::new(0.5, 1.0));
+
+        // NaN inputs don't persist past the processor
+        let double = DualAxisSensitivity::all(2.0);
+        assert_eq!(double.scale(Vec2::new(f32::NAN, 0.25)), Vec2::new(0.0, 0.5));
+        assert_eq!(double.scale(Vec2::new(f32::MAX, 0.0)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_apply_pipeline() {
+        let pipeline = [
+            DualAxisProcessor::from(CircleDeadZone::new(0.2)),
+            DualAxisProcessor::from(DualAxisSensitivity::new(2.0, 1.0)),
+            DualAxisProcessor::from(CircleBounds::new(1.0)),
+        ];
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                let expected = pipeline
+                    .iter()
+                    .fold(value, |value, processor| processor.process(value));
+                assert_eq!(apply_pipeline(&pipeline, value), expected);
+                assert_eq!(
+                    apply_processor(pipeline[0].clone(), value),
+                    pipeline[0].process(value)
+                );
+            }
+        }
+
+        // Inside the deadzone
+        assert_eq!(apply_pipeline(&pipeline, Vec2::new(0.1, 0.1)), Vec2::ZERO);
+
+        // Scaled horizontally, then clamped to the unit circle
+        assert_eq!(apply_pipeline(&pipeline, Vec2::X), Vec2::X);
+        assert_eq!(
+            apply_pipeline(&pipeline, Vec2::new(0.6, 0.0)),
+            Vec2::new(1.0, 0.0)
+        );
+
+        // An empty pipeline leaves values unchanged
+        assert_eq!(
+            apply_pipeline(&[], Vec2::new(0.3, -4.0)),
+            Vec2::new(0.3, -4.0)
+        );
+    }
+
+    #[test]
+    fn test_dual_axis_per_second_processor() {
+        let processor = DualAxisProcessor::PerSecond;
+        let motion = Vec2::new(3.0, -1.5);
+
+        // Twice the motion over twice the delta time is the same rate
+        let rate = processor.process_with_delta(motion, 0.25);
+        assert_eq!(rate, Vec2::new(12.0, -6.0));
+        assert_eq!(processor.process_with_delta(2.0 * motion, 0.5), rate);
+
+        assert_eq!(processor.process_with_delta(motion, 0.0), Vec2::ZERO);
+        assert_eq!(processor.process(motion), motion);
+        assert_eq!(processor.describe()[0].to_string(), "PerSecond");
+    }
 }
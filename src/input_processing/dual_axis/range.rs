@@ -715,10 +715,48 @@ impl DualAxisExclusion {
         )
     }
 
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone begins.
+    ///
+    /// Input values below these thresholds are excluded,
+    /// while greater values are passed through unchanged.
+    #[must_use]
+    #[inline]
+    pub fn livezone_lower_bound(&self) -> Vec2 {
+        Vec2::new(self.exclusion_x.max(), self.exclusion_y.max())
+    }
+
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone ends.
+    ///
+    /// As exclusions don't limit input values, this is always [`Vec2::INFINITY`].
+    #[must_use]
+    #[inline]
+    pub fn livezone_upper_bound(&self) -> Vec2 {
+        Vec2::INFINITY
+    }
+
     /// Creates a [`DualAxisDeadZone`] using `self` as the exclusion range.
     pub fn scaled(self) -> DualAxisDeadZone {
         DualAxisDeadZone::new(self.exclusion_x.min_max(), self.exclusion_y.min_max())
     }
+
+    /// Creates a [`RectExclusion`] that ignores values within the rectangle `[x_min, x_max] × [y_min, y_max]`,
+    /// which doesn't need to contain the origin.
+    ///
+    /// Unlike a cross-shaped [`DualAxisExclusion`], both axes are zeroed together,
+    /// and only when the value lies inside the rectangle.
+    ///
+    /// # Requirements
+    ///
+    /// - `x_min` <= `x_max`.
+    /// - `y_min` <= `y_max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn rect(x_min: f32, x_max: f32, y_min: f32, y_max: f32) -> RectExclusion {
+        RectExclusion::new(x_min, x_max, y_min, y_max)
+    }
 }
 
 impl Default for DualAxisExclusion {
@@ -788,6 +826,91 @@ impl From<AxisExclusion> for DualAxisProcessor {
     }
 }
 
+/// Specifies a rectangular region for excluding dual-axis inputs,
+/// with arbitrary min-max ranges for each axis that don't need to contain the origin,
+/// helping filter out the resting positions of sticks that aren't centered.
+///
+/// Values inside the rectangle are treated as zeros, while values outside of it are passed through unchanged.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Exclude the region [0.1, 0.3] × [-0.2, 0.0].
+/// let exclusion = DualAxisExclusion::rect(0.1, 0.3, -0.2, 0.0);
+/// assert_eq!(exclusion.range_x().min_max(), (0.1, 0.3));
+/// assert_eq!(exclusion.range_y().min_max(), (-0.2, 0.0));
+///
+/// assert_eq!(exclusion.exclude(Vec2::new(0.2, -0.1)), Vec2::ZERO);
+/// assert_eq!(exclusion.exclude(Vec2::new(0.0, -0.1)), Vec2::new(0.0, -0.1));
+/// assert_eq!(exclusion.exclude(Vec2::new(0.2, 0.5)), Vec2::new(0.2, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct RectExclusion {
+    /// The excluded range of X values.
+    pub(crate) range_x: AxisBounds,
+
+    /// The excluded range of Y values.
+    pub(crate) range_y: AxisBounds,
+}
+
+impl RectExclusion {
+    /// Creates a [`RectExclusion`] that ignores values within the rectangle `[x_min, x_max] × [y_min, y_max]`.
+    ///
+    /// # Requirements
+    ///
+    /// - `x_min` <= `x_max`.
+    /// - `y_min` <= `y_max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(x_min: f32, x_max: f32, y_min: f32, y_max: f32) -> Self {
+        Self {
+            range_x: AxisBounds::new(x_min, x_max),
+            range_y: AxisBounds::new(y_min, y_max),
+        }
+    }
+
+    /// Returns the excluded range of X values.
+    #[inline]
+    pub fn range_x(&self) -> AxisBounds {
+        self.range_x
+    }
+
+    /// Returns the excluded range of Y values.
+    #[inline]
+    pub fn range_y(&self) -> AxisBounds {
+        self.range_y
+    }
+
+    /// Is the `input_value` within the excluded rectangle?
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, input_value: Vec2) -> bool {
+        self.range_x.contains(input_value.x) && self.range_y.contains(input_value.y)
+    }
+
+    /// Excludes values within the specified rectangle.
+    #[must_use]
+    #[inline]
+    pub fn exclude(&self, input_value: Vec2) -> Vec2 {
+        if self.contains(input_value) {
+            Vec2::ZERO
+        } else {
+            input_value
+        }
+    }
+}
+
+impl From<RectExclusion> for DualAxisProcessor {
+    fn from(value: RectExclusion) -> Self {
+        Self::RectExclusion(value)
+    }
+}
+
 /// A scaled version of [`DualAxisExclusion`] with the bounds
 /// set to [`DualAxisBounds::symmetric_all(1.0)`](DualAxisBounds::default)
 /// that normalizes non-excluded input values into the "live zone",
@@ -1142,6 +1265,31 @@ impl DualAxisDeadZone {
         DualAxisBounds::default()
     }
 
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone begins.
+    ///
+    /// Input values below these thresholds are excluded,
+    /// while greater values are scaled into the live zone.
+    #[must_use]
+    #[inline]
+    pub fn livezone_lower_bound(&self) -> Vec2 {
+        Vec2::new(
+            self.deadzone_x.livezone_upper_min_max().0,
+            self.deadzone_y.livezone_upper_min_max().0,
+        )
+    }
+
+    /// Returns the magnitudes along the positive X and Y axes at which the live zone ends.
+    ///
+    /// Input values beyond these thresholds are clamped to the maximum output.
+    #[must_use]
+    #[inline]
+    pub fn livezone_upper_bound(&self) -> Vec2 {
+        Vec2::new(
+            self.deadzone_x.livezone_upper_min_max().1,
+            self.deadzone_y.livezone_upper_min_max().1,
+        )
+    }
+
     /// Is the given `input_value` within the exclusion ranges?
     #[must_use]
     #[inline]
@@ -1191,6 +1339,13 @@ impl DualAxisDeadZone {
             self.deadzone_y.normalize(input_value.y),
         )
     }
+
+    /// Creates a [`DirectionalDeadZone`] that uses `self` for its thresholds
+    /// but keeps the direction of input values.
+    #[inline]
+    pub const fn preserving_direction(self) -> DirectionalDeadZone {
+        DirectionalDeadZone(self)
+    }
 }
 
 impl Default for DualAxisDeadZone {
@@ -1259,6 +1414,72 @@ impl From<AxisDeadZone> for DualAxisProcessor {
     }
 }
 
+/// A [`DualAxisDeadZone`] that keeps the direction of input values exactly.
+///
+/// [`DualAxisDeadZone`] normalizes each axis independently,
+/// so diagonal values near the dead zone edge are bent towards the axes.
+/// This processor applies the same per-axis thresholds,
+/// treating values excluded on both axes as zeros,
+/// but only uses the per-axis result for the magnitude of the output,
+/// pointing it in the same direction as the input.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let deadzone = DualAxisDeadZone::symmetric_all(0.25);
+/// let directional = deadzone.preserving_direction();
+/// assert_eq!(directional, DirectionalDeadZone::new(deadzone));
+///
+/// // Values excluded on both axes are still treated as zeros
+/// assert_eq!(directional.normalize(Vec2::new(0.125, -0.2)), Vec2::ZERO);
+///
+/// // The per-axis dead zone snaps the X-axis, while this one keeps the direction
+/// let value = Vec2::new(0.125, 0.625);
+/// assert_eq!(deadzone.normalize(value), Vec2::new(0.0, 0.5));
+/// assert!(directional.normalize(value).normalize().abs_diff_eq(value.normalize(), 1e-6));
+/// assert!((directional.normalize(value).length() - 0.5).abs() < 1e-6);
+/// ```
+#[doc(alias("RadialAxialDeadZone"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DirectionalDeadZone(pub(crate) DualAxisDeadZone);
+
+impl DirectionalDeadZone {
+    /// Creates a [`DirectionalDeadZone`] with the thresholds of the given `deadzone`.
+    #[inline]
+    pub const fn new(deadzone: DualAxisDeadZone) -> Self {
+        Self(deadzone)
+    }
+
+    /// Returns the [`DualAxisDeadZone`] whose thresholds are used.
+    #[inline]
+    pub fn deadzone(&self) -> DualAxisDeadZone {
+        self.0
+    }
+
+    /// Normalizes input values into the live zone, keeping their direction.
+    #[must_use]
+    #[inline]
+    pub fn normalize(&self, input_value: Vec2) -> Vec2 {
+        let magnitude = self.0.normalize(input_value).length();
+        input_value.normalize_or_zero() * magnitude
+    }
+}
+
+impl Default for DirectionalDeadZone {
+    /// Creates a [`DirectionalDeadZone`] that excludes input values within the deadzone `[-0.1, 0.1]` on both axes.
+    fn default() -> Self {
+        DualAxisDeadZone::default().preserving_direction()
+    }
+}
+
+impl From<DirectionalDeadZone> for DualAxisProcessor {
+    fn from(value: DirectionalDeadZone) -> Self {
+        Self::DirectionalDeadZone(value)
+    }
+}
+
 impl From<DualAxisExclusion> for DualAxisDeadZone {
     fn from(exclusion: DualAxisExclusion) -> Self {
         Self::new(
@@ -1508,6 +1729,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rect_exclusion() {
+        let exclusion = DualAxisExclusion::rect(0.1, 0.5, -0.4, -0.2);
+        assert_eq!(exclusion, RectExclusion::new(0.1, 0.5, -0.4, -0.2));
+        assert_eq!(exclusion.range_x(), AxisBounds::new(0.1, 0.5));
+        assert_eq!(exclusion.range_y(), AxisBounds::new(-0.4, -0.2));
+
+        let processor = DualAxisProcessor::RectExclusion(exclusion);
+        assert_eq!(DualAxisProcessor::from(exclusion), processor);
+
+        // The origin lies outside of the off-center rectangle
+        assert!(!exclusion.contains(Vec2::ZERO));
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                assert_eq!(processor.process(value), exclusion.exclude(value));
+
+                if (0.1..=0.5).contains(&x) && (-0.4..=-0.2).contains(&y) {
+                    assert!(exclusion.contains(value));
+                    assert_eq!(exclusion.exclude(value), Vec2::ZERO);
+                } else {
+                    assert!(!exclusion.contains(value));
+                    assert_eq!(exclusion.exclude(value), value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dual_axis_livezone_bounds() {
+        let exclusion = DualAxisExclusion::new((-0.1, 0.2), (-0.3, 0.4));
+        assert_eq!(exclusion.livezone_lower_bound(), Vec2::new(0.2, 0.4));
+        assert_eq!(exclusion.livezone_upper_bound(), Vec2::INFINITY);
+
+        let deadzone = DualAxisDeadZone::new((-0.1, 0.2), (-0.3, 0.4));
+        assert_eq!(deadzone.livezone_lower_bound(), Vec2::new(0.2, 0.4));
+        assert_eq!(deadzone.livezone_upper_bound(), Vec2::ONE);
+
+        // Values just inside the live zone produce output, values just outside don't
+        let below = deadzone.livezone_lower_bound() - 0.01;
+        let above = deadzone.livezone_lower_bound() + 0.01;
+        assert_eq!(deadzone.normalize(below), Vec2::ZERO);
+        assert!(deadzone.normalize(above).cmpgt(Vec2::ZERO).all());
+    }
+
+    #[test]
+    fn test_directional_deadzone() {
+        let deadzone = DualAxisDeadZone::symmetric_all(0.2);
+        let directional = deadzone.preserving_direction();
+        let processor = DualAxisProcessor::DirectionalDeadZone(directional);
+        assert_eq!(DualAxisProcessor::from(directional), processor);
+        assert_eq!(directional.deadzone(), deadzone);
+
+        // A diagonal value just outside of the dead zone edge
+        let diagonal = Vec2::new(0.25, 0.5);
+        assert!(!deadzone
+            .normalize(diagonal)
+            .normalize()
+            .abs_diff_eq(diagonal.normalize(), 1e-3));
+        assert!(processor
+            .process(diagonal)
+            .normalize()
+            .abs_diff_eq(diagonal.normalize(), 1e-6));
+
+        for x in -300..300 {
+            let x = x as f32 * 0.01;
+
+            for y in -300..300 {
+                let y = y as f32 * 0.01;
+                let value = Vec2::new(x, y);
+
+                let expected_length = deadzone.normalize(value).length();
+                let result = processor.process(value);
+                assert_eq!(result, directional.normalize(value));
+
+                if deadzone.within_exclusion(value).all() {
+                    assert_eq!(result, Vec2::ZERO);
+                } else {
+                    assert!((result.length() - expected_length).abs() <= 1e-5);
+                    assert!(result.normalize().abs_diff_eq(value.normalize(), 1e-5));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_dual_axis_deadzone() {
         fn test_deadzone(
@@ -15,6 +15,10 @@
 //!
 //! Feel free to suggest additions to the built-in processors if you have a common use case!
 //!
+//! Processors are plain functions of their input values, so they aren't limited to user inputs.
+//! Use [`apply_processor`] and [`apply_pipeline`] to reuse the same math on any [`Vec2`](bevy::prelude::Vec2),
+//! such as the aim of an AI-controlled character.
+//!
 //! # Built-in Processors
 //!
 //! ## Digital Conversion
@@ -40,6 +44,30 @@
 //!
 //! - [`AxisProcessor::Sensitivity`]: Single-axis scaling.
 //! - [`DualAxisSensitivity`]: Dual-axis scaling, implemented [`Into<DualAxisProcessor>`].
+//! - [`DualAxisBandedSensitivity`]: Dual-axis scaling that depends on the input magnitude,
+//!   implemented [`Into<DualAxisProcessor>`].
+//!
+//! ## Frame Rate Independence
+//!
+//! Per-second processors divide values accumulated over a frame by the frame's delta time,
+//! so that mouse motion describes a speed rather than depending on the frame rate.
+//!
+//! - [`AxisProcessor::PerSecond`]: Single-axis rate conversion.
+//! - [`DualAxisProcessor::PerSecond`]: Dual-axis rate conversion.
+//!
+//! ## Rotation
+//!
+//! Rotation turns dual-axis input values by a constant angle,
+//! compensating for controllers that are physically rotated.
+//!
+//! - [`DualAxisRotation`]: Dual-axis rotation, implemented [`Into<DualAxisProcessor>`].
+//!
+//! ## Blending
+//!
+//! Blending interpolates between the outputs of two processors by a weight,
+//! such as to smoothly fade between two sensitivity presets.
+//!
+//! - [`DualAxisBlend`]: Dual-axis blending, implemented [`Into<DualAxisProcessor>`].
 //!
 //! ## Value Bounds
 //!
@@ -66,6 +94,8 @@
 //!     implemented [`Into<AxisProcessor>`] and [`Into<DualAxisProcessor>`].
 //! - [`DualAxisExclusion`]: A cross-shaped region for excluding dual-axis inputs,
 //!     with independent min-max ranges for each axis, implemented [`Into<DualAxisProcessor>`].
+//! - [`RectExclusion`]: A rectangular region for excluding dual-axis inputs,
+//!   which doesn't need to contain the origin, implemented [`Into<DualAxisProcessor>`].
 //! - [`CircleExclusion`]: A circular region for excluding dual-axis inputs,
 //!     with a radius defining the maximum excluded magnitude, implemented [`Into<DualAxisProcessor>`].
 //!
@@ -80,8 +110,25 @@
 //!     implemented [`Into<AxisProcessor>`] and [`Into<DualAxisProcessor>`].
 //! - [`DualAxisDeadZone`]: A scaled version of [`DualAxisExclusion`] with the bounds
 //!     set to [`DualAxisBounds::symmetric_all(1.0)`](DualAxisBounds::default), implemented [`Into<DualAxisProcessor>`].
+//! - [`DirectionalDeadZone`]: A [`DualAxisDeadZone`] that keeps the input direction, implemented [`Into<DualAxisProcessor>`].
 //! - [`CircleDeadZone`]: A scaled version of [`CircleExclusion`] with the bounds
 //!     set to [`CircleBounds::new(1.0)`](CircleBounds::default), implemented [`Into<DualAxisProcessor>`].
+//!
+//! ### Anti-Dead Zones
+//!
+//! Anti-dead zones counteract dead zones applied after processing, such as by the game engine,
+//! lifting any non-zero input value to a minimum magnitude.
+//!
+//! - [`CircleAntiDeadZone`]: A minimum magnitude for non-zero dual-axis inputs, implemented [`Into<DualAxisProcessor>`].
+//!
+//! ## Calibration
+//!
+//! Calibration stretches the range actually reached by a worn or cheap controller
+//! back to the full range of input values.
+//!
+//! - [`AxisCalibration`]: Records the range of single-axis values observed during a calibration phase.
+//! - [`AxisRescale`]: Stretches single-axis values to `[-1.0, 1.0]`, implemented [`Into<AxisProcessor>`].
+//! - [`AxisRemap`]: Maps single-axis values from one range to another, implemented [`Into<AxisProcessor>`].
 
 pub use self::dual_axis::*;
 pub use self::single_axis::*;
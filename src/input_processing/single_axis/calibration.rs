@@ -0,0 +1,389 @@
+//! Calibration for single-axis inputs that don't reach their full range
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    math::FloatOrd,
+    prelude::{Component, Reflect, ReflectComponent, ReflectResource, Resource},
+};
+use serde::{Deserialize, Serialize};
+
+use super::{AxisBounds, AxisProcessor};
+
+/// Records the range of values observed on a single axis during a calibration phase,
+/// learning an [`AxisRescale`] that stretches the observed range back to `[-1.0, 1.0]`.
+///
+/// Cheap or worn-out gamepads often fail to reach the full range of their axes.
+/// Ask the player to move the axis to its extremes between [`AxisCalibration::begin_calibration`]
+/// and [`AxisCalibration::end_calibration`], feeding the raw values into [`AxisCalibration::record`],
+/// then append the learned [`AxisRescale`] to the processing pipeline of the axis.
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// let mut calibration = AxisCalibration::default();
+///
+/// calibration.begin_calibration();
+/// for value in [0.0, 0.4, 0.8, -0.3, -0.6] {
+///     calibration.record(value);
+/// }
+/// let rescale = calibration.end_calibration().unwrap();
+///
+/// // The observed extremes are mapped to the full range
+/// assert_eq!(rescale.rescale(0.8), 1.0);
+/// assert_eq!(rescale.rescale(-0.6), -1.0);
+/// assert_eq!(rescale.rescale(0.4), 0.5);
+///
+/// // Values outside of calibration are ignored
+/// calibration.record(2.0);
+/// assert_eq!(calibration.observed_range(), Some(AxisBounds::new(-0.6, 0.8)));
+/// ```
+#[derive(Resource, Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Component)]
+pub struct AxisCalibration {
+    /// Are recorded values currently being used to learn the range?
+    calibrating: bool,
+
+    /// The range of values recorded during the current or most recent calibration phase.
+    observed: Option<AxisBounds>,
+}
+
+impl AxisCalibration {
+    /// Starts a new calibration phase, forgetting any previously observed range.
+    #[inline]
+    pub fn begin_calibration(&mut self) {
+        self.calibrating = true;
+        self.observed = None;
+    }
+
+    /// Ends the current calibration phase,
+    /// returning the [`AxisRescale`] learned from the observed range.
+    ///
+    /// Returns [`None`] if no values were recorded.
+    #[inline]
+    pub fn end_calibration(&mut self) -> Option<AxisRescale> {
+        self.calibrating = false;
+        self.rescale()
+    }
+
+    /// Is a calibration phase currently in progress?
+    #[must_use]
+    #[inline]
+    pub const fn is_calibrating(&self) -> bool {
+        self.calibrating
+    }
+
+    /// Expands the observed range to include the raw `input_value`.
+    ///
+    /// Does nothing outside of a calibration phase.
+    #[inline]
+    pub fn record(&mut self, input_value: f32) {
+        if !self.calibrating || input_value.is_nan() {
+            return;
+        }
+
+        let (min, max) = self
+            .observed
+            .map_or((input_value, input_value), |bounds| bounds.min_max());
+        self.observed = Some(AxisBounds::new(min.min(input_value), max.max(input_value)));
+    }
+
+    /// Returns the range of values recorded during the current or most recent calibration phase.
+    #[must_use]
+    #[inline]
+    pub fn observed_range(&self) -> Option<AxisBounds> {
+        self.observed
+    }
+
+    /// Returns the [`AxisRescale`] learned from the observed range, if any values were recorded.
+    #[must_use]
+    #[inline]
+    pub fn rescale(&self) -> Option<AxisRescale> {
+        self.observed
+            .map(|bounds| AxisRescale::new(bounds.min().min(0.0), bounds.max().max(0.0)))
+    }
+}
+
+/// Stretches single-axis input values from the range `[min, max]` to `[-1.0, 1.0]`,
+/// typically learned with an [`AxisCalibration`].
+///
+/// Each side of the axis is scaled independently so that `0.0` stays at rest,
+/// and the results are clamped to `[-1.0, 1.0]`.
+/// A side whose extreme is `0.0` is left unscaled.
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// // The axis only reaches -0.5 and 0.8
+/// let rescale = AxisRescale::new(-0.5, 0.8);
+///
+/// // The ways to create an AxisProcessor.
+/// let processor = AxisProcessor::from(rescale);
+/// assert_eq!(processor, AxisProcessor::Rescale(rescale));
+///
+/// assert_eq!(rescale.rescale(0.0), 0.0);
+/// assert_eq!(rescale.rescale(0.4), 0.5);
+/// assert_eq!(rescale.rescale(-0.5), -1.0);
+/// assert_eq!(rescale.rescale(0.9), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct AxisRescale {
+    /// The most negative value reached by the axis.
+    pub(crate) min: f32,
+
+    /// The most positive value reached by the axis.
+    pub(crate) max: f32,
+}
+
+impl AxisRescale {
+    /// Creates an [`AxisRescale`] that stretches values from the range `[min, max]` to `[-1.0, 1.0]`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` <= `0.0` <= `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(min: f32, max: f32) -> Self {
+        assert!(min <= 0.0);
+        assert!(0.0 <= max);
+        Self { min, max }
+    }
+
+    /// Returns the range that is stretched to `[-1.0, 1.0]`.
+    #[must_use]
+    #[inline]
+    pub fn min_max(&self) -> (f32, f32) {
+        (self.min, self.max)
+    }
+
+    /// Stretches the `input_value` from `[min, max]` to `[-1.0, 1.0]`.
+    #[must_use]
+    #[inline]
+    pub fn rescale(&self, input_value: f32) -> f32 {
+        let scaled = if input_value > 0.0 && self.max > 0.0 {
+            input_value / self.max
+        } else if input_value < 0.0 && self.min < 0.0 {
+            input_value / -self.min
+        } else {
+            input_value
+        };
+
+        scaled.clamp(-1.0, 1.0)
+    }
+}
+
+impl Default for AxisRescale {
+    /// Creates an [`AxisRescale`] for an axis that already reaches the range `[-1.0, 1.0]`.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min: -1.0,
+            max: 1.0,
+        }
+    }
+}
+
+impl From<AxisRescale> for AxisProcessor {
+    fn from(value: AxisRescale) -> Self {
+        Self::Rescale(value)
+    }
+}
+
+impl Eq for AxisRescale {}
+
+impl Hash for AxisRescale {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.min).hash(state);
+        FloatOrd(self.max).hash(state);
+    }
+}
+
+/// Linearly maps single-axis input values from the range `[in_min, in_max]` to `[out_min, out_max]`,
+/// clamping values outside of the input range to the nearest end of the output range.
+///
+/// This is handy for triggers that rest away from zero or never reach their full range.
+/// If `in_min` equals `in_max`, values below it map to `out_min`, and all other values map to `out_max`.
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// // A trigger that rests at 0.25 and tops out at 0.75
+/// let remap = AxisRemap::new(0.25, 0.75, 0.0, 1.0);
+///
+/// // The ways to create an AxisProcessor.
+/// let processor = AxisProcessor::from(remap);
+/// assert_eq!(processor, AxisProcessor::Remap(remap));
+///
+/// assert_eq!(remap.remap(0.25), 0.0);
+/// assert_eq!(remap.remap(0.5), 0.5);
+/// assert_eq!(remap.remap(0.75), 1.0);
+///
+/// // Values outside the input range are clamped
+/// assert_eq!(remap.remap(0.0), 0.0);
+/// assert_eq!(remap.remap(1.0), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct AxisRemap {
+    /// The input value mapped to `out_min`.
+    pub(crate) in_min: f32,
+
+    /// The input value mapped to `out_max`.
+    pub(crate) in_max: f32,
+
+    /// The output value for inputs at or below `in_min`.
+    pub(crate) out_min: f32,
+
+    /// The output value for inputs at or above `in_max`.
+    pub(crate) out_max: f32,
+}
+
+impl AxisRemap {
+    /// Creates an [`AxisRemap`] that maps values from the range `[in_min, in_max]` to `[out_min, out_max]`.
+    #[inline]
+    pub const fn new(in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> Self {
+        Self {
+            in_min,
+            in_max,
+            out_min,
+            out_max,
+        }
+    }
+
+    /// Returns the input range `(in_min, in_max)`.
+    #[must_use]
+    #[inline]
+    pub fn input_range(&self) -> (f32, f32) {
+        (self.in_min, self.in_max)
+    }
+
+    /// Returns the output range `(out_min, out_max)`.
+    #[must_use]
+    #[inline]
+    pub fn output_range(&self) -> (f32, f32) {
+        (self.out_min, self.out_max)
+    }
+
+    /// Maps the `input_value` from `[in_min, in_max]` to `[out_min, out_max]`.
+    #[must_use]
+    #[inline]
+    pub fn remap(&self, input_value: f32) -> f32 {
+        let t = if self.in_min == self.in_max {
+            // Avoid dividing by zero for an empty input range
+            if input_value < self.in_min {
+                0.0
+            } else {
+                1.0
+            }
+        } else {
+            ((input_value - self.in_min) / (self.in_max - self.in_min)).clamp(0.0, 1.0)
+        };
+
+        self.out_min + (self.out_max - self.out_min) * t
+    }
+}
+
+impl Default for AxisRemap {
+    /// Creates an [`AxisRemap`] that leaves values in the range `[-1.0, 1.0]` unchanged.
+    #[inline]
+    fn default() -> Self {
+        Self::new(-1.0, 1.0, -1.0, 1.0)
+    }
+}
+
+impl From<AxisRemap> for AxisProcessor {
+    fn from(value: AxisRemap) -> Self {
+        Self::Remap(value)
+    }
+}
+
+impl Eq for AxisRemap {}
+
+impl Hash for AxisRemap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.in_min).hash(state);
+        FloatOrd(self.in_max).hash(state);
+        FloatOrd(self.out_min).hash(state);
+        FloatOrd(self.out_max).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_calibration() {
+        let mut calibration = AxisCalibration::default();
+        assert!(!calibration.is_calibrating());
+        assert_eq!(calibration.end_calibration(), None);
+
+        // Values are ignored outside of calibration
+        calibration.record(0.5);
+        assert_eq!(calibration.observed_range(), None);
+
+        // A cheap gamepad that only reaches [-0.7, 0.6]
+        calibration.begin_calibration();
+        assert!(calibration.is_calibrating());
+        for value in -70..=60 {
+            calibration.record(value as f32 / 100.0);
+        }
+
+        let rescale = calibration.end_calibration().unwrap();
+        assert!(!calibration.is_calibrating());
+        assert_eq!(rescale, AxisRescale::new(-0.7, 0.6));
+
+        let processor = AxisProcessor::from(rescale);
+        assert_eq!(processor.process(0.0), 0.0);
+        assert_eq!(processor.process(0.6), 1.0);
+        assert_eq!(processor.process(-0.7), -1.0);
+        assert_eq!(processor.process(0.3), 0.5);
+        assert_eq!(processor.process(-0.35), -0.5);
+
+        // Values beyond the observed range are clamped
+        assert_eq!(processor.process(0.9), 1.0);
+        assert_eq!(processor.process(-0.9), -1.0);
+
+        // Starting over forgets the previous range
+        calibration.begin_calibration();
+        assert_eq!(calibration.observed_range(), None);
+        calibration.record(0.4);
+        assert_eq!(
+            calibration.end_calibration(),
+            Some(AxisRescale::new(0.0, 0.4))
+        );
+    }
+
+    #[test]
+    fn test_axis_remap() {
+        // A trigger that rests at 0.2 and tops out at 0.9
+        let remap = AxisRemap::new(0.2, 0.9, 0.0, 1.0);
+        assert_eq!(remap.input_range(), (0.2, 0.9));
+        assert_eq!(remap.output_range(), (0.0, 1.0));
+
+        let processor = AxisProcessor::from(remap);
+        assert_eq!(processor, AxisProcessor::Remap(remap));
+        assert_eq!(processor.process(0.2), 0.0);
+        assert_eq!(processor.process(0.9), 1.0);
+        assert!((processor.process(0.55) - 0.5).abs() <= f32::EPSILON);
+
+        // Values outside the input range are clamped
+        assert_eq!(processor.process(0.0), 0.0);
+        assert_eq!(processor.process(1.0), 1.0);
+
+        // Reversed output ranges flip the direction
+        let reversed = AxisRemap::new(0.2, 0.9, 1.0, 0.0);
+        assert_eq!(reversed.remap(0.2), 1.0);
+        assert_eq!(reversed.remap(0.9), 0.0);
+
+        // An empty input range acts as a threshold instead of dividing by zero
+        let threshold = AxisRemap::new(0.5, 0.5, -1.0, 1.0);
+        assert_eq!(threshold.remap(0.4), -1.0);
+        assert_eq!(threshold.remap(0.5), 1.0);
+        assert_eq!(threshold.remap(0.6), 1.0);
+    }
+}
@@ -5,9 +5,11 @@ use std::hash::{Hash, Hasher};
 use bevy::{math::FloatOrd, prelude::Reflect};
 use serde::{Deserialize, Serialize};
 
+pub use self::calibration::*;
 pub use self::custom::*;
 pub use self::range::*;
 
+mod calibration;
 mod custom;
 mod range;
 
@@ -63,6 +65,42 @@ pub enum AxisProcessor {
     /// ```
     Sensitivity(f32),
 
+    /// Rounds input values to the nearest multiple of the specified step,
+    /// snapping them to a fixed grid so that they are reproducible across machines.
+    ///
+    /// A step of `0.0` leaves values unchanged.
+    /// Halfway values are rounded away from zero, so the result is symmetric around zero.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// assert_eq!(AxisProcessor::Quantize(0.25).process(0.3), 0.25);
+    /// assert_eq!(AxisProcessor::Quantize(0.25).process(-0.3), -0.25);
+    /// assert_eq!(AxisProcessor::Quantize(0.25).process(0.4), 0.5);
+    ///
+    /// // Zero step is the identity
+    /// assert_eq!(AxisProcessor::Quantize(0.0).process(0.3), 0.3);
+    /// ```
+    Quantize(f32),
+
+    /// Divides input values by the duration of the frame in seconds,
+    /// turning amounts accumulated over a frame (such as mouse motion) into rates
+    /// that don't depend on the frame rate.
+    ///
+    /// This needs the delta time of the frame, so it only takes effect in [`AxisProcessor::process_with_delta`],
+    /// which is used by the processing pipelines of all inputs and of the [`InputMap`](crate::input_map::InputMap).
+    /// [`AxisProcessor::process`] leaves values unchanged, so calling it directly on this processor has no effect.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// // 2 pixels in a 1/64s frame and 4 pixels in a 1/32s frame are the same rate
+    /// let rate = AxisProcessor::PerSecond.process_with_delta(2.0, 1.0 / 64.0);
+    /// assert_eq!(rate, 128.0);
+    /// assert_eq!(AxisProcessor::PerSecond.process_with_delta(4.0, 1.0 / 32.0), rate);
+    /// ```
+    PerSecond,
+
     /// A wrapper around [`AxisBounds`] to represent value bounds.
     ValueBounds(AxisBounds),
 
@@ -72,6 +110,12 @@ pub enum AxisProcessor {
     /// A wrapper around [`AxisDeadZone`] to represent scaled deadzone.
     DeadZone(AxisDeadZone),
 
+    /// A wrapper around [`AxisRescale`] to represent a calibrated range.
+    Rescale(AxisRescale),
+
+    /// A wrapper around [`AxisRemap`] to represent a linear mapping between ranges.
+    Remap(AxisRemap),
+
     /// A user-defined processor that implements [`CustomAxisProcessor`].
     Custom(Box<dyn CustomAxisProcessor>),
 }
@@ -91,12 +135,51 @@ impl AxisProcessor {
             }
             Self::Inverted => -input_value,
             Self::Sensitivity(sensitivity) => sensitivity * input_value,
+            Self::Quantize(step) => quantize(input_value, *step),
+            Self::PerSecond => input_value,
             Self::ValueBounds(bounds) => bounds.clamp(input_value),
             Self::Exclusion(exclusion) => exclusion.exclude(input_value),
             Self::DeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::Rescale(rescale) => rescale.rescale(input_value),
+            Self::Remap(remap) => remap.remap(input_value),
             Self::Custom(processor) => processor.process(input_value),
         }
     }
+
+    /// Computes the result by processing the `input_value` accumulated over a frame lasting `delta_secs` seconds.
+    ///
+    /// Unlike [`AxisProcessor::process`], this applies [`AxisProcessor::PerSecond`].
+    #[must_use]
+    #[inline]
+    pub fn process_with_delta(&self, input_value: f32, delta_secs: f32) -> f32 {
+        match self {
+            Self::PerSecond => input_value * per_second_factor(delta_secs),
+            _ => self.process(input_value),
+        }
+    }
+}
+
+/// Returns the factor that turns a value accumulated over a frame lasting `delta_secs` seconds into a rate,
+/// or `0.0` if the frame has no valid duration, such as before time first advances.
+#[must_use]
+#[inline]
+pub(crate) fn per_second_factor(delta_secs: f32) -> f32 {
+    if delta_secs > 0.0 && delta_secs.is_finite() {
+        delta_secs.recip()
+    } else {
+        0.0
+    }
+}
+
+/// Rounds the `input_value` to the nearest multiple of `step`, leaving it unchanged if `step` is zero.
+#[must_use]
+#[inline]
+pub(crate) fn quantize(input_value: f32, step: f32) -> f32 {
+    if step == 0.0 {
+        input_value
+    } else {
+        (input_value / step).round() * step
+    }
 }
 
 impl Eq for AxisProcessor {}
@@ -108,9 +191,13 @@ impl Hash for AxisProcessor {
             Self::Digital => {}
             Self::Inverted => {}
             Self::Sensitivity(sensitivity) => FloatOrd(*sensitivity).hash(state),
+            Self::Quantize(step) => FloatOrd(*step).hash(state),
+            Self::PerSecond => {}
             Self::ValueBounds(bounds) => bounds.hash(state),
             Self::Exclusion(exclusion) => exclusion.hash(state),
             Self::DeadZone(deadzone) => deadzone.hash(state),
+            Self::Rescale(rescale) => rescale.hash(state),
+            Self::Remap(remap) => remap.hash(state),
             Self::Custom(processor) => processor.hash(state),
         }
     }
@@ -151,6 +238,46 @@ pub trait WithAxisProcessingPipelineExt: Sized {
         self.with_processor(AxisProcessor::Sensitivity(sensitivity))
     }
 
+    /// Appends an [`AxisProcessor::Quantize`] processor as the next processing step,
+    /// rounding values on the axis to the nearest multiple of the given step.
+    #[inline]
+    fn quantized(self, step: f32) -> Self {
+        self.with_processor(AxisProcessor::Quantize(step))
+    }
+
+    /// Appends an [`AxisProcessor::PerSecond`] processor as the next processing step,
+    /// dividing values accumulated over a frame by the frame's delta time.
+    ///
+    /// This is meant for inputs that accumulate over a frame, such as mouse motion and scrolling.
+    /// Values are only divided when the input is read from the [`CentralInputStore`](crate::user_input::updating::CentralInputStore),
+    /// using the delta time recorded there.
+    #[inline]
+    fn per_second(self) -> Self {
+        self.with_processor(AxisProcessor::PerSecond)
+    }
+
+    /// Appends an [`AxisRescale`] processor as the next processing step,
+    /// stretching values from the range `[min, max]` to `[-1.0, 1.0]`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` <= `0.0` <= `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn rescaled(self, min: f32, max: f32) -> Self {
+        self.with_processor(AxisRescale::new(min, max))
+    }
+
+    /// Appends an [`AxisRemap`] processor as the next processing step,
+    /// linearly mapping values from the range `[in_min, in_max]` to `[out_min, out_max]` with clamping.
+    #[inline]
+    fn remapped(self, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> Self {
+        self.with_processor(AxisRemap::new(in_min, in_max, out_min, out_max))
+    }
+
     /// Appends an [`AxisBounds`] processor as the next processing step,
     /// restricting values within the range `[min, max]` on the axis.
     #[inline]
@@ -337,4 +464,46 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_axis_quantize_processor() {
+        let processor = AxisProcessor::Quantize(0.25);
+        assert_eq!(processor.process(0.0), 0.0);
+        assert_eq!(processor.process(0.1), 0.0);
+        assert_eq!(processor.process(0.2), 0.25);
+        assert_eq!(processor.process(0.375), 0.5);
+        assert_eq!(processor.process(0.9), 1.0);
+
+        for value in -300..300 {
+            let value = value as f32 * 0.01;
+
+            // Symmetric around zero
+            assert_eq!(processor.process(-value), -processor.process(value));
+
+            // Snapped to the grid
+            let steps = processor.process(value) / 0.25;
+            assert_eq!(steps, steps.round());
+            assert!((processor.process(value) - value).abs() <= 0.125);
+
+            // Zero step is the identity
+            assert_eq!(AxisProcessor::Quantize(0.0).process(value), value);
+        }
+    }
+
+    #[test]
+    fn test_axis_per_second_processor() {
+        let processor = AxisProcessor::PerSecond;
+
+        // The same rate at different frame rates
+        assert_eq!(processor.process_with_delta(0.5, 0.01), 50.0);
+        assert_eq!(processor.process_with_delta(1.0, 0.02), 50.0);
+
+        // Frames without a valid duration produce no rate
+        assert_eq!(processor.process_with_delta(1.0, 0.0), 0.0);
+        assert_eq!(processor.process_with_delta(1.0, f32::NAN), 0.0);
+
+        // Other processors ignore the delta time
+        assert_eq!(processor.process(3.0), 3.0);
+        assert_eq!(AxisProcessor::Inverted.process_with_delta(3.0, 0.5), -3.0);
+    }
 }
@@ -16,7 +16,11 @@ pub mod axislike;
 pub mod buttonlike;
 pub mod clashing_inputs;
 pub mod common_conditions;
+pub mod input_context;
+#[cfg(feature = "debug_log")]
+pub mod input_debug_log;
 pub mod input_map;
+pub mod input_map_history;
 pub mod input_processing;
 pub mod plugin;
 pub mod systems;
@@ -24,6 +28,7 @@ pub mod systems;
 #[cfg(feature = "timing")]
 pub mod timing;
 pub mod typetag;
+pub mod unmapped_inputs;
 pub mod user_input;
 
 // Importing the derive macro
@@ -35,11 +40,13 @@ pub mod prelude {
 
     pub use crate::action_state::ActionState;
     pub use crate::clashing_inputs::ClashStrategy;
+    pub use crate::input_context::InputContextStack;
     pub use crate::input_map::InputMap;
+    pub use crate::input_map_history::InputMapHistory;
     pub use crate::input_processing::*;
     pub use crate::user_input::*;
 
-    pub use crate::plugin::InputManagerPlugin;
+    pub use crate::plugin::{InputManagerPlugin, InputManagerPlugins, InputManagerSystem};
     pub use crate::{Actionlike, InputManagerBundle};
 
     pub use leafwing_input_manager_macros::serde_typetag;
@@ -102,6 +109,37 @@ pub mod prelude {
 ///    TakePhoto, // This action is controlled by buttons since we have overridden the default option
 /// }
 /// ```
+///
+/// # Actions with data
+///
+/// Variants can carry data, such as a direction or the index of an ability slot.
+/// Each distinct value is a separate action: [`InputMap`] and [`ActionState`] are keyed on the full value,
+/// so `Move(Direction::Up)` and `Move(Direction::Down)` are bound and pressed independently.
+/// As the possible values can't be enumerated automatically, each of them must be bound explicitly.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Reflect)]
+/// enum Direction {
+///    Up,
+///    Down,
+/// }
+///
+/// #[derive(Actionlike, Debug, PartialEq, Eq, Clone, Copy, Hash, Reflect)]
+/// enum PlayerAction {
+///    Move(Direction),
+///    #[actionlike(Axis)]
+///    Zoom { camera: u8 }, // Variants with data can also override the control kind
+/// }
+///
+/// let input_map = InputMap::new([
+///     (PlayerAction::Move(Direction::Up), KeyCode::KeyW),
+///     (PlayerAction::Move(Direction::Down), KeyCode::KeyS),
+/// ])
+/// .with_axis(PlayerAction::Zoom { camera: 0 }, MouseScrollAxis::Y);
+/// ```
 pub trait Actionlike:
     Debug + Eq + Hash + Send + Sync + Clone + Reflect + Typed + TypePath + FromReflect + 'static
 {
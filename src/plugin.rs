@@ -4,7 +4,7 @@ use core::hash::Hash;
 use core::marker::PhantomData;
 use std::fmt::Debug;
 
-use bevy::app::{App, FixedPostUpdate, Plugin, RunFixedMainLoop};
+use bevy::app::{App, FixedPostUpdate, Plugin, PluginGroupBuilder, RunFixedMainLoop};
 use bevy::input::InputSystem;
 #[cfg(feature = "picking")]
 use bevy::picking::PickSet;
@@ -12,6 +12,7 @@ use bevy::prelude::*;
 use bevy::reflect::TypePath;
 #[cfg(feature = "ui")]
 use bevy::ui::UiSystem;
+use bevy::utils::{Duration, Instant};
 use updating::CentralInputStore;
 
 use crate::action_state::{ActionState, ButtonData};
@@ -21,6 +22,7 @@ use crate::input_processing::*;
 use crate::prelude::updating::register_standard_input_kinds;
 #[cfg(feature = "timing")]
 use crate::timing::Timing;
+use crate::unmapped_inputs::UnmappedInputs;
 use crate::user_input::*;
 use crate::Actionlike;
 
@@ -37,6 +39,9 @@ use crate::Actionlike;
 /// If you have more than one distinct type of action (e.g., menu actions, camera actions, and player actions),
 /// consider creating multiple `Actionlike` enums
 /// and adding a copy of this plugin for each `Actionlike` type.
+/// A single entity can hold an [`InputManagerBundle`](crate::InputManagerBundle) for each of these types:
+/// each [`ActionState`] is updated independently from its own [`InputMap`],
+/// and clashes are only resolved between actions of the same type.
 ///
 /// All actions can be dynamically enabled or disabled by calling the relevant methods on
 /// `ActionState<A>`. This can be useful when working with states to pause the game, navigate
@@ -53,9 +58,31 @@ use crate::Actionlike;
 ///
 /// - [`tick_action_state`](crate::systems::tick_action_state), which resets the `pressed` and `just_pressed` fields of the [`ActionState`] each frame
 /// - [`update_action_state`](crate::systems::update_action_state), which collects [`ButtonInput`] resources to update the [`ActionState`]
+/// - [`ignore_held_at_startup`](crate::systems::ignore_held_at_startup), which only runs on the first update,
+///   and only if [`InputManagerPlugin::treat_startup_held_as_pressed`] is disabled
+///
+/// ## Global actions
+///
+/// Besides components, [`ActionState`] and [`InputMap`] can be stored as resources,
+/// which is handy for actions that aren't tied to any entity (such as UI or debug controls).
+/// These are updated by the same systems as the components,
+/// and can be set up via [`InputManagerPlugin::with_global_input_map`].
+///
+/// ## Run conditions
+///
+/// To only process inputs in certain game states, such as while not paused,
+/// pass a run condition to [`InputManagerPlugin::run_if`].
 pub struct InputManagerPlugin<A: Actionlike> {
     _phantom: PhantomData<A>,
     machine: Machine,
+    global_input_map: Option<InputMap<A>>,
+    treat_startup_held_as_pressed: bool,
+    track_fixed_update: bool,
+    input_delay_frames: u32,
+    global_noise_floor: f32,
+    clamp_axis_output: bool,
+    report_unmapped_inputs: bool,
+    run_conditions: Vec<Box<dyn Fn(&mut App) + Send + Sync>>,
 }
 
 // Deriving default induces an undesired bound on the generic
@@ -64,6 +91,14 @@ impl<A: Actionlike> Default for InputManagerPlugin<A> {
         Self {
             _phantom: PhantomData,
             machine: Machine::Client,
+            global_input_map: None,
+            treat_startup_held_as_pressed: true,
+            track_fixed_update: true,
+            input_delay_frames: 0,
+            global_noise_floor: 0.0,
+            clamp_axis_output: false,
+            report_unmapped_inputs: false,
+            run_conditions: Vec::new(),
         }
     }
 }
@@ -79,8 +114,220 @@ impl<A: Actionlike> InputManagerPlugin<A> {
         Self {
             _phantom: PhantomData,
             machine: Machine::Server,
+            global_input_map: None,
+            treat_startup_held_as_pressed: true,
+            track_fixed_update: true,
+            input_delay_frames: 0,
+            global_noise_floor: 0.0,
+            clamp_axis_output: false,
+            report_unmapped_inputs: false,
+            run_conditions: Vec::new(),
         }
     }
+
+    /// Inserts the given `input_map` as a global [`InputMap`] resource when the plugin is built,
+    /// alongside a matching [`ActionState`] resource,
+    /// so that actions can be read without spawning a dedicated entity.
+    #[must_use]
+    pub fn with_global_input_map(mut self, input_map: InputMap<A>) -> Self {
+        self.global_input_map = Some(input_map);
+        self
+    }
+
+    /// Configures whether inputs that are already held during the first update
+    /// are reported as [`just_pressed`](ActionState::just_pressed).
+    ///
+    /// This is `true` by default.
+    /// When disabled, such actions start out [`pressed`](ActionState::pressed) but not `just_pressed`,
+    /// so that keys held while the app is launching don't trigger one-off actions.
+    #[must_use]
+    pub fn treat_startup_held_as_pressed(mut self, enabled: bool) -> Self {
+        self.treat_startup_held_as_pressed = enabled;
+        self
+    }
+
+    /// Configures whether a separate copy of each [`ActionState`] is kept for the [`FixedMain`](bevy::app::FixedMain) schedule.
+    ///
+    /// This is `true` by default: the [`ActionState`] is updated once before the fixed main loop runs,
+    /// and ticked after each fixed step, independently of the [`Main`] schedule.
+    /// As a result, systems in both [`Update`] and [`FixedUpdate`] observe each press as
+    /// [`just_pressed`](ActionState::just_pressed) exactly once,
+    /// no matter how many fixed steps run during a frame.
+    ///
+    /// Disable this if your app never reads actions in [`FixedUpdate`], to skip the extra bookkeeping.
+    /// Fixed-step systems will then observe the [`Main`] state as is,
+    /// so a press may be reported as `just_pressed` during several fixed steps of the same frame, or none at all.
+    #[must_use]
+    pub fn track_fixed_update(mut self, enabled: bool) -> Self {
+        self.track_fixed_update = enabled;
+        self
+    }
+
+    /// Delays the inputs applied to each [`ActionState`] by the given number of frames.
+    ///
+    /// This is `0` by default, applying inputs as soon as they are received.
+    /// Adding artificial latency is useful to test how rollback netcode copes with lag.
+    ///
+    /// The delay can be changed at runtime via the [`InputDelay`] resource,
+    /// though lowering it may skip short presses that were buffered.
+    #[must_use]
+    pub fn input_delay_frames(mut self, frames: u32) -> Self {
+        self.input_delay_frames = frames;
+        self
+    }
+
+    /// Treats analog gamepad axis values whose magnitude is below `noise_floor` as zero,
+    /// filtering out stick drift before any per-action processing.
+    ///
+    /// This is `0.0` by default, leaving values untouched.
+    /// Single axes are compared by their absolute value, and sticks by their magnitude;
+    /// other inputs, such as mouse motion and touch positions, are left as is.
+    /// The floor is stored in the [`CentralInputStore`],
+    /// so it is shared by every [`InputManagerPlugin`]: if several of them configure it, the largest one is used.
+    /// It can be changed at runtime via [`CentralInputStore::set_noise_floor`].
+    ///
+    /// # Requirements
+    ///
+    /// - `noise_floor` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[must_use]
+    pub fn global_noise_floor(mut self, noise_floor: f32) -> Self {
+        assert!(noise_floor >= 0.0);
+        self.global_noise_floor = noise_floor;
+        self
+    }
+
+    /// Configures whether the values of axislike actions are clamped as the final step of their processing.
+    ///
+    /// This is `false` by default.
+    /// When enabled, the values of [`Axislike`] actions are clamped to `[-1.0, 1.0]`,
+    /// and those of [`DualAxislike`] actions to a magnitude of `1.0`,
+    /// as if a [`CircleBounds::new(1.0)`](crate::input_processing::CircleBounds::new)
+    /// was appended to the processors of every action.
+    /// This keeps values in range after processors that scale them up, such as sensitivity.
+    ///
+    /// The clamping can be toggled at runtime via the [`ClampAxisOutput`] resource.
+    #[must_use]
+    pub fn clamp_axis_output(mut self, enabled: bool) -> Self {
+        self.clamp_axis_output = enabled;
+        self
+    }
+
+    /// Configures whether the pressed inputs that aren't bound to any action are reported,
+    /// to help track down missing bindings.
+    ///
+    /// This is `false` by default.
+    /// When enabled, the inputs are collected each frame into the [`UnmappedInputs`] resource.
+    #[must_use]
+    pub fn report_unmapped_inputs(mut self, enabled: bool) -> Self {
+        self.report_unmapped_inputs = enabled;
+        self
+    }
+
+    /// Only ticks and updates each [`ActionState`] while the given `condition` is met,
+    /// such as `in_state` for a particular game state.
+    ///
+    /// While the `condition` is false, inputs are ignored and each [`ActionState`] holds steady:
+    /// [`just_pressed`](ActionState::just_pressed) and [`just_released`](ActionState::just_released)
+    /// are not cleared, and action durations are not advanced.
+    /// Actions can still be set manually on the [`ActionState`].
+    ///
+    /// Calling this several times adds conditions, all of which must be met.
+    #[must_use]
+    pub fn run_if<M>(
+        mut self,
+        condition: impl Condition<M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        self.run_conditions.push(Box::new(move |app: &mut App| {
+            app.init_resource::<PausedTime<A>>().add_systems(
+                PreUpdate,
+                crate::systems::skip_paused_time::<A>
+                    .run_if(not(condition.clone()))
+                    .before(InputManagerSystem::Tick),
+            );
+            app.configure_sets(
+                PreUpdate,
+                InputManagerRunConditionSystem::<A>::new().run_if(condition.clone()),
+            )
+            .configure_sets(
+                RunFixedMainLoop,
+                InputManagerRunConditionSystem::<A>::new().run_if(condition.clone()),
+            )
+            .configure_sets(
+                FixedPostUpdate,
+                InputManagerRunConditionSystem::<A>::new().run_if(condition.clone()),
+            );
+        }));
+        self
+    }
+}
+
+/// A [`PluginGroup`] adding an [`InputManagerPlugin`] for each of several action types at once.
+///
+/// Each call to [`with`](InputManagerPlugins::with) adds the default [`InputManagerPlugin`] for an action type,
+/// while [`with_plugin`](InputManagerPlugins::with_plugin) accepts a configured one.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+/// enum Movement {
+///     Jump,
+/// }
+///
+/// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+/// enum UiAction {
+///     Confirm,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(
+///     InputManagerPlugins::new()
+///         .with::<Movement>()
+///         .with_plugin(InputManagerPlugin::<UiAction>::default().track_fixed_update(false)),
+/// );
+/// ```
+#[derive(Default)]
+#[must_use]
+pub struct InputManagerPlugins {
+    plugins: Vec<Box<dyn FnOnce(PluginGroupBuilder) -> PluginGroupBuilder>>,
+}
+
+impl InputManagerPlugins {
+    /// Creates an empty group, to which action types can be added with [`InputManagerPlugins::with`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the default [`InputManagerPlugin`] for the action type `A`.
+    pub fn with<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration>(self) -> Self {
+        self.with_plugin(InputManagerPlugin::<A>::default())
+    }
+
+    /// Adds the given, possibly customized, [`InputManagerPlugin`].
+    ///
+    /// If a plugin was already added for the same action type, it is replaced.
+    pub fn with_plugin<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration>(
+        mut self,
+        plugin: InputManagerPlugin<A>,
+    ) -> Self {
+        self.plugins.push(Box::new(|group| group.add(plugin)));
+        self
+    }
+}
+
+impl PluginGroup for InputManagerPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        self.plugins
+            .into_iter()
+            .fold(PluginGroupBuilder::start::<Self>(), |group, add_plugin| {
+                add_plugin(group)
+            })
+    }
 }
 
 /// Which machine is this plugin running on?
@@ -95,19 +342,35 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
     fn build(&self, app: &mut App) {
         use crate::systems::*;
 
+        if let Some(input_map) = &self.global_input_map {
+            app.insert_resource(input_map.clone())
+                .init_resource::<ActionState<A>>();
+        }
+
         match self.machine {
             Machine::Client => {
                 if !app.is_plugin_added::<CentralInputStorePlugin>() {
                     app.add_plugins(CentralInputStorePlugin);
                 }
 
+                if self.global_noise_floor > 0.0 {
+                    let mut input_store = app.world_mut().resource_mut::<CentralInputStore>();
+                    let noise_floor = input_store.noise_floor().max(self.global_noise_floor);
+                    input_store.set_noise_floor(noise_floor);
+                }
+
+                app.insert_resource(InputDelay::<A>::new(self.input_delay_frames))
+                    .insert_resource(ClampAxisOutput::<A>::new(self.clamp_axis_output));
+
+                #[cfg(feature = "debug_log")]
+                app.init_resource::<crate::input_debug_log::InputDebugLog<A>>();
+
                 // Main schedule
                 app.add_systems(
                     PreUpdate,
-                    (
-                        tick_action_state::<A>.in_set(TickActionStateSystem::<A>::new()),
-                        clear_central_input_store,
-                    )
+                    tick_action_state::<A>
+                        .in_set(TickActionStateSystem::<A>::new())
+                        .in_set(InputManagerRunConditionSystem::<A>::new())
                         .in_set(InputManagerSystem::Tick)
                         .before(InputManagerSystem::Update),
                 )
@@ -115,9 +378,30 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
 
                 app.add_systems(
                     PreUpdate,
-                    update_action_state::<A>.in_set(InputManagerSystem::Update),
+                    update_action_state::<A>
+                        .in_set(InputManagerSystem::Update)
+                        .in_set(InputManagerRunConditionSystem::<A>::new()),
                 );
 
+                if self.report_unmapped_inputs {
+                    app.init_resource::<UnmappedInputs<A>>().add_systems(
+                        PreUpdate,
+                        report_unmapped_inputs::<A>
+                            .in_set(InputManagerSystem::Update)
+                            .in_set(InputManagerRunConditionSystem::<A>::new()),
+                    );
+                }
+
+                if !self.treat_startup_held_as_pressed {
+                    app.add_systems(
+                        PreUpdate,
+                        ignore_held_at_startup::<A>
+                            .run_if(run_once)
+                            .after(InputManagerSystem::Update)
+                            .before(InputManagerSystem::ManualControl),
+                    );
+                }
+
                 app.configure_sets(
                     PreUpdate,
                     InputManagerSystem::ManualControl.after(InputManagerSystem::Update),
@@ -167,40 +451,59 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
                 app.configure_sets(PreUpdate, InputManagerSystem::Update.before(PickSet::Focus));
 
                 // FixedMain schedule
-                app.add_systems(
-                    RunFixedMainLoop,
-                    (
-                        swap_to_fixed_update::<A>,
-                        // we want to update the ActionState only once, even if the FixedMain schedule runs multiple times
-                        update_action_state::<A>,
-                    )
-                        .chain()
-                        .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
-                );
-
-                app.add_systems(FixedPostUpdate, release_on_input_map_removed::<A>);
-                app.add_systems(
-                    FixedPostUpdate,
-                    tick_action_state::<A>
-                        .in_set(TickActionStateSystem::<A>::new())
-                        .in_set(InputManagerSystem::Tick)
-                        .before(InputManagerSystem::Update),
-                );
-                app.add_systems(
-                    RunFixedMainLoop,
-                    swap_to_update::<A>.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
-                );
+                if self.track_fixed_update {
+                    app.add_systems(
+                        RunFixedMainLoop,
+                        (
+                            swap_to_fixed_update::<A>,
+                            // we want to update the ActionState only once, even if the FixedMain schedule runs multiple times
+                            update_action_state::<A>
+                                .in_set(InputManagerRunConditionSystem::<A>::new()),
+                        )
+                            .chain()
+                            .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+                    );
+
+                    if !self.treat_startup_held_as_pressed {
+                        app.add_systems(
+                            RunFixedMainLoop,
+                            ignore_held_at_startup::<A>
+                                .run_if(run_once)
+                                .after(update_action_state::<A>)
+                                .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+                        );
+                    }
+
+                    app.add_systems(FixedPostUpdate, release_on_input_map_removed::<A>);
+                    app.add_systems(
+                        FixedPostUpdate,
+                        tick_action_state::<A>
+                            .in_set(TickActionStateSystem::<A>::new())
+                            .in_set(InputManagerRunConditionSystem::<A>::new())
+                            .in_set(InputManagerSystem::Tick)
+                            .before(InputManagerSystem::Update),
+                    );
+                    app.add_systems(
+                        RunFixedMainLoop,
+                        swap_to_update::<A>.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                    );
+                }
             }
             Machine::Server => {
                 app.add_systems(
                     PreUpdate,
                     tick_action_state::<A>
                         .in_set(TickActionStateSystem::<A>::new())
+                        .in_set(InputManagerRunConditionSystem::<A>::new())
                         .in_set(InputManagerSystem::Tick),
                 );
             }
         };
 
+        for configure_run_condition in &self.run_conditions {
+            configure_run_condition(app);
+        }
+
         #[cfg(feature = "mouse")]
         app.register_buttonlike_input::<MouseButton>()
             .register_buttonlike_input::<MouseMoveDirection>()
@@ -213,18 +516,27 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
 
         #[cfg(feature = "keyboard")]
         app.register_buttonlike_input::<KeyCode>()
-            .register_buttonlike_input::<ModifierKey>();
+            .register_buttonlike_input::<ModifierKey>()
+            .register_buttonlike_input::<AnyKeyboardKey>();
 
         #[cfg(feature = "gamepad")]
         app.register_buttonlike_input::<GamepadControlDirection>()
             .register_axislike_input::<GamepadControlAxis>()
             .register_dual_axislike_input::<GamepadStick>()
-            .register_buttonlike_input::<GamepadButton>();
+            .register_buttonlike_input::<GamepadButton>()
+            .register_buttonlike_input::<AnyGamepadButton>();
+
+        #[cfg(feature = "touch")]
+        app.register_buttonlike_input::<ScreenTouch>();
+
+        #[cfg(feature = "window")]
+        app.register_buttonlike_input::<AppEvent>();
 
         // Virtual Axes
         app.register_axislike_input::<VirtualAxis>()
             .register_dual_axislike_input::<VirtualDPad>()
             .register_triple_axislike_input::<VirtualDPad3D>();
+        app.register_type::<SocdMode>();
 
         // Chords
         app.register_buttonlike_input::<ButtonlikeChord>()
@@ -244,18 +556,30 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
             .register_type::<AxisBounds>()
             .register_type::<AxisExclusion>()
             .register_type::<AxisDeadZone>()
+            .register_type::<AxisRescale>()
+            .register_type::<AxisRemap>()
+            .register_type::<AxisCalibration>()
             .register_type::<DualAxisProcessor>()
             .register_type::<DualAxisInverted>()
             .register_type::<DualAxisSensitivity>()
+            .register_type::<DualAxisBandedSensitivity>()
+            .register_type::<DualAxisRotation>()
+            .register_type::<DualAxisQuantization>()
+            .register_type::<DualAxisBlend>()
             .register_type::<DualAxisBounds>()
             .register_type::<DualAxisExclusion>()
+            .register_type::<RectExclusion>()
             .register_type::<DualAxisDeadZone>()
+            .register_type::<DirectionalDeadZone>()
             .register_type::<CircleBounds>()
             .register_type::<CircleExclusion>()
-            .register_type::<CircleDeadZone>();
+            .register_type::<CircleDeadZone>()
+            .register_type::<CircleAntiDeadZone>()
+            .register_type::<CircleDeadZoneThenBounds>();
 
         // Resources
-        app.init_resource::<ClashStrategy>();
+        app.init_resource::<ClashStrategy>()
+            .register_type::<ClashStrategy>();
 
         #[cfg(feature = "timing")]
         app.register_type::<Timing>();
@@ -264,7 +588,15 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
 
 /// [`SystemSet`]s for the [`crate::systems`] used by this crate
 ///
-/// `Reset` must occur before `Update`
+/// Each frame, these sets run in the [`PreUpdate`] schedule:
+/// [`Tick`](InputManagerSystem::Tick) first, then [`Update`](InputManagerSystem::Update)
+/// once the inputs have been gathered, and finally [`ManualControl`](InputManagerSystem::ManualControl).
+///
+/// Order your own systems relative to these sets to run them deterministically around input updates:
+/// systems ordered `.after(InputManagerSystem::Update)` observe the [`ActionState`] of the current frame,
+/// while systems that set actions manually should run in [`ManualControl`](InputManagerSystem::ManualControl)
+/// so that their changes are not overridden by the inputs.
+/// Systems in [`Update`] always observe the current frame.
 #[derive(SystemSet, Clone, Hash, Debug, PartialEq, Eq)]
 pub enum InputManagerSystem {
     /// Advances action timers.
@@ -308,6 +640,126 @@ impl<A: Actionlike> Default for TickActionStateSystem<A> {
     }
 }
 
+/// [`SystemSet`] for the systems that tick and update [`ActionState<A>`],
+/// which only run while the conditions passed to [`InputManagerPlugin::run_if`] are met.
+///
+/// The systems that swap between the [`Main`](bevy::app::Main) and [`FixedMain`](bevy::app::FixedMain) states
+/// are not part of this set, so that they always stay paired.
+#[derive(SystemSet, Clone, Hash, Debug, PartialEq, Eq)]
+struct InputManagerRunConditionSystem<A: Actionlike> {
+    phantom_data: PhantomData<A>,
+}
+
+impl<A: Actionlike> InputManagerRunConditionSystem<A> {
+    fn new() -> Self {
+        Self {
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// The number of frames by which inputs are delayed before being applied to each [`ActionState<A>`],
+/// configured via [`InputManagerPlugin::input_delay_frames`].
+///
+/// Inputs received in one frame are applied by [`update_action_state`](crate::systems::update_action_state)
+/// `frames` frames later, simulating input latency.
+/// Actions set directly on the [`ActionState`] are not delayed.
+///
+/// Lowering `frames` applies all the inputs buffered beyond the new delay during the next update, oldest first.
+/// Presses and releases among them then happen within a single update,
+/// so a press that was released during those frames is never observed as pressed.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputDelay<A: Actionlike> {
+    /// The number of frames by which inputs are delayed.
+    pub frames: u32,
+    phantom_data: PhantomData<A>,
+}
+
+impl<A: Actionlike> InputDelay<A> {
+    /// Creates an [`InputDelay`] of the given number of `frames`.
+    pub fn new(frames: u32) -> Self {
+        Self {
+            frames,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> Default for InputDelay<A> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// The total time during which the [run conditions](InputManagerPlugin::run_if)
+/// of the [`InputManagerPlugin<A>`] were not met.
+///
+/// This time is excluded from the durations and cooldowns of each [`ActionState<A>`] by [`ActionState::skip`],
+/// so that they don't jump forward once the conditions are met again.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PausedTime<A: Actionlike> {
+    total: Duration,
+    last_paused_frame: Option<Instant>,
+    phantom_data: PhantomData<A>,
+}
+
+impl<A: Actionlike> PausedTime<A> {
+    /// The total time during which the run conditions were not met.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Records the frame that started at `frame_instant` and lasted for `delta` as paused,
+    /// returning `false` if it was already recorded.
+    pub(crate) fn record(&mut self, frame_instant: Option<Instant>, delta: Duration) -> bool {
+        if frame_instant.is_some() && self.last_paused_frame == frame_instant {
+            return false;
+        }
+
+        self.last_paused_frame = frame_instant;
+        self.total += delta;
+        true
+    }
+}
+
+impl<A: Actionlike> Default for PausedTime<A> {
+    fn default() -> Self {
+        Self {
+            total: Duration::ZERO,
+            last_paused_frame: None,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Whether the values of axislike actions in each [`ActionState<A>`] are clamped to a magnitude of `1.0`
+/// after processing, configured via [`InputManagerPlugin::clamp_axis_output`].
+///
+/// Actions set directly on the [`ActionState`] are not clamped.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClampAxisOutput<A: Actionlike> {
+    /// Whether the values are clamped.
+    pub enabled: bool,
+    phantom_data: PhantomData<A>,
+}
+
+impl<A: Actionlike> ClampAxisOutput<A> {
+    /// Creates a [`ClampAxisOutput`] that clamps values if `enabled`.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> Default for ClampAxisOutput<A> {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
 /// A plugin that keeps track of all inputs in a central store.
 ///
 /// This plugin is added by default by [`InputManagerPlugin`],
@@ -322,6 +774,18 @@ impl Plugin for CentralInputStorePlugin {
 
         register_standard_input_kinds(app);
 
+        // Shared by every `InputManagerPlugin`, so the store is cleared exactly once per frame,
+        // before any of the inputs are gathered
+        app.add_systems(
+            PreUpdate,
+            crate::systems::clear_central_input_store
+                .in_set(InputManagerSystem::Tick)
+                .before(InputManagerSystem::Unify),
+        );
+        app.add_systems(
+            PreUpdate,
+            crate::systems::update_central_input_store_delta.in_set(InputManagerSystem::Unify),
+        );
         app.configure_sets(PreUpdate, InputManagerSystem::Unify.after(InputSystem));
     }
 }
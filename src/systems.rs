@@ -5,14 +5,25 @@ use bevy::ecs::query::QueryFilter;
 use bevy::log::debug;
 
 use crate::{
-    action_state::ActionState, clashing_inputs::ClashStrategy, input_map::InputMap, Actionlike,
+    action_state::ActionState,
+    buttonlike::EdgeMode,
+    clashing_inputs::ClashStrategy,
+    input_context::InputContextStack,
+    input_map::{InputMap, LastActiveBindings, TriggeringBindings, UpdatedActions},
+    plugin::{ClampAxisOutput, InputDelay, PausedTime},
+    unmapped_inputs::UnmappedInputs,
+    user_input::Buttonlike,
+    Actionlike,
 };
+use std::collections::VecDeque;
 
+use bevy::ecs::event::EventCursor;
 use bevy::ecs::prelude::*;
+use bevy::input::gamepad::GamepadConnectionEvent;
 use bevy::prelude::Gamepad;
 use bevy::{
     time::{Real, Time},
-    utils::Instant,
+    utils::{Duration, HashMap, HashSet, Instant},
 };
 
 use crate::action_diff::{ActionDiffEvent, SummarizedActionState};
@@ -57,11 +68,18 @@ pub fn tick_action_state<A: Actionlike>(
     mut query: Query<&mut ActionState<A>>,
     action_state: Option<ResMut<ActionState<A>>>,
     time: Res<Time<Real>>,
+    paused_time: Option<Res<PausedTime<A>>>,
     mut stored_previous_instant: Local<Option<Instant>>,
+    mut stored_paused_time: Local<Duration>,
 ) {
     // If this is the very first tick, measure from the start of the app
     let current_instant = time.last_update().unwrap_or_else(|| time.startup());
-    let previous_instant = stored_previous_instant.unwrap_or_else(|| time.startup());
+    let mut previous_instant = stored_previous_instant.unwrap_or_else(|| time.startup());
+
+    // Frames during which the run conditions were not met don't count
+    let paused_time = paused_time.map_or(Duration::ZERO, |paused_time| paused_time.total());
+    previous_instant += paused_time.saturating_sub(*stored_paused_time);
+    *stored_paused_time = paused_time;
 
     // Only tick the ActionState resource if it exists
     if let Some(mut action_state) = action_state {
@@ -79,31 +97,368 @@ pub fn tick_action_state<A: Actionlike>(
     *stored_previous_instant = time.last_update();
 }
 
+/// Excludes the current frame from the timers of all [`ActionState`]s,
+/// recording it in the [`PausedTime`] resource.
+///
+/// This is run instead of [`tick_action_state`] while the [run conditions](crate::plugin::InputManagerPlugin::run_if)
+/// of the plugin are not met, so that action durations and cooldowns are frozen.
+pub fn skip_paused_time<A: Actionlike>(
+    mut query: Query<&mut ActionState<A>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    time: Res<Time<Real>>,
+    mut paused_time: ResMut<PausedTime<A>>,
+) {
+    // Each unmet run condition adds this system, but each frame should only be skipped once
+    if !paused_time.record(time.last_update(), time.delta()) {
+        return;
+    }
+
+    let resources = action_state.map(Mut::from);
+    for mut action_state in query.iter_mut().chain(resources) {
+        action_state.skip(time.delta());
+    }
+}
+
 /// Fetches the [`CentralInputStore`]
 /// to update [`ActionState`] according to the [`InputMap`].
 ///
 /// Clashes will be resolved according to the [`ClashStrategy`] resource.
+///
+/// If an [`InputContextStack`] resource is present, its active [`InputMap`]
+/// is used instead of the [`InputMap`] resource to update the [`ActionState`] resource.
+/// [`InputMap`] components of entities are not affected.
+///
+/// If a [`ClampAxisOutput`] resource is present and enabled, the values of axislike actions are clamped
+/// to a magnitude of `1.0` after processing.
+///
+/// If an [`InputDelay`] resource is present, the processed inputs are buffered
+/// and only applied once they are old enough.
+///
+/// The cooldowns, edge modes and any-gamepad actions of each [`InputMap`] are copied to its [`ActionState`].
+/// Once they are removed from the map, or another map becomes active, they are reset to their defaults.
+///
+/// When a gamepad read by an [`InputMap`] is disconnected, whether it is the associated gamepad,
+/// the first connected one used in its absence, or one pressing an any-gamepad action,
+/// all of its buffered inputs are discarded and its buttonlike actions are released,
+/// so that they don't stay stuck pressed.
+#[allow(clippy::too_many_arguments)]
 pub fn update_action_state<A: Actionlike>(
     input_store: Res<CentralInputStore>,
     clash_strategy: Res<ClashStrategy>,
+    input_delay: Option<Res<InputDelay<A>>>,
+    clamp_axis_output: Option<Res<ClampAxisOutput<A>>>,
+    mut delayed_actions: Local<
+        HashMap<Option<Entity>, VecDeque<(UpdatedActions<A>, TriggeringBindings<A>)>>,
+    >,
+    mut last_active_bindings: Local<HashMap<Option<Entity>, LastActiveBindings<A>>>,
+    mut applied_settings: Local<HashMap<Option<Entity>, AppliedSettings<A>>>,
+    mut resolved_gamepads: Local<HashMap<Option<Entity>, Entity>>,
     mut gamepads: Query<Entity, With<Gamepad>>,
+    gamepad_connections: Option<Res<Events<GamepadConnectionEvent>>>,
+    mut gamepad_connection_cursor: Local<EventCursor<GamepadConnectionEvent>>,
     action_state: Option<ResMut<ActionState<A>>>,
     input_map: Option<Res<InputMap<A>>>,
-    mut query: Query<(&mut ActionState<A>, &InputMap<A>)>,
+    input_contexts: Option<Res<InputContextStack<A>>>,
+    mut query: Query<(Entity, &mut ActionState<A>, &InputMap<A>)>,
+    #[cfg(feature = "debug_log")] mut debug_log: Option<
+        ResMut<crate::input_debug_log::InputDebugLog<A>>,
+    >,
 ) {
+    let delay_frames = input_delay.map_or(0, |input_delay| input_delay.frames as usize);
+    let clamp_axis_output = clamp_axis_output.is_some_and(|clamp| clamp.enabled);
+
+    let disconnected_gamepads: Vec<Entity> = gamepad_connections
+        .map(|events| {
+            gamepad_connection_cursor
+                .read(&events)
+                .filter(|event| event.disconnected())
+                .map(|event| event.gamepad)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // The active input context takes precedence over the `InputMap` resource
+    let input_map = input_contexts
+        .map(Res::into_inner)
+        .and_then(InputContextStack::active)
+        .or(input_map.map(Res::into_inner));
+
     let resources = input_map
         .zip(action_state)
-        .map(|(input_map, action_state)| (Mut::from(action_state), input_map.into_inner()));
+        .map(|(input_map, action_state)| (None, Mut::from(action_state), input_map));
+
+    let components = query
+        .iter_mut()
+        .map(|(entity, action_state, input_map)| (Some(entity), action_state, input_map));
 
-    for (mut action_state, input_map) in query.iter_mut().chain(resources) {
-        action_state.update(input_map.process_actions(
+    let mut seen_entities = HashSet::new();
+    for (entity, mut action_state, input_map) in components.chain(resources) {
+        seen_entities.insert(entity);
+
+        // Disconnected gamepads have already lost their `Gamepad` component,
+        // so compare with the gamepads read from during the previous update
+        let uses_gamepad = |gamepad: Entity| {
+            input_map.gamepad() == Some(gamepad)
+                || resolved_gamepads.get(&entity) == Some(&gamepad)
+                || input_map
+                    .iter_any_gamepad_actions()
+                    .any(|action| action_state.triggering_gamepad(action) == Some(gamepad))
+        };
+        let gamepad_disconnected = disconnected_gamepads
+            .iter()
+            .any(|gamepad| uses_gamepad(*gamepad));
+        resolved_gamepads.insert(entity, input_map.resolve_gamepad(Some(gamepads.reborrow())));
+
+        applied_settings
+            .entry(entity)
+            .or_default()
+            .reset_removed(input_map, &mut action_state);
+
+        for (action, cooldown) in input_map.iter_cooldowns() {
+            action_state.set_cooldown(action, *cooldown);
+        }
+
+        for (action, edge_mode) in input_map.iter_edge_modes() {
+            action_state.set_edge_mode(action, *edge_mode);
+        }
+
+        for action in input_map.iter_any_gamepad_actions() {
+            let gamepad = input_map.triggering_gamepad(action, gamepads.iter(), &input_store);
+            action_state.set_triggering_gamepad(action, gamepad);
+        }
+
+        applied_settings.insert(entity, AppliedSettings::new(input_map));
+
+        let (mut updated_actions, triggering_bindings) = input_map.process_actions_with_history(
             Some(gamepads.reborrow()),
             &input_store,
             *clash_strategy,
-        ));
+            last_active_bindings.entry(entity).or_default(),
+            |gate| action_state.pressed(gate),
+        );
+        if clamp_axis_output {
+            updated_actions.clamp_axis_values();
+        }
+
+        if gamepad_disconnected {
+            // Inputs buffered before the disconnection are stale
+            delayed_actions.remove(&entity);
+        }
+
+        // Decisions are logged as they are applied, once any input delay has passed
+        let mut apply = |updated_actions: UpdatedActions<A>,
+                         triggering_bindings: TriggeringBindings<A>| {
+            #[cfg(feature = "debug_log")]
+            if let Some(debug_log) = debug_log.as_mut() {
+                record_decisions(
+                    debug_log,
+                    entity,
+                    input_map,
+                    &updated_actions,
+                    &triggering_bindings,
+                );
+            }
+            action_state.update_with_bindings(updated_actions, &triggering_bindings.pressed);
+        };
+
+        if delay_frames == 0 && delayed_actions.is_empty() {
+            apply(updated_actions, triggering_bindings);
+        } else {
+            // Apply the inputs that have been buffered for long enough, oldest first
+            let buffer = delayed_actions.entry(entity).or_default();
+            buffer.push_back((updated_actions, triggering_bindings));
+            while buffer.len() > delay_frames {
+                if let Some((updated_actions, triggering_bindings)) = buffer.pop_front() {
+                    apply(updated_actions, triggering_bindings);
+                }
+            }
+        }
+
+        if gamepad_disconnected {
+            debug!("Releasing all actions of an `InputMap` whose gamepad was disconnected");
+            action_state.release_all();
+
+            #[cfg(feature = "debug_log")]
+            if let Some(debug_log) = debug_log.as_mut() {
+                for (action, _) in input_map.iter_buttonlike() {
+                    debug_log.record(entity, action, None, false, false);
+                }
+            }
+        }
+    }
+
+    // Forget about buffers that have been fully applied, such as when the delay is removed,
+    // and about entities that have been despawned or lost their `InputMap`
+    delayed_actions.retain(|entity, buffer| !buffer.is_empty() && seen_entities.contains(entity));
+    last_active_bindings.retain(|entity, _| seen_entities.contains(entity));
+    applied_settings.retain(|entity, _| seen_entities.contains(entity));
+    resolved_gamepads.retain(|entity, _| seen_entities.contains(entity));
+    #[cfg(feature = "debug_log")]
+    if let Some(debug_log) = debug_log.as_mut() {
+        debug_log.retain_entities(|entity| seen_entities.contains(&entity));
+    }
+}
+
+/// Records the decision made for each [`Buttonlike`](crate::user_input::Buttonlike) action of the `input_map`
+/// once its `updated_actions` are applied to the [`ActionState`] of the given `entity`.
+#[cfg(feature = "debug_log")]
+fn record_decisions<A: Actionlike>(
+    debug_log: &mut crate::input_debug_log::InputDebugLog<A>,
+    entity: Option<Entity>,
+    input_map: &InputMap<A>,
+    updated_actions: &UpdatedActions<A>,
+    triggering_bindings: &TriggeringBindings<A>,
+) {
+    for (action, bindings) in input_map.iter_buttonlike() {
+        let suppressed_by_clash = triggering_bindings.suppressed_by_clash.get(action);
+        let input = triggering_bindings
+            .pressed
+            .get(action)
+            .or(suppressed_by_clash)
+            .and_then(|index| bindings.get(*index))
+            .map(AsRef::as_ref);
+        debug_log.record(
+            entity,
+            action,
+            input,
+            updated_actions.pressed(action),
+            suppressed_by_clash.is_some(),
+        );
+    }
+}
+
+/// The per-action settings that [`update_action_state`] copied from an [`InputMap`] into its [`ActionState`],
+/// remembered so that they can be reset once they are removed from the map.
+///
+/// Settings applied directly to the [`ActionState`] are left alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedSettings<A: Actionlike> {
+    cooldowns: HashSet<A>,
+    edge_modes: HashSet<A>,
+    any_gamepad_actions: HashSet<A>,
+}
+
+impl<A: Actionlike> AppliedSettings<A> {
+    /// Records the settings of the `input_map`.
+    fn new(input_map: &InputMap<A>) -> Self {
+        Self {
+            cooldowns: input_map
+                .iter_cooldowns()
+                .map(|(action, _)| action.clone())
+                .collect(),
+            edge_modes: input_map
+                .iter_edge_modes()
+                .map(|(action, _)| action.clone())
+                .collect(),
+            any_gamepad_actions: input_map.iter_any_gamepad_actions().cloned().collect(),
+        }
+    }
+
+    /// Resets the settings that are no longer part of the `input_map` to their defaults in the `action_state`.
+    fn reset_removed(&self, input_map: &InputMap<A>, action_state: &mut ActionState<A>) {
+        for action in self.cooldowns.iter() {
+            if input_map.cooldown(action).is_none() {
+                action_state.set_cooldown(action, Duration::ZERO);
+            }
+        }
+
+        for action in self.edge_modes.iter() {
+            if !input_map
+                .iter_edge_modes()
+                .any(|(other, _)| other == action)
+            {
+                action_state.set_edge_mode(action, EdgeMode::default());
+            }
+        }
+
+        for action in self.any_gamepad_actions.iter() {
+            if !input_map.accepts_any_gamepad(action) {
+                action_state.set_triggering_gamepad(action, None);
+            }
+        }
+    }
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Actionlike> Default for AppliedSettings<A> {
+    fn default() -> Self {
+        Self {
+            cooldowns: HashSet::default(),
+            edge_modes: HashSet::default(),
+            any_gamepad_actions: HashSet::default(),
+        }
+    }
+}
+
+/// Downgrades actions that are already held during the first update
+/// from [`just_pressed`](ActionState::just_pressed) to merely [`pressed`](ActionState::pressed).
+///
+/// This is only added when [`InputManagerPlugin::treat_startup_held_as_pressed`](crate::plugin::InputManagerPlugin::treat_startup_held_as_pressed)
+/// is disabled, and should only be run once.
+pub fn ignore_held_at_startup<A: Actionlike>(
+    mut query: Query<&mut ActionState<A>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+) {
+    let resources = action_state.map(Mut::from);
+
+    for mut action_state in query.iter_mut().chain(resources) {
+        for action in action_state.get_just_pressed() {
+            action_state
+                .button_data_mut_or_default(&action)
+                .state
+                .tick();
+        }
     }
 }
 
+/// Collects the pressed [`Buttonlike`] inputs that aren't bound to any action of type `A`
+/// into the [`UnmappedInputs`] resource.
+///
+/// This is only added when [`InputManagerPlugin::report_unmapped_inputs`](crate::plugin::InputManagerPlugin::report_unmapped_inputs)
+/// is enabled.
+pub fn report_unmapped_inputs<A: Actionlike>(
+    input_store: Res<CentralInputStore>,
+    input_map: Option<Res<InputMap<A>>>,
+    input_contexts: Option<Res<InputContextStack<A>>>,
+    query: Query<&InputMap<A>>,
+    mut unmapped_inputs: ResMut<UnmappedInputs<A>>,
+) {
+    // The active input context takes precedence over the `InputMap` resource
+    let input_map = input_contexts
+        .map(Res::into_inner)
+        .and_then(InputContextStack::active)
+        .or(input_map.map(Res::into_inner));
+
+    let input_maps: Vec<&InputMap<A>> = query.iter().chain(input_map).collect();
+    let is_mapped = |input: &dyn Buttonlike| {
+        input_maps
+            .iter()
+            .any(|input_map| !input_map.conflicts(input).is_empty())
+    };
+
+    let inputs = input_store
+        .all_pressed_buttonlikes()
+        .filter(|input| {
+            // Buttons are stored per gamepad, but are usually bound for any gamepad
+            #[cfg(feature = "gamepad")]
+            {
+                use crate::user_input::gamepad::SpecificGamepadButton;
+
+                let specific =
+                    bevy::reflect::Reflect::as_any(*input).downcast_ref::<SpecificGamepadButton>();
+                if specific.is_some_and(|specific| is_mapped(&specific.button)) {
+                    return false;
+                }
+            }
+
+            !is_mapped(*input)
+        })
+        .map(dyn_clone::clone_box)
+        .collect();
+
+    unmapped_inputs.set(inputs);
+}
+
 #[cfg(any(feature = "egui", feature = "ui"))]
 /// Filters out all inputs that are captured by the UI.
 pub fn filter_captured_input(
@@ -216,3 +571,13 @@ pub fn release_on_input_map_removed<A: Actionlike>(
 pub fn clear_central_input_store(mut input_store: ResMut<CentralInputStore>) {
     input_store.clear();
 }
+
+/// Records the duration of the frame in the [`CentralInputStore`],
+/// so that processors such as [`AxisProcessor::PerSecond`](crate::input_processing::AxisProcessor::PerSecond) can use it.
+pub fn update_central_input_store_delta(
+    mut input_store: ResMut<CentralInputStore>,
+    time: Option<Res<Time<Real>>>,
+) {
+    let delta_secs = time.map_or(0.0, |time| time.delta_secs());
+    input_store.set_delta_secs(delta_secs);
+}
@@ -23,6 +23,18 @@ pub struct Timing {
     pub current_duration: Duration,
     /// The [`Duration`] for which the button was pressed or released before the state last changed.
     pub previous_duration: Duration,
+    /// How far into its frame the current press happened,
+    /// as recorded by [`ActionState::set_press_timestamp`](crate::action_state::ActionState::set_press_timestamp).
+    #[serde(default)]
+    pub press_offset: Option<Duration>,
+    /// The number of fixed steps for which the button has been pressed or released.
+    ///
+    /// Unlike `current_duration`, this is only advanced by [`Timing::tick_fixed`].
+    #[serde(default)]
+    pub current_ticks: u32,
+    /// The number of fixed steps for which the button was pressed or released before the state last changed.
+    #[serde(default)]
+    pub previous_ticks: u32,
 }
 
 impl Timing {
@@ -31,6 +43,9 @@ impl Timing {
         instant_started: None,
         current_duration: Duration::ZERO,
         previous_duration: Duration::ZERO,
+        press_offset: None,
+        current_ticks: 0,
+        previous_ticks: 0,
     };
 }
 
@@ -54,13 +69,34 @@ impl Timing {
         }
     }
 
-    /// Flips the metaphorical hourglass, storing `current_duration` in `previous_duration` and resetting `instant_started`
+    /// Excludes the given `duration` from this timer, as if it never passed.
+    ///
+    /// This has no effect if [`Timing::tick`] has not been called yet.
+    pub fn skip(&mut self, duration: Duration) {
+        if let Some(instant_started) = self.instant_started.as_mut() {
+            *instant_started += duration;
+        }
+    }
+
+    /// Advances the `current_ticks` of this timer by `steps`
+    ///
+    /// As this only counts steps, it is fully deterministic,
+    /// unlike [`Timing::tick`] which depends on wall-clock time.
+    pub fn tick_fixed(&mut self, steps: u32) {
+        self.current_ticks = self.current_ticks.saturating_add(steps);
+    }
+
+    /// Flips the metaphorical hourglass, storing `current_duration` in `previous_duration`
+    /// and `current_ticks` in `previous_ticks`, resetting `instant_started` and discarding the `press_offset`
     ///
     /// This method is called whenever actions are pressed or released
     pub fn flip(&mut self) {
         self.previous_duration = self.current_duration;
         self.current_duration = Duration::ZERO;
+        self.previous_ticks = self.current_ticks;
+        self.current_ticks = 0;
         self.instant_started = None;
+        self.press_offset = None;
     }
 }
 
@@ -157,4 +193,163 @@ mod tests {
         assert_eq!(action_state.current_duration(&Action::Jump), Duration::ZERO);
         assert_eq!(action_state.previous_duration(&Action::Jump), t2 - t0);
     }
+
+    #[test]
+    fn fixed_ticks() {
+        use crate::action_state::ActionState;
+        use bevy::utils::Duration;
+
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(action_state.current_ticks(&Action::Jump), 0);
+
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.current_ticks(&Action::Jump), 0);
+
+        // Ticking advances the state like a regular tick, counting steps instead of time
+        action_state.tick_fixed(1);
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert_eq!(action_state.current_ticks(&Action::Jump), 1);
+
+        action_state.tick_fixed(3);
+        action_state.tick_fixed(1);
+        assert_eq!(action_state.current_ticks(&Action::Jump), 5);
+        assert_eq!(action_state.previous_ticks(&Action::Jump), 0);
+        assert_eq!(action_state.current_duration(&Action::Jump), Duration::ZERO);
+
+        // Releasing swaps the current tick count to the previous one
+        action_state.release(&Action::Jump);
+        assert_eq!(action_state.current_ticks(&Action::Jump), 0);
+        assert_eq!(action_state.previous_ticks(&Action::Jump), 5);
+
+        action_state.tick_fixed(2);
+        assert!(!action_state.just_released(&Action::Jump));
+        assert_eq!(action_state.current_ticks(&Action::Jump), 2);
+        assert_eq!(action_state.previous_ticks(&Action::Jump), 5);
+    }
+
+    #[test]
+    fn just_long_pressed() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let threshold = Duration::from_millis(500);
+        let frame = Duration::from_millis(200);
+        let mut action_state = ActionState::<Action>::default();
+        let mut previous_instant = Instant::now();
+
+        action_state.press(&Action::Jump);
+        assert!(!action_state.just_long_pressed(&Action::Jump, threshold));
+        assert!(action_state.just_long_pressed(&Action::Jump, Duration::ZERO));
+
+        // Held for 200ms, 400ms, 600ms, 800ms and 1000ms: the threshold is only crossed once
+        let mut fired = Vec::new();
+        for _ in 0..5 {
+            let current_instant = previous_instant + frame;
+            action_state.tick(current_instant, previous_instant);
+            previous_instant = current_instant;
+
+            fired.push(action_state.just_long_pressed(&Action::Jump, threshold));
+            assert!(!action_state.just_long_pressed(&Action::Jump, Duration::ZERO));
+        }
+        assert_eq!(fired, [false, false, true, false, false]);
+
+        // Releasing stops the long press from firing
+        action_state.release(&Action::Jump);
+        assert!(!action_state.just_long_pressed(&Action::Jump, threshold));
+        assert!(!action_state.just_long_pressed(&Action::Run, threshold));
+    }
+
+    #[test]
+    fn press_offset() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_millis(16);
+
+        // The frame from t0 to t1 has just been ticked, and the press event happened 4ms into it
+        action_state.tick(t1, t0);
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.press_offset(&Action::Jump), None);
+        action_state.set_press_timestamp(&Action::Jump, t0 + Duration::from_millis(4));
+        assert_eq!(
+            action_state.press_offset(&Action::Jump),
+            Some(Duration::from_millis(4))
+        );
+
+        // The offset is kept while the action is held
+        let t2 = t1 + Duration::from_millis(16);
+        action_state.tick(t2, t1);
+        assert_eq!(
+            action_state.press_offset(&Action::Jump),
+            Some(Duration::from_millis(4))
+        );
+
+        // Releasing discards the offset
+        action_state.release(&Action::Jump);
+        assert_eq!(action_state.press_offset(&Action::Jump), None);
+
+        // Timestamps are ignored for actions that are not pressed
+        action_state.set_press_timestamp(&Action::Jump, t2);
+        assert_eq!(action_state.press_offset(&Action::Jump), None);
+    }
+
+    #[test]
+    fn charge() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let max_duration = Duration::from_secs(2);
+        assert_eq!(action_state.charge(&Action::Jump, max_duration), 0.0);
+
+        action_state.press(&Action::Jump);
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(1);
+        action_state.tick(t1, t0);
+        assert!((action_state.charge(&Action::Jump, max_duration) - 0.5).abs() < f32::EPSILON);
+
+        // Charging is capped once the max duration has been reached
+        let t2 = t1 + Duration::from_secs(5);
+        action_state.tick(t2, t1);
+        assert_eq!(action_state.charge(&Action::Jump, max_duration), 1.0);
+
+        // Releasing discharges the action
+        action_state.release(&Action::Jump);
+        assert_eq!(action_state.charge(&Action::Jump, max_duration), 0.0);
+    }
+
+    #[test]
+    fn tap_vs_hold() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let max_tap_duration = Duration::from_millis(200);
+        let t0 = Instant::now();
+
+        // A brief press is a tap
+        action_state.press(&Action::Jump);
+        let t1 = t0 + Duration::from_millis(100);
+        action_state.tick(t1, t0);
+        assert!(!action_state.was_tap(&Action::Jump, max_tap_duration));
+        action_state.release(&Action::Jump);
+        assert!(action_state.was_tap(&Action::Jump, max_tap_duration));
+
+        // Taps are only reported on the frame of release
+        let t2 = t1 + Duration::from_millis(100);
+        action_state.tick(t2, t1);
+        assert!(!action_state.was_tap(&Action::Jump, max_tap_duration));
+
+        // A long press is a hold
+        action_state.press(&Action::Jump);
+        action_state.tick(t2, t1);
+        let t3 = t2 + Duration::from_secs(1);
+        action_state.tick(t3, t2);
+        action_state.release(&Action::Jump);
+        assert!(action_state.just_released(&Action::Jump));
+        assert!(!action_state.was_tap(&Action::Jump, max_tap_duration));
+    }
 }
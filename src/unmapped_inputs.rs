@@ -0,0 +1,92 @@
+//! A report of pressed inputs that aren't bound to any action, for debugging missing bindings.
+//!
+//! When enabled via [`InputManagerPlugin::report_unmapped_inputs`](crate::plugin::InputManagerPlugin::report_unmapped_inputs),
+//! each frame, every pressed [`Buttonlike`] input is checked against the active [`InputMap`](crate::input_map::InputMap)s
+//! of the action type, and those that aren't part of any binding are collected into an [`UnmappedInputs`] resource.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::Resource;
+
+use crate::{user_input::Buttonlike, Actionlike};
+
+/// The [`Buttonlike`] inputs pressed during the current frame that aren't bound to any action of type `A`.
+///
+/// An input counts as bound if it is part of any binding in the active [`InputMap<A>`](crate::input_map::InputMap) resource
+/// (or the active map of the [`InputContextStack<A>`](crate::input_context::InputContextStack), if any),
+/// or in the [`InputMap<A>`](crate::input_map::InputMap) of any entity.
+/// Inputs that are only part of a chord or a virtual axis are considered bound too.
+///
+/// This resource is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+/// when [`report_unmapped_inputs`](crate::plugin::InputManagerPlugin::report_unmapped_inputs) is enabled,
+/// and is refreshed each frame during [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::unmapped_inputs::UnmappedInputs;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn warn_about_unmapped_inputs(unmapped: Res<UnmappedInputs<Action>>) {
+///     for input in unmapped.iter() {
+///         warn!("{input:?} isn't bound to any action");
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(InputManagerPlugin::<Action>::default().report_unmapped_inputs(true))
+///     .add_systems(Update, warn_about_unmapped_inputs);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct UnmappedInputs<A: Actionlike> {
+    inputs: Vec<Box<dyn Buttonlike>>,
+    _phantom: PhantomData<A>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Actionlike> Default for UnmappedInputs<A> {
+    fn default() -> Self {
+        Self {
+            inputs: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> UnmappedInputs<A> {
+    /// Returns an iterator over the unmapped inputs pressed during the current frame.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Buttonlike> {
+        self.inputs.iter().map(AsRef::as_ref)
+    }
+
+    /// Checks if the given `input` was pressed during the current frame without being bound to any action.
+    #[must_use]
+    pub fn contains(&self, input: &dyn Buttonlike) -> bool {
+        self.inputs
+            .iter()
+            .any(|unmapped| unmapped.as_ref() == input)
+    }
+
+    /// Returns the number of unmapped inputs pressed during the current frame.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Checks if no unmapped inputs were pressed during the current frame.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Replaces the unmapped inputs with those of the current frame.
+    pub(crate) fn set(&mut self, inputs: Vec<Box<dyn Buttonlike>>) {
+        self.inputs = inputs;
+    }
+}
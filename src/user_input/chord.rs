@@ -2,12 +2,13 @@
 
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Entity, Reflect, World};
+use itertools::Itertools;
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
 
 use crate as leafwing_input_manager;
 use crate::clashing_inputs::BasicInputs;
-use crate::user_input::{Buttonlike, TripleAxislike, UserInput};
+use crate::user_input::{Buttonlike, DeviceClass, TripleAxislike, UserInput};
 use crate::InputControlKind;
 
 use super::updating::CentralInputStore;
@@ -17,6 +18,8 @@ use super::{Axislike, DualAxislike};
 /// allowing you to define complex input combinations like hotkeys, shortcuts, and macros.
 ///
 /// A chord is pressed only if all its constituent buttons are pressed simultaneously.
+/// Members from different devices are hard to press at exactly the same time,
+/// so a timing tolerance can be configured with [`ButtonlikeChord::with_tolerance`].
 ///
 /// Adding duplicate buttons within a chord will ignore the extras,
 /// preventing redundant data fetching from multiple instances of the same input.
@@ -47,14 +50,17 @@ use super::{Axislike, DualAxislike};
 /// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
-pub struct ButtonlikeChord(
+pub struct ButtonlikeChord {
     // Note: We can't use a HashSet here because of
     // https://users.rust-lang.org/t/hash-not-implemented-why-cant-it-be-derived/92416/8
     // We can't use a BTreeSet because the underlying types don't impl Ord
     // We don't want to use a PetitSet here because of memory bloat
     // So a vec it is!
-    pub(crate) Vec<Box<dyn Buttonlike>>,
-);
+    pub(crate) inputs: Vec<Box<dyn Buttonlike>>,
+    /// The number of frames for which a released member still counts as pressed.
+    #[serde(default)]
+    pub(crate) tolerance_frames: u32,
+}
 
 impl ButtonlikeChord {
     /// Creates a [`ButtonlikeChord`] from multiple [`Buttonlike`]s, avoiding duplicates.
@@ -78,6 +84,26 @@ impl ButtonlikeChord {
         Self::default().with(modifier).with(input)
     }
 
+    /// Lets members released during the last `frames` frames still count as pressed,
+    /// so that members pressed slightly apart, such as a key and a gamepad button, activate the chord.
+    ///
+    /// This is `0` by default, requiring all members to be held at once.
+    /// At least one member must still be pressed for the chord to be active.
+    ///
+    /// Only members that track their release order via [`Buttonlike::release_order`] benefit from the tolerance.
+    #[inline]
+    pub fn with_tolerance(mut self, frames: u32) -> Self {
+        self.tolerance_frames = frames;
+        self
+    }
+
+    /// Returns the number of frames for which a released member still counts as pressed.
+    #[must_use]
+    #[inline]
+    pub fn tolerance(&self) -> u32 {
+        self.tolerance_frames
+    }
+
     /// Adds the given [`Buttonlike`] into this chord, avoiding duplicates.
     #[inline]
     pub fn with(mut self, input: impl Buttonlike) -> Self {
@@ -98,9 +124,20 @@ impl ButtonlikeChord {
     /// Adds the given boxed dyn [`Buttonlike`] to this chord, avoiding duplicates.
     #[inline]
     fn push_boxed_unique(&mut self, input: Box<dyn Buttonlike>) {
-        if !self.0.contains(&input) {
-            self.0.push(input);
+        if !self.inputs.contains(&input) {
+            self.inputs.push(input);
+        }
+    }
+}
+
+impl FromIterator<Box<dyn Buttonlike>> for ButtonlikeChord {
+    /// Creates a [`ButtonlikeChord`] from boxed [`Buttonlike`]s of any type, avoiding duplicates.
+    fn from_iter<T: IntoIterator<Item = Box<dyn Buttonlike>>>(iter: T) -> Self {
+        let mut chord = Self::default();
+        for input in iter {
+            chord.push_boxed_unique(input);
         }
+        chord
     }
 }
 
@@ -113,13 +150,15 @@ impl UserInput for ButtonlikeChord {
 
     /// Retrieves a list of simple, atomic [`Buttonlike`]s that compose the chord.
     ///
-    /// The length of the basic inputs is the sum of the lengths of the inner inputs.
+    /// Nested chords are flattened, and buttons shared between the inner inputs are only listed once,
+    /// so the length of the basic inputs is the number of distinct buttons that must be pressed.
     #[inline]
     fn decompose(&self) -> BasicInputs {
         let inputs = self
-            .0
+            .inputs
             .iter()
             .flat_map(|input| input.decompose().inputs())
+            .unique()
             .collect();
         BasicInputs::Chord(inputs)
     }
@@ -127,35 +166,52 @@ impl UserInput for ButtonlikeChord {
 
 #[serde_typetag]
 impl Buttonlike for ButtonlikeChord {
-    /// Checks if all the inner inputs within the chord are active simultaneously.
+    /// Checks if all the inner inputs within the chord are active simultaneously,
+    /// treating those released within the [tolerance](ButtonlikeChord::with_tolerance) as still active.
     #[must_use]
     #[inline]
     fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
-        self.0
-            .iter()
-            .all(|input| input.pressed(input_store, gamepad))
+        if self.tolerance_frames == 0 {
+            return self
+                .inputs
+                .iter()
+                .all(|input| input.pressed(input_store, gamepad));
+        }
+
+        let mut any_pressed = false;
+        let all_within_tolerance = self.inputs.iter().all(|input| {
+            if input.pressed(input_store, gamepad) {
+                any_pressed = true;
+                return true;
+            }
+
+            input
+                .release_order(input_store, gamepad)
+                .is_some_and(|frame| input_store.frame() - frame < u64::from(self.tolerance_frames))
+        });
+        any_pressed && all_within_tolerance
     }
 
     fn press(&self, world: &mut World) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.press(world);
         }
     }
 
     fn release(&self, world: &mut World) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.release(world);
         }
     }
 
     fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.press_as_gamepad(world, gamepad);
         }
     }
 
     fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.release_as_gamepad(world, gamepad);
         }
     }
@@ -173,6 +229,10 @@ impl<U: Buttonlike> FromIterator<U> for ButtonlikeChord {
 
 /// A combined input that groups a [`Buttonlike`] and a [`Axislike`] together,
 /// allowing you to only read the axis value when the button is pressed.
+///
+/// Any [`Buttonlike`] can gate the axis, including mouse buttons:
+/// `AxislikeChord::new(MouseButton::Right, MouseMoveAxis::X)` only reads
+/// horizontal mouse motion while the right mouse button is held.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct AxislikeChord {
@@ -205,6 +265,12 @@ impl UserInput for AxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.axis.decompose())
     }
+
+    /// Checks if either the button or the axis of the chord reads from the `device`.
+    #[inline]
+    fn reads_from(&self, device: DeviceClass) -> bool {
+        self.button.reads_from(device) || self.axis.reads_from(device)
+    }
 }
 
 #[serde_typetag]
@@ -260,6 +326,12 @@ impl UserInput for DualAxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.dual_axis.decompose())
     }
+
+    /// Checks if either the button or the dual axis of the chord reads from the `device`.
+    #[inline]
+    fn reads_from(&self, device: DeviceClass) -> bool {
+        self.button.reads_from(device) || self.dual_axis.reads_from(device)
+    }
 }
 
 #[serde_typetag]
@@ -321,6 +393,12 @@ impl UserInput for TripleAxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.triple_axis.decompose())
     }
+
+    /// Checks if either the button or the triple axis of the chord reads from the `device`.
+    #[inline]
+    fn reads_from(&self, device: DeviceClass) -> bool {
+        self.button.reads_from(device) || self.triple_axis.reads_from(device)
+    }
 }
 
 #[serde_typetag]
@@ -404,7 +482,7 @@ mod tests {
             .iter()
             .map(|key| Box::new(*key) as Box<dyn Buttonlike>)
             .collect::<Vec<_>>();
-        assert_eq!(chord.0, expected_inners);
+        assert_eq!(chord.inputs, expected_inners);
 
         // No keys pressed, resulting in a released chord with a value of zero.
         let mut app = test_app();
@@ -445,4 +523,66 @@ mod tests {
         let inputs = app.world().resource::<CentralInputStore>();
         assert!(!chord.pressed(inputs, gamepad));
     }
+
+    #[test]
+    #[cfg(feature = "mouse")]
+    fn test_chord_with_tolerance() {
+        let strict = ButtonlikeChord::from_single(KeyCode::KeyA).with(MouseButton::Left);
+        let tolerant = strict.clone().with_tolerance(2);
+        assert_eq!(strict.tolerance(), 0);
+        assert_eq!(tolerant.tolerance(), 2);
+
+        let mut app = test_app();
+        let gamepad = app.world_mut().spawn(()).id();
+
+        // The key is pressed first...
+        KeyCode::KeyA.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!strict.pressed(inputs, gamepad));
+        assert!(!tolerant.pressed(inputs, gamepad));
+
+        // ...and released a frame later, as the mouse button is pressed
+        KeyCode::KeyA.release(app.world_mut());
+        MouseButton::Left.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!strict.pressed(inputs, gamepad));
+        assert!(tolerant.pressed(inputs, gamepad));
+
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(tolerant.pressed(inputs, gamepad));
+
+        // Once the tolerance has elapsed, the released key no longer counts
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!tolerant.pressed(inputs, gamepad));
+
+        // Members released within the tolerance don't activate the chord on their own
+        MouseButton::Left.release(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!tolerant.pressed(inputs, gamepad));
+    }
+
+    #[test]
+    fn test_nested_chord_decomposition() {
+        use crate::user_input::UserInput;
+
+        let inner = ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::ShiftLeft]);
+        let other_inner = ButtonlikeChord::new([KeyCode::ShiftLeft, KeyCode::KeyS]);
+        let chord = ButtonlikeChord::from_single(inner)
+            .with(other_inner)
+            .with(KeyCode::KeyS);
+
+        let expected_inputs = [KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyS]
+            .iter()
+            .map(|key| Box::new(*key) as Box<dyn Buttonlike>)
+            .collect::<Vec<_>>();
+
+        let basic_inputs = chord.decompose();
+        assert_eq!(basic_inputs.inputs(), expected_inputs);
+        assert_eq!(basic_inputs.len(), 3);
+    }
 }
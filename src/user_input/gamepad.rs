@@ -24,7 +24,7 @@ use crate::input_processing::{
     AxisProcessor, DualAxisProcessor, WithAxisProcessingPipelineExt,
     WithDualAxisProcessingPipelineExt,
 };
-use crate::user_input::UserInput;
+use crate::user_input::{DeviceClass, InputTag, UserInput};
 use crate::InputControlKind;
 
 use super::updating::{CentralInputStore, UpdatableInput};
@@ -41,12 +41,38 @@ pub fn find_gamepad(gamepads: Option<Query<Entity, With<Gamepad>>>) -> Entity {
     }
 }
 
-/// Retrieves the current value of the specified `axis`.
+/// Retrieves the current value of the specified `axis`,
+/// treating values below the [noise floor](CentralInputStore::noise_floor) as zero.
 #[must_use]
 #[inline]
 fn read_axis_value(input_store: &CentralInputStore, gamepad: Entity, axis: GamepadAxis) -> f32 {
-    let axis = SpecificGamepadAxis::new(gamepad, axis);
-    input_store.value(&axis)
+    let value = input_store.value(&SpecificGamepadAxis::new(gamepad, axis));
+    if value.abs() < input_store.noise_floor() {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// Retrieves the current values of the `x` and `y` axes of a stick,
+/// treating sticks whose magnitude is below the [noise floor](CentralInputStore::noise_floor) as centered.
+#[must_use]
+#[inline]
+fn read_stick_value(
+    input_store: &CentralInputStore,
+    gamepad: Entity,
+    x: GamepadAxis,
+    y: GamepadAxis,
+) -> Vec2 {
+    let value = Vec2::new(
+        input_store.value(&SpecificGamepadAxis::new(gamepad, x)),
+        input_store.value(&SpecificGamepadAxis::new(gamepad, y)),
+    );
+    if value.length() < input_store.noise_floor() {
+        Vec2::ZERO
+    } else {
+        value
+    }
 }
 
 /// A [`GamepadAxis`] for a specific gamepad (as opposed to all gamepads).
@@ -197,6 +223,12 @@ impl UserInput for GamepadControlDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// [`GamepadControlDirection`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -293,6 +325,12 @@ impl UserInput for GamepadAxis {
             Box::new(GamepadControlDirection::positive(*self)),
         ])
     }
+
+    /// [`GamepadAxis`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -316,6 +354,12 @@ impl UserInput for SpecificGamepadAxis {
             Box::new(GamepadControlDirection::positive(self.axis)),
         ])
     }
+
+    /// [`SpecificGamepadAxis`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -330,6 +374,16 @@ impl Axislike for SpecificGamepadAxis {
 /// By default, it reads from **any connected gamepad**.
 /// Use the [`InputMap::set_gamepad`](crate::input_map::InputMap::set_gamepad) for specific ones.
 ///
+/// # Analog Only
+///
+/// This input passes the raw value of the axis to an axislike action, such as steering or throttle.
+/// It has no threshold: the action never has a pressed state, and only its
+/// [`value`](crate::action_state::ActionState::value) changes, continuously.
+/// Its bindings don't take part in clash resolution with buttonlike actions either.
+///
+/// To treat an axis as a button instead, bind a [`GamepadControlDirection`] with a threshold,
+/// or use [`InputMap::insert_split_axis`](crate::input_map::InputMap::insert_split_axis).
+///
 /// # Value Processing
 ///
 /// You can customize how the values are processed using a pipeline of processors.
@@ -414,6 +468,12 @@ impl UserInput for GamepadControlAxis {
             Box::new(GamepadControlDirection::positive(self.axis)),
         ])
     }
+
+    /// [`GamepadControlAxis`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -423,9 +483,9 @@ impl Axislike for GamepadControlAxis {
     #[inline]
     fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
         let value = read_axis_value(input_store, gamepad, self.axis);
-        self.processors
-            .iter()
-            .fold(value, |value, processor| processor.process(value))
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`RawGamepadEvent::Axis`] event with the specified value on the provided gamepad.
@@ -542,6 +602,12 @@ impl UserInput for GamepadStick {
             Box::new(GamepadControlDirection::positive(self.y)),
         ])
     }
+
+    /// [`GamepadStick`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -550,11 +616,10 @@ impl DualAxislike for GamepadStick {
     #[must_use]
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, gamepad: Entity) -> Vec2 {
-        let x = read_axis_value(input_store, gamepad, self.x);
-        let y = read_axis_value(input_store, gamepad, self.y);
-        self.processors
-            .iter()
-            .fold(Vec2::new(x, y), |value, processor| processor.process(value))
+        let value = read_stick_value(input_store, gamepad, self.x, self.y);
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`RawGamepadEvent::Axis`] event with the specified values on the provided gamepad [`Entity`].
@@ -610,6 +675,30 @@ fn button_pressed(input_store: &CentralInputStore, gamepad: Entity, button: Game
     input_store.pressed(&button)
 }
 
+/// Retrieves the frame at which the given [`GamepadButton`] was pressed.
+#[must_use]
+#[inline]
+fn button_press_order(
+    input_store: &CentralInputStore,
+    gamepad: Entity,
+    button: GamepadButton,
+) -> Option<u64> {
+    let button = SpecificGamepadButton::new(gamepad, button);
+    input_store.press_order(&button)
+}
+
+/// Retrieves the frame at which the given [`GamepadButton`] was last released.
+#[must_use]
+#[inline]
+fn button_release_order(
+    input_store: &CentralInputStore,
+    gamepad: Entity,
+    button: GamepadButton,
+) -> Option<u64> {
+    let button = SpecificGamepadButton::new(gamepad, button);
+    input_store.release_order(&button)
+}
+
 /// Retrieves the current value of the given [`GamepadButton`].
 ///
 /// This will be 0.0 if the button is released, and 1.0 if it is pressed.
@@ -674,6 +763,12 @@ impl UserInput for SpecificGamepadButton {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// [`SpecificGamepadButton`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
 #[serde_typetag]
@@ -688,6 +783,16 @@ impl Buttonlike for SpecificGamepadButton {
         button_value(input_store, self.gamepad, self.button)
     }
 
+    /// WARNING: The supplied gamepad is ignored, as the button is already specific to a gamepad.
+    fn press_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        button_press_order(input_store, self.gamepad, self.button)
+    }
+
+    /// WARNING: The supplied gamepad is ignored, as the button is already specific to a gamepad.
+    fn release_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        button_release_order(input_store, self.gamepad, self.button)
+    }
+
     fn press(&self, world: &mut World) {
         self.set_value(world, 1.0);
     }
@@ -720,8 +825,16 @@ impl UserInput for GamepadButton {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// [`GamepadButton`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
 }
 
+impl InputTag for GamepadButton {}
+
 #[serde_typetag]
 impl Buttonlike for GamepadButton {
     /// Checks if the specified button is currently pressed down.
@@ -742,6 +855,18 @@ impl Buttonlike for GamepadButton {
         button_value(input_store, gamepad, *self)
     }
 
+    /// Retrieves the frame at which the specified button was pressed.
+    #[inline]
+    fn press_order(&self, input_store: &CentralInputStore, gamepad: Entity) -> Option<u64> {
+        button_press_order(input_store, gamepad, *self)
+    }
+
+    /// Retrieves the frame at which the specified button was last released.
+    #[inline]
+    fn release_order(&self, input_store: &CentralInputStore, gamepad: Entity) -> Option<u64> {
+        button_release_order(input_store, gamepad, *self)
+    }
+
     /// Sends a [`RawGamepadEvent::Button`] event with a magnitude of 1.0 in the direction defined by `self` on the provided gamepad [`Entity`].
     fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
         self.set_value_as_gamepad(world, 1.0, gamepad);
@@ -768,6 +893,90 @@ impl Buttonlike for GamepadButton {
     }
 }
 
+/// A buttonlike input that is pressed whenever any [`GamepadButton`] of the gamepad is pressed,
+/// such as for "press any button to continue" prompts.
+///
+/// By default, it reads from **any connected gamepad**.
+/// Use the [`InputMap::set_gamepad`](crate::input_map::InputMap::set_gamepad) for specific ones.
+///
+/// Use [`AnyGamepadButton::pressed_buttons`] to find out which buttons are pressing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct AnyGamepadButton;
+
+impl AnyGamepadButton {
+    /// Returns the [`GamepadButton`]s of the `gamepad` that are currently pressed.
+    #[inline]
+    pub fn pressed_buttons(
+        input_store: &CentralInputStore,
+        gamepad: Entity,
+    ) -> impl Iterator<Item = GamepadButton> + '_ {
+        input_store
+            .pressed_buttonlikes::<SpecificGamepadButton>()
+            .filter(move |specific_button| specific_button.gamepad == gamepad)
+            .map(|specific_button| specific_button.button)
+    }
+}
+
+impl UserInput for AnyGamepadButton {
+    /// [`AnyGamepadButton`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns itself, as it does not stand for any particular buttons.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(*self))
+    }
+
+    /// [`AnyGamepadButton`] is read from the gamepad.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Gamepad)
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for AnyGamepadButton {
+    /// Checks if any button of the provided gamepad [`Entity`] is currently pressed down.
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        Self::pressed_buttons(input_store, gamepad).next().is_some()
+    }
+
+    /// Presses [`GamepadButton::South`] on the provided gamepad [`Entity`], standing in for any button.
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        GamepadButton::South.press_as_gamepad(world, gamepad);
+    }
+
+    /// Releases every button that is currently pressed on the provided gamepad [`Entity`].
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        let mut query_state = SystemState::<Query<Entity, With<Gamepad>>>::new(world);
+        let query = query_state.get(world);
+        let gamepad = gamepad.unwrap_or(find_gamepad(Some(query)));
+
+        let pressed_buttons: Vec<GamepadButton> = world
+            .get::<Gamepad>(gamepad)
+            .map(|state| state.get_pressed().copied().collect())
+            .unwrap_or_default();
+
+        for button in pressed_buttons {
+            button.release_as_gamepad(world, Some(gamepad));
+        }
+    }
+
+    /// If the value is greater than `0.0`, press a button; otherwise release all buttons.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        if value > 0.0 {
+            self.press_as_gamepad(world, gamepad);
+        } else {
+            self.release_as_gamepad(world, gamepad);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,13 +11,16 @@ use serde::{Deserialize, Serialize};
 use crate as leafwing_input_manager;
 use crate::buttonlike::ButtonValue;
 use crate::clashing_inputs::BasicInputs;
-use crate::user_input::{ButtonlikeChord, UserInput};
+use crate::user_input::{ButtonlikeChord, DeviceClass, InputTag, UserInput};
 use crate::InputControlKind;
 
 use super::updating::{CentralInputStore, UpdatableInput};
 use super::Buttonlike;
 
 // Built-in support for Bevy's KeyCode
+//
+// `KeyCode` identifies the physical position of a key (its scancode), rather than the character it produces.
+// As a result, bindings such as WASD stay in the same place regardless of the active keyboard layout.
 impl UserInput for KeyCode {
     /// [`KeyCode`] acts as a button.
     #[inline]
@@ -31,8 +34,16 @@ impl UserInput for KeyCode {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// [`KeyCode`] is read from the keyboard.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Keyboard)
+    }
 }
 
+impl InputTag for KeyCode {}
+
 impl UpdatableInput for KeyCode {
     type SourceData = SRes<ButtonInput<KeyCode>>;
 
@@ -59,6 +70,18 @@ impl Buttonlike for KeyCode {
         input_store.pressed(self)
     }
 
+    /// Returns the frame at which the specified key was pressed.
+    #[inline]
+    fn press_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        input_store.press_order(self)
+    }
+
+    /// Returns the frame at which the specified key was last released.
+    #[inline]
+    fn release_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        input_store.release_order(self)
+    }
+
     /// Sends a fake [`KeyboardInput`] event to the world with [`ButtonState::Pressed`].
     ///
     /// # Note
@@ -172,6 +195,12 @@ impl UserInput for ModifierKey {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Composite(vec![Box::new(self.left()), Box::new(self.right())])
     }
+
+    /// [`ModifierKey`] is read from the keyboard.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Keyboard)
+    }
 }
 
 #[serde_typetag]
@@ -217,6 +246,86 @@ impl Buttonlike for ModifierKey {
     }
 }
 
+/// A buttonlike input that is pressed whenever any [`KeyCode`] is pressed,
+/// such as for "press any key to continue" prompts.
+///
+/// Use [`AnyKeyboardKey::pressed_keys`] to find out which keys are pressing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct AnyKeyboardKey;
+
+impl AnyKeyboardKey {
+    /// Returns the [`KeyCode`]s that are currently pressed.
+    #[inline]
+    pub fn pressed_keys(input_store: &CentralInputStore) -> impl Iterator<Item = KeyCode> + '_ {
+        input_store.pressed_buttonlikes::<KeyCode>().copied()
+    }
+}
+
+impl UserInput for AnyKeyboardKey {
+    /// [`AnyKeyboardKey`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns itself, as it does not stand for any particular keys.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(*self))
+    }
+
+    /// [`AnyKeyboardKey`] is read from the keyboard.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Keyboard)
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for AnyKeyboardKey {
+    /// Checks if any key is currently pressed down.
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, _gamepad: Entity) -> bool {
+        Self::pressed_keys(input_store).next().is_some()
+    }
+
+    /// Presses [`KeyCode::Space`], standing in for any key.
+    ///
+    /// # Note
+    ///
+    /// The `logical_key` and `window` fields will be filled with placeholder values.
+    fn press(&self, world: &mut World) {
+        KeyCode::Space.press(world);
+    }
+
+    /// Releases every key that is currently pressed.
+    ///
+    /// # Note
+    ///
+    /// The `logical_key` and `window` fields will be filled with placeholder values.
+    fn release(&self, world: &mut World) {
+        let pressed_keys: Vec<KeyCode> = world
+            .resource::<ButtonInput<KeyCode>>()
+            .get_pressed()
+            .copied()
+            .collect();
+
+        for key in pressed_keys {
+            key.release(world);
+        }
+    }
+
+    /// If the value is greater than `0.0`, press a key; otherwise release all keys.
+    fn set_value(&self, world: &mut World, value: f32) {
+        if value > 0.0 {
+            self.press(world);
+        } else {
+            self.release(world);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,6 +340,38 @@ mod tests {
         app
     }
 
+    #[test]
+    fn test_any_keyboard_key() {
+        let any_key = AnyKeyboardKey;
+        assert_eq!(any_key.kind(), InputControlKind::Button);
+
+        let gamepad = Entity::PLACEHOLDER;
+
+        // No inputs
+        let mut app = test_app();
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(!any_key.pressed(inputs, gamepad));
+        assert_eq!(AnyKeyboardKey::pressed_keys(inputs).count(), 0);
+
+        // Press an arbitrary key
+        KeyCode::KeyQ.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(any_key.pressed(inputs, gamepad));
+        let pressed_keys: Vec<_> = AnyKeyboardKey::pressed_keys(inputs).collect();
+        assert_eq!(pressed_keys, [KeyCode::KeyQ]);
+
+        // Releasing it releases every pressed key
+        any_key.release(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(!any_key.pressed(inputs, gamepad));
+    }
+
     #[test]
     fn test_keyboard_input() {
         let up = KeyCode::ArrowUp;
@@ -324,4 +465,24 @@ mod tests {
         assert!(!left.pressed(inputs, gamepad));
         assert!(alt.pressed(inputs, gamepad));
     }
+
+    #[test]
+    fn test_key_code_tags() {
+        for key in [
+            KeyCode::KeyA,
+            KeyCode::Space,
+            KeyCode::ShiftLeft,
+            KeyCode::F12,
+        ] {
+            let tag = key.as_tag().unwrap();
+            assert_eq!(KeyCode::from_tag(tag), Some(key));
+        }
+        assert_eq!(KeyCode::KeyA.as_tag(), Some("KeyA"));
+
+        let unidentified =
+            KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified);
+        assert_eq!(unidentified.as_tag(), None);
+        assert_eq!(KeyCode::from_tag("Unidentified"), None);
+        assert_eq!(KeyCode::from_tag("NotAKey"), None);
+    }
 }
@@ -52,6 +52,14 @@
 //! - Track mouse motion with [`MouseMove`], [`MouseMoveAxis`], and [`MouseMoveDirection`].
 //! - Capture mouse wheel events with [`MouseScroll`], [`MouseScrollAxis`], and [`MouseScrollDirection`].
 //!
+//! ### Touch Inputs
+//!
+//! - Bind taps, or touches within a region of the screen, with [`ScreenTouch`].
+//!
+//! ### Window Inputs
+//!
+//! - React to the window losing focus or being resized with [`AppEvent`].
+//!
 //! ### Virtual Axial Controls
 //!
 //! - [`VirtualAxis`]: Create a virtual axis control from two buttons.
@@ -82,11 +90,14 @@ use std::fmt::Debug;
 
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Entity, World};
-use bevy::reflect::{erased_serde, Reflect};
+use bevy::reflect::{
+    erased_serde, DynamicEnum, DynamicVariant, Enum, FromReflect, Reflect, TypeInfo, Typed,
+    VariantInfo,
+};
 use dyn_clone::DynClone;
 use dyn_eq::DynEq;
 use dyn_hash::DynHash;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use updating::CentralInputStore;
 
 use crate::clashing_inputs::BasicInputs;
@@ -99,8 +110,12 @@ pub use self::gamepad::*;
 pub use self::keyboard::*;
 #[cfg(feature = "mouse")]
 pub use self::mouse::*;
+#[cfg(feature = "touch")]
+pub use self::touch::*;
 pub use self::trait_serde::RegisterUserInput;
 pub use self::virtual_axial::*;
+#[cfg(feature = "window")]
+pub use self::window::*;
 
 pub mod chord;
 #[cfg(feature = "gamepad")]
@@ -110,10 +125,14 @@ pub mod keyboard;
 #[cfg(feature = "mouse")]
 pub mod mouse;
 pub mod testing_utils;
+#[cfg(feature = "touch")]
+pub mod touch;
 mod trait_reflection;
 mod trait_serde;
 pub mod updating;
 pub mod virtual_axial;
+#[cfg(feature = "window")]
+pub mod window;
 
 /// A trait for defining the behavior expected from different user input sources.
 pub trait UserInput: Send + Sync + Debug {
@@ -128,6 +147,81 @@ pub trait UserInput: Send + Sync + Debug {
     /// For inputs that represent a simple, atomic control,
     /// this method should always return a [`BasicInputs::Simple`] that only contains the input itself.
     fn decompose(&self) -> BasicInputs;
+
+    /// Returns the class of the physical device that this input is read from,
+    /// or [`None`] if it is made of other inputs or isn't read from a keyboard, mouse or gamepad.
+    fn device_class(&self) -> Option<DeviceClass> {
+        None
+    }
+
+    /// Checks if this input reads from a device of the given class,
+    /// either directly or through any of the [`BasicInputs`] that make it up.
+    ///
+    /// This is useful to split bindings by device, such as in the settings menus for each device.
+    fn reads_from(&self, device: DeviceClass) -> bool {
+        self.device_class() == Some(device)
+            || self
+                .decompose()
+                .inputs()
+                .iter()
+                .any(|input| input.device_class() == Some(device))
+    }
+}
+
+/// The classes of physical devices that [`UserInput`]s can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum DeviceClass {
+    /// A keyboard.
+    Keyboard,
+    /// A mouse, including its wheel.
+    Mouse,
+    /// A gamepad.
+    Gamepad,
+    /// A touchscreen.
+    Touch,
+}
+
+/// Stable string identifiers for the simple variants of built-in button inputs,
+/// such as [`KeyCode::KeyA`] or [`MouseButton::Left`],
+/// to map between bindings and data, such as the dropdowns of a data-driven binding editor.
+///
+/// The tag of an input is the name of its variant, which doesn't change with the way it's serialized.
+/// Variants that hold data, such as [`MouseButton::Other`], have no tag.
+///
+/// Tags are only unique among inputs of the same type:
+/// store the [`DeviceClass`] alongside them when mixing inputs from different devices.
+///
+/// [`KeyCode::KeyA`]: bevy::prelude::KeyCode::KeyA
+/// [`MouseButton::Left`]: bevy::prelude::MouseButton::Left
+/// [`MouseButton::Other`]: bevy::prelude::MouseButton::Other
+pub trait InputTag: Enum + Typed + FromReflect {
+    /// Returns the stable identifier of this input, or [`None`] if its variant holds data.
+    #[must_use]
+    fn as_tag(&self) -> Option<&'static str> {
+        let TypeInfo::Enum(info) = Self::type_info() else {
+            return None;
+        };
+
+        match info.variant_at(self.variant_index())? {
+            VariantInfo::Unit(variant) => Some(variant.name()),
+            _ => None,
+        }
+    }
+
+    /// Creates the input identified by the `tag`, or returns [`None`] if no variant without data has this name.
+    #[must_use]
+    fn from_tag(tag: &str) -> Option<Self> {
+        let TypeInfo::Enum(info) = Self::type_info() else {
+            return None;
+        };
+
+        match info.variant(tag)? {
+            VariantInfo::Unit(variant) => {
+                Self::from_reflect(&DynamicEnum::new(variant.name(), DynamicVariant::Unit))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// A trait used for buttonlike user inputs, which can be pressed or released
@@ -152,6 +246,25 @@ pub trait Buttonlike:
         f32::from(self.pressed(input_store, gamepad))
     }
 
+    /// Returns a number that orders the moments at which inputs were pressed,
+    /// where inputs pressed later have larger numbers and inputs pressed during the same frame share one,
+    /// or `None` if the input is not pressed or its press order is not tracked.
+    ///
+    /// This is used to resolve simultaneous opposite inputs, as described by [`SocdMode`].
+    /// The default implementation returns `None`.
+    fn press_order(&self, _input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        None
+    }
+
+    /// Returns the frame at which the input was last released, comparable with [`CentralInputStore::frame`],
+    /// or `None` if the input is pressed, has never been released, or its release order is not tracked.
+    ///
+    /// This is used by [`ButtonlikeChord::with_tolerance`] to let members pressed slightly apart count as simultaneous.
+    /// The default implementation returns `None`.
+    fn release_order(&self, _input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        None
+    }
+
     /// Simulates a press of the buttonlike input by sending the appropriate event.
     ///
     /// This method defaults to calling [`Buttonlike::press_as_gamepad`] if not overridden,
@@ -218,6 +331,12 @@ pub trait Buttonlike:
     }
 }
 
+impl<B: Buttonlike> From<B> for Box<dyn Buttonlike> {
+    fn from(input: B) -> Self {
+        Box::new(input)
+    }
+}
+
 /// A trait used for axis-like user inputs, which provide a continuous value.
 pub trait Axislike:
     UserInput + DynClone + DynEq + DynHash + Reflect + erased_serde::Serialize
@@ -346,4 +465,22 @@ impl UserInput for UserInputWrapper {
             UserInputWrapper::TripleAxis(input) => input.decompose(),
         }
     }
+
+    fn device_class(&self) -> Option<DeviceClass> {
+        match self {
+            UserInputWrapper::Button(input) => input.device_class(),
+            UserInputWrapper::Axis(input) => input.device_class(),
+            UserInputWrapper::DualAxis(input) => input.device_class(),
+            UserInputWrapper::TripleAxis(input) => input.device_class(),
+        }
+    }
+
+    fn reads_from(&self, device: DeviceClass) -> bool {
+        match self {
+            UserInputWrapper::Button(input) => input.reads_from(device),
+            UserInputWrapper::Axis(input) => input.reads_from(device),
+            UserInputWrapper::DualAxis(input) => input.reads_from(device),
+            UserInputWrapper::TripleAxis(input) => input.reads_from(device),
+        }
+    }
 }
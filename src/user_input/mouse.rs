@@ -5,8 +5,8 @@ use crate::axislike::{DualAxisDirection, DualAxisType};
 use crate::buttonlike::ButtonValue;
 use crate::clashing_inputs::BasicInputs;
 use crate::input_processing::*;
-use crate::user_input::{InputControlKind, UserInput};
-use bevy::ecs::system::lifetimeless::SRes;
+use crate::user_input::{DeviceClass, InputControlKind, InputTag, UserInput};
+use bevy::ecs::system::lifetimeless::{SRes, SResMut};
 use bevy::ecs::system::StaticSystemParam;
 use bevy::input::mouse::{
     AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton, MouseButtonInput, MouseMotion,
@@ -14,7 +14,7 @@ use bevy::input::mouse::{
 };
 use bevy::input::{ButtonInput, ButtonState};
 use bevy::math::FloatOrd;
-use bevy::prelude::{Entity, Events, Reflect, ResMut, Vec2, World};
+use bevy::prelude::{Entity, Events, Reflect, ResMut, Resource, Vec2, World};
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
@@ -36,8 +36,16 @@ impl UserInput for MouseButton {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// [`MouseButton`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
+impl InputTag for MouseButton {}
+
 impl UpdatableInput for MouseButton {
     type SourceData = SRes<ButtonInput<MouseButton>>;
 
@@ -63,6 +71,18 @@ impl Buttonlike for MouseButton {
         input_store.pressed(self)
     }
 
+    /// Returns the frame at which the specified button was pressed.
+    #[inline]
+    fn press_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        input_store.press_order(self)
+    }
+
+    /// Returns the frame at which the specified button was last released.
+    #[inline]
+    fn release_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        input_store.release_order(self)
+    }
+
     /// Sends a fake [`MouseButtonInput`] event to the world with [`ButtonState::Pressed`].
     ///
     /// # Note
@@ -191,6 +211,12 @@ impl UserInput for MouseMoveDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// [`MouseMoveDirection`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -302,6 +328,12 @@ impl UserInput for MouseMoveAxis {
             }),
         ])
     }
+
+    /// [`MouseMoveAxis`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -313,9 +345,9 @@ impl Axislike for MouseMoveAxis {
     fn value(&self, input_store: &CentralInputStore, _gamepad: Entity) -> f32 {
         let movement = input_store.pair(&MouseMove::default());
         let value = self.axis.get_value(movement);
-        self.processors
-            .iter()
-            .fold(value, |value, processor| processor.process(value))
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`MouseMotion`] event along the appropriate axis with the specified value.
@@ -353,6 +385,68 @@ impl WithAxisProcessingPipelineExt for MouseMoveAxis {
     }
 }
 
+/// Controls how long the mouse motion read by [`MouseMove`] and its derived inputs persists.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum MouseMotionResetPolicy {
+    /// The motion is only reported during the frame it happened in,
+    /// and reset to zero on the next frame without motion.
+    ///
+    /// This suits edge-triggered systems that must react to each movement exactly once.
+    #[default]
+    EachFrame,
+
+    /// The motion is reported during the frame it happened in,
+    /// and kept for one more frame if no new motion arrives in the meantime.
+    ///
+    /// This suits continuous systems such as camera look,
+    /// which would otherwise stutter when the app updates faster than the mouse reports movement.
+    PersistOneFrame,
+}
+
+/// A resource that tracks the mouse motion read by [`MouseMove`],
+/// applying the configured [`MouseMotionResetPolicy`].
+///
+/// It is added by the [`CentralInputStorePlugin`](crate::plugin::CentralInputStorePlugin),
+/// and its policy can be changed at any time.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let mut app = App::new();
+/// app.insert_resource(MouseMotionAccumulator::new(MouseMotionResetPolicy::PersistOneFrame));
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct MouseMotionAccumulator {
+    /// The policy used to reset the mouse motion.
+    pub policy: MouseMotionResetPolicy,
+    /// The motion that may still be reported during the next frame.
+    held: Vec2,
+}
+
+impl MouseMotionAccumulator {
+    /// Creates a [`MouseMotionAccumulator`] using the given `policy`.
+    #[inline]
+    pub fn new(policy: MouseMotionResetPolicy) -> Self {
+        Self {
+            policy,
+            held: Vec2::ZERO,
+        }
+    }
+
+    /// Returns the motion to report this frame, given the `delta` accumulated during it.
+    fn accumulate(&mut self, delta: Vec2) -> Vec2 {
+        match self.policy {
+            MouseMotionResetPolicy::EachFrame => delta,
+            MouseMotionResetPolicy::PersistOneFrame if delta != Vec2::ZERO => {
+                self.held = delta;
+                delta
+            }
+            MouseMotionResetPolicy::PersistOneFrame => std::mem::take(&mut self.held),
+        }
+    }
+}
+
 /// Relative changes in position of mouse movement on both axes.
 ///
 /// # Value Processing
@@ -389,13 +483,21 @@ pub struct MouseMove {
 }
 
 impl UpdatableInput for MouseMove {
-    type SourceData = SRes<AccumulatedMouseMotion>;
+    type SourceData = (
+        SRes<AccumulatedMouseMotion>,
+        Option<SResMut<MouseMotionAccumulator>>,
+    );
 
     fn compute(
         mut central_input_store: ResMut<CentralInputStore>,
         source_data: StaticSystemParam<Self::SourceData>,
     ) {
-        central_input_store.update_dualaxislike(Self::default(), source_data.delta);
+        let (accumulated, accumulator) = source_data.into_inner();
+        let delta = match accumulator {
+            Some(mut accumulator) => accumulator.accumulate(accumulated.delta),
+            None => accumulated.delta,
+        };
+        central_input_store.update_dualaxislike(Self::default(), delta);
     }
 }
 
@@ -416,6 +518,12 @@ impl UserInput for MouseMove {
             Box::new(MouseMoveDirection::RIGHT),
         ])
     }
+
+    /// [`MouseMove`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -425,9 +533,9 @@ impl DualAxislike for MouseMove {
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Vec2 {
         let movement = input_store.pair(&MouseMove::default());
-        self.processors
-            .iter()
-            .fold(movement, |value, processor| processor.process(value))
+        self.processors.iter().fold(movement, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`MouseMotion`] event with the specified displacement.
@@ -551,6 +659,12 @@ impl UserInput for MouseScrollDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// [`MouseScrollDirection`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -669,6 +783,12 @@ impl UserInput for MouseScrollAxis {
             }),
         ])
     }
+
+    /// [`MouseScrollAxis`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -680,9 +800,9 @@ impl Axislike for MouseScrollAxis {
     fn value(&self, input_store: &CentralInputStore, _gamepad: Entity) -> f32 {
         let movement = input_store.pair(&MouseScroll::default());
         let value = self.axis.get_value(movement);
-        self.processors
-            .iter()
-            .fold(value, |value, processor| processor.process(value))
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`MouseWheel`] event along the appropriate axis with the specified value in pixels.
@@ -795,6 +915,12 @@ impl UserInput for MouseScroll {
             Box::new(MouseScrollDirection::RIGHT),
         ])
     }
+
+    /// [`MouseScroll`] is read from the mouse.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Mouse)
+    }
 }
 
 #[serde_typetag]
@@ -804,9 +930,9 @@ impl DualAxislike for MouseScroll {
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Vec2 {
         let movement = input_store.pair(&MouseScroll::default());
-        self.processors
-            .iter()
-            .fold(movement, |value, processor| processor.process(value))
+        self.processors.iter().fold(movement, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sends a [`MouseWheel`] event with the specified displacement in pixels.
@@ -1113,4 +1239,70 @@ mod tests {
             "No movement was expected. Is the position in the event stream being cleared properly?"
         );
     }
+
+    #[test]
+    fn mouse_motion_reset_policy() {
+        let motion_after_two_frames = |policy: MouseMotionResetPolicy| {
+            let mut app = test_app();
+            app.insert_resource(MouseMotionAccumulator::new(policy));
+
+            MouseMoveAxis::Y.set_value(app.world_mut(), 3.0);
+            app.update();
+            let inputs = app.world().resource::<CentralInputStore>();
+            assert_eq!(inputs.pair(&MouseMove::default()), Vec2::new(0.0, 3.0));
+
+            app.update();
+            let inputs = app.world().resource::<CentralInputStore>();
+            let second = inputs.pair(&MouseMove::default());
+
+            app.update();
+            let inputs = app.world().resource::<CentralInputStore>();
+            assert_eq!(inputs.pair(&MouseMove::default()), Vec2::ZERO);
+
+            second
+        };
+
+        assert_eq!(
+            motion_after_two_frames(MouseMotionResetPolicy::EachFrame),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            motion_after_two_frames(MouseMotionResetPolicy::PersistOneFrame),
+            Vec2::new(0.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn mouse_motion_per_second() {
+        let rate = |motion: Vec2, delta_secs: f32| {
+            let mut inputs = CentralInputStore::default();
+            inputs.update_dualaxislike(MouseMove::default(), motion);
+            inputs.set_delta_secs(delta_secs);
+
+            let x = MouseMoveAxis::X
+                .per_second()
+                .value(&inputs, Entity::PLACEHOLDER);
+            let pair = MouseMove::default()
+                .per_second()
+                .axis_pair(&inputs, Entity::PLACEHOLDER);
+            assert_eq!(x, pair.x);
+            pair
+        };
+
+        // The same mouse speed at 50 and 25 frames per second
+        assert_eq!(rate(Vec2::new(2.0, 1.0), 0.02), Vec2::new(100.0, 50.0));
+        assert_eq!(rate(Vec2::new(4.0, 2.0), 0.04), Vec2::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn test_mouse_button_tags() {
+        for button in [MouseButton::Left, MouseButton::Middle, MouseButton::Forward] {
+            let tag = button.as_tag().unwrap();
+            assert_eq!(MouseButton::from_tag(tag), Some(button));
+        }
+
+        assert_eq!(MouseButton::Other(7).as_tag(), None);
+        assert_eq!(MouseButton::from_tag("Other"), None);
+        assert_eq!(MouseButton::from_tag("KeyA"), None);
+    }
 }
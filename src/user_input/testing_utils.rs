@@ -3,11 +3,11 @@
 use bevy::{
     app::App,
     ecs::system::SystemState,
-    math::Vec2,
+    math::{Vec2, Vec3},
     prelude::{Entity, Gamepad, Query, With, World},
 };
 
-use super::{updating::CentralInputStore, Axislike, Buttonlike, DualAxislike};
+use super::{updating::CentralInputStore, Axislike, Buttonlike, DualAxislike, TripleAxislike};
 
 #[cfg(feature = "gamepad")]
 use crate::user_input::gamepad::find_gamepad;
@@ -89,3 +89,136 @@ impl FetchUserInput for App {
         self.world_mut().read_dual_axis_values(input)
     }
 }
+
+/// A single mocked input change queued in a [`MockInputBatch`].
+#[derive(Debug)]
+enum MockedInput {
+    Press(Box<dyn Buttonlike>),
+    Release(Box<dyn Buttonlike>),
+    ButtonValue(Box<dyn Buttonlike>, f32),
+    AxisValue(Box<dyn Axislike>, f32),
+    AxisPair(Box<dyn DualAxislike>, Vec2),
+    AxisTriple(Box<dyn TripleAxislike>, Vec3),
+}
+
+/// A builder that queues several mocked inputs, which are then sent together.
+///
+/// As all of the inputs are sent before the next [`App::update`],
+/// they are all registered in the same frame.
+///
+/// # Examples
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::user_input::testing_utils::{FetchUserInput, MockInputBatch};
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// MockInputBatch::new()
+///     .press(KeyCode::Space)
+///     .press(MouseButton::Left)
+///     .send(app.world_mut());
+/// app.update();
+///
+/// assert!(app.read_pressed(KeyCode::Space));
+/// assert!(app.read_pressed(MouseButton::Left));
+/// ```
+#[derive(Debug, Default)]
+#[must_use]
+pub struct MockInputBatch {
+    /// The gamepad [`Entity`] to send gamepad inputs from.
+    gamepad: Option<Entity>,
+
+    /// The queued input changes, in the order they will be sent.
+    inputs: Vec<MockedInput>,
+}
+
+impl MockInputBatch {
+    /// Creates an empty [`MockInputBatch`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends gamepad inputs as if they came from the provided `gamepad`.
+    ///
+    /// If this is not called, the first connected gamepad is used.
+    #[inline]
+    pub fn with_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
+    /// Queues a press of the [`Buttonlike`] `input`.
+    #[inline]
+    pub fn press(mut self, input: impl Buttonlike) -> Self {
+        self.inputs.push(MockedInput::Press(Box::new(input)));
+        self
+    }
+
+    /// Queues a release of the [`Buttonlike`] `input`.
+    #[inline]
+    pub fn release(mut self, input: impl Buttonlike) -> Self {
+        self.inputs.push(MockedInput::Release(Box::new(input)));
+        self
+    }
+
+    /// Queues a value change of the [`Buttonlike`] `input`.
+    #[inline]
+    pub fn set_button_value(mut self, input: impl Buttonlike, value: f32) -> Self {
+        self.inputs
+            .push(MockedInput::ButtonValue(Box::new(input), value));
+        self
+    }
+
+    /// Queues a value change of the [`Axislike`] `input`.
+    #[inline]
+    pub fn set_axis_value(mut self, input: impl Axislike, value: f32) -> Self {
+        self.inputs
+            .push(MockedInput::AxisValue(Box::new(input), value));
+        self
+    }
+
+    /// Queues a value change of the [`DualAxislike`] `input`.
+    #[inline]
+    pub fn set_axis_pair(mut self, input: impl DualAxislike, value: Vec2) -> Self {
+        self.inputs
+            .push(MockedInput::AxisPair(Box::new(input), value));
+        self
+    }
+
+    /// Queues a value change of the [`TripleAxislike`] `input`.
+    #[inline]
+    pub fn set_axis_triple(mut self, input: impl TripleAxislike, value: Vec3) -> Self {
+        self.inputs
+            .push(MockedInput::AxisTriple(Box::new(input), value));
+        self
+    }
+
+    /// Sends all of the queued inputs to the `world`, in the order they were queued.
+    pub fn send(self, world: &mut World) {
+        let gamepad = self.gamepad;
+        for input in self.inputs {
+            match input {
+                MockedInput::Press(input) => input.press_as_gamepad(world, gamepad),
+                MockedInput::Release(input) => input.release_as_gamepad(world, gamepad),
+                MockedInput::ButtonValue(input, value) => {
+                    input.set_value_as_gamepad(world, value, gamepad);
+                }
+                MockedInput::AxisValue(input, value) => {
+                    input.set_value_as_gamepad(world, value, gamepad);
+                }
+                MockedInput::AxisPair(input, value) => {
+                    input.set_axis_pair_as_gamepad(world, value, gamepad);
+                }
+                MockedInput::AxisTriple(input, value) => {
+                    input.set_axis_triple_as_gamepad(world, value, gamepad);
+                }
+            }
+        }
+    }
+}
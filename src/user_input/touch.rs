@@ -0,0 +1,293 @@
+//! Touch inputs
+
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::StaticSystemParam;
+use bevy::input::touch::{TouchInput, TouchPhase, Touches};
+use bevy::math::{FloatOrd, Rect};
+use bevy::prelude::{Entity, Events, Reflect, ResMut, Vec2, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+use crate as leafwing_input_manager;
+use crate::buttonlike::ButtonValue;
+use crate::clashing_inputs::BasicInputs;
+use crate::user_input::{DeviceClass, UserInput};
+use crate::InputControlKind;
+
+use super::updating::{CentralInputStore, UpdatableInput};
+use super::{Buttonlike, DualAxislike};
+
+/// A finger touching the screen, anywhere or within a given region of it.
+///
+/// The input is pressed while any finger touches the screen (within the region, if any),
+/// so binding it to an action fires [`just_pressed`](crate::action_state::ActionState::just_pressed) on each tap.
+/// Taps that start and end during the same frame are still reported as pressed for that frame.
+///
+/// Regions are expressed in the logical pixels of the window, with the origin at its top-left corner,
+/// just like the positions reported by Bevy's [`Touches`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // A button covering the top-left corner of the screen
+/// let corner = ScreenTouch::in_region(Rect::new(0.0, 0.0, 100.0, 100.0));
+///
+/// // Touching anywhere else doesn't activate it
+/// ScreenTouch::in_region(Rect::new(500.0, 500.0, 600.0, 600.0)).press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(ScreenTouch::ANYWHERE));
+/// assert!(!app.read_pressed(corner));
+///
+/// // Touching the corner does
+/// corner.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(corner));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct ScreenTouch {
+    /// The region of the screen that must be touched, or `None` to accept touches anywhere.
+    pub region: Option<Rect>,
+}
+
+impl ScreenTouch {
+    /// A touch anywhere on the screen.
+    pub const ANYWHERE: Self = Self { region: None };
+
+    /// Creates a [`ScreenTouch`] that is only pressed by touches within the given `region`.
+    #[inline]
+    pub const fn in_region(region: Rect) -> Self {
+        Self {
+            region: Some(region),
+        }
+    }
+
+    /// Returns the point used to simulate a touch on this input: the center of its region, if any.
+    fn simulated_position(&self) -> Vec2 {
+        self.region
+            .map(|region| region.center())
+            .unwrap_or_default()
+    }
+
+    /// Sends a fake [`TouchInput`] event in the given `phase` at the simulated position.
+    fn send_touch(&self, world: &mut World, phase: TouchPhase) {
+        world.resource_mut::<Events<TouchInput>>().send(TouchInput {
+            phase,
+            position: self.simulated_position(),
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id: 0,
+        });
+    }
+}
+
+impl UserInput for ScreenTouch {
+    /// [`ScreenTouch`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns a [`BasicInputs`] that only contains the [`ScreenTouch`] itself,
+    /// as it represents a simple virtual button.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(*self))
+    }
+
+    /// [`ScreenTouch`] is read from the touchscreen.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Touch)
+    }
+}
+
+impl UpdatableInput for ScreenTouch {
+    type SourceData = SRes<Touches>;
+
+    fn compute(
+        mut central_input_store: ResMut<CentralInputStore>,
+        source_data: StaticSystemParam<Self::SourceData>,
+    ) {
+        // Quick taps are released during the same frame they start in,
+        // so they are only listed among the just pressed touches
+        let mut touched = false;
+        for touch in source_data.iter().chain(source_data.iter_just_pressed()) {
+            touched = true;
+            central_input_store
+                .update_dualaxislike(TouchPoint { id: touch.id() }, touch.position());
+        }
+
+        central_input_store.update_buttonlike(Self::ANYWHERE, ButtonValue::from_pressed(touched));
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for ScreenTouch {
+    /// Checks if any finger is touching the screen, within the region if any.
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, _gamepad: Entity) -> bool {
+        match self.region {
+            None => input_store.pressed(self),
+            Some(region) => input_store
+                .dualaxislikes::<TouchPoint>()
+                .any(|(_, position)| region.contains(position)),
+        }
+    }
+
+    /// Returns the frame at which the screen started being touched.
+    ///
+    /// The order is only tracked for touches anywhere on the screen.
+    #[inline]
+    fn press_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        match self.region {
+            None => input_store.press_order(self),
+            Some(_) => None,
+        }
+    }
+
+    /// Sends a fake [`TouchInput`] event to the world with [`TouchPhase::Started`],
+    /// at the center of the region if any.
+    ///
+    /// # Note
+    ///
+    /// The `window` field will be filled with a placeholder value,
+    /// and all simulated touches share the same finger.
+    fn press(&self, world: &mut World) {
+        self.send_touch(world, TouchPhase::Started);
+    }
+
+    /// Sends a fake [`TouchInput`] event to the world with [`TouchPhase::Ended`].
+    fn release(&self, world: &mut World) {
+        self.send_touch(world, TouchPhase::Ended);
+    }
+}
+
+impl Eq for ScreenTouch {}
+
+impl Hash for ScreenTouch {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.region.is_some().hash(state);
+        if let Some(region) = self.region {
+            for value in [region.min.x, region.min.y, region.max.x, region.max.y] {
+                FloatOrd(value).hash(state);
+            }
+        }
+    }
+}
+
+/// The position of a single finger touching the screen, stored by [`ScreenTouch`]
+/// so that touches can be matched against the regions of each input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+struct TouchPoint {
+    /// The unique identifier of the finger.
+    id: u64,
+}
+
+impl UserInput for TouchPoint {
+    /// [`TouchPoint`] acts as a dual-axis input.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::DualAxis
+    }
+
+    /// [`TouchPoint`] doesn't involve any buttonlike inputs.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::None
+    }
+
+    /// [`TouchPoint`] is read from the touchscreen.
+    #[inline]
+    fn device_class(&self) -> Option<DeviceClass> {
+        Some(DeviceClass::Touch)
+    }
+}
+
+#[serde_typetag]
+impl DualAxislike for TouchPoint {
+    /// Retrieves the position of the finger, in the logical pixels of the window.
+    #[inline]
+    fn axis_pair(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Vec2 {
+        input_store.pair(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    enum Action {
+        Jump,
+        Pause,
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin))
+            .add_plugins(InputManagerPlugin::<Action>::default())
+            .insert_resource(InputMap::new([
+                (Action::Jump, ScreenTouch::ANYWHERE),
+                (
+                    Action::Pause,
+                    ScreenTouch::in_region(Rect::new(0.0, 0.0, 100.0, 100.0)),
+                ),
+            ]))
+            .init_resource::<ActionState<Action>>();
+        app.update();
+        app
+    }
+
+    #[test]
+    fn tap_fires_action() {
+        let mut app = test_app();
+
+        // A quick tap, starting and ending during the same frame
+        let tap = ScreenTouch::in_region(Rect::new(500.0, 500.0, 600.0, 600.0));
+        tap.press(app.world_mut());
+        tap.release(app.world_mut());
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_pressed(&Action::Jump));
+        assert!(action_state.released(&Action::Pause));
+
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_released(&Action::Jump));
+    }
+
+    #[test]
+    fn touch_and_hold_in_region() {
+        let mut app = test_app();
+
+        let corner = ScreenTouch::in_region(Rect::new(0.0, 0.0, 100.0, 100.0));
+        corner.press(app.world_mut());
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_pressed(&Action::Pause));
+        assert!(action_state.pressed(&Action::Jump));
+
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.pressed(&Action::Pause));
+        assert!(!action_state.just_pressed(&Action::Pause));
+
+        corner.release(app.world_mut());
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_released(&Action::Pause));
+        assert!(action_state.just_released(&Action::Jump));
+    }
+}
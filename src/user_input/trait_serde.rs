@@ -256,6 +256,57 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn test_chord_serde() {
+        use crate::prelude::ButtonlikeChord;
+        use bevy::prelude::KeyCode;
+        use serde_test::{assert_tokens, Token};
+
+        register_input_deserializers();
+
+        let chord = ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]).with_tolerance(3);
+        assert_tokens(
+            &chord,
+            &[
+                Token::Struct {
+                    name: "ButtonlikeChord",
+                    len: 2,
+                },
+                Token::Str("inputs"),
+                Token::Seq { len: Some(2) },
+                Token::Map { len: Some(1) },
+                Token::BorrowedStr("KeyCode"),
+                Token::UnitVariant {
+                    name: "KeyCode",
+                    variant: "ControlLeft",
+                },
+                Token::MapEnd,
+                Token::Map { len: Some(1) },
+                Token::BorrowedStr("KeyCode"),
+                Token::UnitVariant {
+                    name: "KeyCode",
+                    variant: "KeyS",
+                },
+                Token::MapEnd,
+                Token::SeqEnd,
+                Token::Str("tolerance_frames"),
+                Token::U32(3),
+                Token::StructEnd,
+            ],
+        );
+
+        // Non-self-describing formats can store chords too
+        let serialized = bincode::serialize(&chord).unwrap();
+        let deserialized: ButtonlikeChord = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, chord);
+
+        // Chords saved without a tolerance load with none
+        let saved = r#"(inputs:[{"KeyCode":ControlLeft},{"KeyCode":KeyS}])"#;
+        let loaded: ButtonlikeChord = ron::from_str(saved).unwrap();
+        assert_eq!(loaded, chord.with_tolerance(0));
+    }
+
     #[cfg(feature = "mouse")]
     #[test]
     fn test_mouse_button_serde() {
@@ -28,13 +28,51 @@ pub struct CentralInputStore {
     updated_values: HashMap<TypeId, UpdatedValues>,
     /// Tracks the input kinds that have been registered, to avoid redundant system additions.
     registered_input_kinds: HashSet<TypeId>,
+    /// Stores the frame at which each currently pressed [`Buttonlike`] input was pressed.
+    press_frames: HashMap<Box<dyn Buttonlike>, u64>,
+    /// Stores the frame at which each currently released [`Buttonlike`] input was last released.
+    release_frames: HashMap<Box<dyn Buttonlike>, u64>,
+    /// The number of times this store has been cleared, used as a frame counter.
+    frame: u64,
+    /// The duration of the current frame in seconds.
+    delta_secs: f32,
+    /// Axis values whose magnitude is below this are stored as zero.
+    noise_floor: f32,
 }
 
 impl CentralInputStore {
     /// Clears all existing values.
     ///
     /// This should be called once at the start of each frame, before polling for new input.
+    /// The frames at which held [`Buttonlike`] inputs were pressed,
+    /// and at which released ones were last released, are kept,
+    /// so that their press and release order can still be compared.
+    /// Inputs that weren't reported as pressed since the last clear count as released,
+    /// even if their release was never reported.
     pub fn clear(&mut self) {
+        let reported_pressed = |buttonlike: &dyn Buttonlike| {
+            self.updated_values.values().any(|map| match map {
+                UpdatedValues::Buttonlike(buttonlikes) => buttonlikes
+                    .get(buttonlike)
+                    .is_some_and(|value| value.pressed),
+                _ => false,
+            })
+        };
+
+        let mut released = Vec::new();
+        self.press_frames.retain(|buttonlike, _| {
+            let held = reported_pressed(buttonlike.as_ref());
+            if !held {
+                released.push(buttonlike.clone());
+            }
+            held
+        });
+        for buttonlike in released {
+            self.release_frames.insert(buttonlike, self.frame);
+        }
+
+        self.frame += 1;
+
         // Clear the values inside of each map:
         // the base maps can be reused, but the values inside them need to be replaced each frame.
         for map in self.updated_values.values_mut() {
@@ -47,6 +85,62 @@ impl CentralInputStore {
         }
     }
 
+    /// Returns the number of the current frame, as counted by the calls to [`CentralInputStore::clear`].
+    ///
+    /// This can be compared with the results of [`CentralInputStore::press_order`]
+    /// and [`CentralInputStore::release_order`].
+    #[must_use]
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns the duration of the current frame in seconds,
+    /// used to turn values accumulated over the frame into rates.
+    #[must_use]
+    #[inline]
+    pub fn delta_secs(&self) -> f32 {
+        self.delta_secs
+    }
+
+    /// Sets the duration of the current frame in seconds.
+    ///
+    /// This is updated from [`Time<Real>`](bevy::time::Real) each frame by the [`CentralInputStorePlugin`](crate::plugin::CentralInputStorePlugin).
+    #[inline]
+    pub fn set_delta_secs(&mut self, delta_secs: f32) {
+        self.delta_secs = delta_secs;
+    }
+
+    /// Returns the magnitude below which analog gamepad axis values are treated as zero.
+    #[must_use]
+    #[inline]
+    pub fn noise_floor(&self) -> f32 {
+        self.noise_floor
+    }
+
+    /// Sets the magnitude below which analog gamepad axis values are treated as zero,
+    /// filtering out stick drift before any per-action processing.
+    ///
+    /// The floor is applied as gamepad axes are read from the store:
+    /// single axes are compared by their absolute value,
+    /// while both axes of a [`GamepadStick`](crate::prelude::GamepadStick) are zeroed together when the stick's magnitude is below the floor.
+    /// Other inputs, such as mouse motion and touch positions, are left as is.
+    ///
+    /// This is usually configured via [`InputManagerPlugin::global_noise_floor`](crate::plugin::InputManagerPlugin::global_noise_floor).
+    ///
+    /// # Requirements
+    ///
+    /// - `noise_floor` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn set_noise_floor(&mut self, noise_floor: f32) {
+        assert!(noise_floor >= 0.0);
+        self.noise_floor = noise_floor;
+    }
+
     /// Updates the value of a [`Buttonlike`] input.
     pub fn update_buttonlike<B: Buttonlike>(&mut self, buttonlike: B, value: ButtonValue) {
         let updated_values = self
@@ -58,7 +152,17 @@ impl CentralInputStore {
             panic!("Expected Buttonlike, found {:?}", updated_values);
         };
 
-        buttonlikes.insert(Box::new(buttonlike), value);
+        let buttonlike: Box<dyn Buttonlike> = Box::new(buttonlike);
+        if value.pressed {
+            self.release_frames.remove(&buttonlike);
+            self.press_frames
+                .entry(buttonlike.clone())
+                .or_insert(self.frame);
+        } else if self.press_frames.remove(&buttonlike).is_some() {
+            self.release_frames.insert(buttonlike.clone(), self.frame);
+        }
+
+        buttonlikes.insert(buttonlike, value);
     }
 
     /// Updates the value of an [`Axislike`] input.
@@ -123,6 +227,72 @@ impl CentralInputStore {
             .unwrap_or(false)
     }
 
+    /// Returns an iterator over the currently pressed [`Buttonlike`] inputs of type `B`.
+    pub fn pressed_buttonlikes<B: Buttonlike>(&self) -> impl Iterator<Item = &B> {
+        let buttonlikes = self
+            .updated_values
+            .get(&TypeId::of::<B>())
+            .map(|updated_values| {
+                let UpdatedValues::Buttonlike(buttonlikes) = updated_values else {
+                    panic!("Expected Buttonlike, found {:?}", updated_values);
+                };
+                buttonlikes
+            });
+
+        buttonlikes
+            .into_iter()
+            .flatten()
+            .filter(|(_, value)| value.pressed)
+            .filter_map(|(buttonlike, _)| Reflect::as_any(&**buttonlike).downcast_ref::<B>())
+    }
+
+    /// Returns an iterator over the [`DualAxislike`] inputs of type `D` updated this frame,
+    /// alongside their values.
+    pub fn dualaxislikes<D: DualAxislike>(&self) -> impl Iterator<Item = (&D, Vec2)> {
+        let dualaxislikes = self
+            .updated_values
+            .get(&TypeId::of::<D>())
+            .map(|updated_values| {
+                let UpdatedValues::Dualaxislike(dualaxislikes) = updated_values else {
+                    panic!("Expected DualAxislike, found {:?}", updated_values);
+                };
+                dualaxislikes
+            });
+
+        dualaxislikes
+            .into_iter()
+            .flatten()
+            .filter_map(|(dualaxislike, value)| {
+                Reflect::as_any(&**dualaxislike)
+                    .downcast_ref::<D>()
+                    .map(|dualaxislike| (dualaxislike, *value))
+            })
+    }
+
+    /// Returns the frame at which a [`Buttonlike`] input was pressed,
+    /// or `None` if it is not currently pressed.
+    ///
+    /// Frames are counted by the calls to [`CentralInputStore::clear`],
+    /// so the result is only meaningful when compared with that of another input.
+    pub fn press_order<B: Buttonlike + Hash + Eq + Clone>(&self, buttonlike: &B) -> Option<u64> {
+        // PERF: surely there's a way to avoid cloning here
+        let boxed_buttonlike: Box<dyn Buttonlike> = Box::new(buttonlike.clone());
+
+        self.press_frames.get(&boxed_buttonlike).copied()
+    }
+
+    /// Returns the frame at which a [`Buttonlike`] input was last released,
+    /// or `None` if it is currently pressed or has never been released.
+    ///
+    /// Frames are counted by the calls to [`CentralInputStore::clear`],
+    /// and the current one is returned by [`CentralInputStore::frame`].
+    pub fn release_order<B: Buttonlike + Hash + Eq + Clone>(&self, buttonlike: &B) -> Option<u64> {
+        // PERF: surely there's a way to avoid cloning here
+        let boxed_buttonlike: Box<dyn Buttonlike> = Box::new(buttonlike.clone());
+
+        self.release_frames.get(&boxed_buttonlike).copied()
+    }
+
     /// Fetches the value of a [`Buttonlike`] input.
     ///
     /// This should be between 0.0 and 1.0, where 0.0 is not pressed and 1.0 is fully pressed.
@@ -200,6 +370,45 @@ impl CentralInputStore {
             .copied()
             .unwrap_or(Vec3::ZERO)
     }
+
+    /// Returns an iterator over all currently pressed [`Buttonlike`] inputs, of every registered kind.
+    pub fn all_pressed_buttonlikes(&self) -> impl Iterator<Item = &dyn Buttonlike> {
+        self.updated_values
+            .values()
+            .filter_map(|map| match map {
+                UpdatedValues::Buttonlike(buttonlikes) => Some(buttonlikes),
+                _ => None,
+            })
+            .flat_map(|buttonlikes| {
+                buttonlikes
+                    .iter()
+                    .filter(|(_, button)| button.pressed)
+                    .map(|(buttonlike, _)| buttonlike.as_ref())
+            })
+    }
+
+    /// Checks if any input is currently active, such as for "press any key to continue" prompts.
+    ///
+    /// An input is considered active if it is a pressed [`Buttonlike`],
+    /// or an [`Axislike`], [`DualAxislike`] or [`TripleAxislike`] with a non-zero value.
+    ///
+    /// Gamepad axis values have already been filtered by Bevy's [`GamepadSettings`](bevy::input::gamepad::GamepadSettings),
+    /// so that stick noise within their deadzones is not reported here.
+    #[must_use]
+    pub fn any_input_active(&self) -> bool {
+        self.updated_values.values().any(|map| match map {
+            UpdatedValues::Buttonlike(buttonlikes) => {
+                buttonlikes.values().any(|button| button.pressed)
+            }
+            UpdatedValues::Axislike(axislikes) => axislikes.values().any(|value| *value != 0.0),
+            UpdatedValues::Dualaxislike(dualaxislikes) => {
+                dualaxislikes.values().any(|value| *value != Vec2::ZERO)
+            }
+            UpdatedValues::Tripleaxislike(tripleaxislikes) => {
+                tripleaxislikes.values().any(|value| *value != Vec3::ZERO)
+            }
+        })
+    }
 }
 
 /// Trait for registering updatable inputs with the central input store
@@ -252,6 +461,10 @@ pub(crate) fn register_standard_input_kinds(app: &mut App) {
     app.register_input_kind::<bevy::input::mouse::MouseButton>(InputControlKind::Button);
     #[cfg(feature = "gamepad")]
     app.register_input_kind::<bevy::input::gamepad::GamepadButton>(InputControlKind::Button);
+    #[cfg(feature = "touch")]
+    app.register_input_kind::<crate::prelude::ScreenTouch>(InputControlKind::Button);
+    #[cfg(feature = "window")]
+    app.register_input_kind::<crate::prelude::AppEvent>(InputControlKind::Button);
 
     // Axislike
     #[cfg(feature = "gamepad")]
@@ -259,6 +472,8 @@ pub(crate) fn register_standard_input_kinds(app: &mut App) {
 
     // Dualaxislike
     #[cfg(feature = "mouse")]
+    app.init_resource::<crate::prelude::MouseMotionAccumulator>();
+    #[cfg(feature = "mouse")]
     app.register_input_kind::<crate::prelude::MouseMove>(InputControlKind::DualAxis);
     #[cfg(feature = "mouse")]
     app.register_input_kind::<crate::prelude::MouseScroll>(InputControlKind::DualAxis);
@@ -380,4 +595,56 @@ mod tests {
         dbg!(central_input_store);
         assert!(central_input_store.pressed(&MouseButton::Left));
     }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn any_input_active() {
+        use crate::user_input::Buttonlike;
+        use bevy::input::InputPlugin;
+        use bevy::prelude::KeyCode;
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, CentralInputStorePlugin));
+        let any_input_active = |app: &App| {
+            app.world()
+                .resource::<CentralInputStore>()
+                .any_input_active()
+        };
+
+        app.update();
+        assert!(!any_input_active(&app));
+
+        KeyCode::KeyQ.press(app.world_mut());
+        app.update();
+        assert!(any_input_active(&app));
+
+        KeyCode::KeyQ.release(app.world_mut());
+        app.update();
+        assert!(!any_input_active(&app));
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn unreported_inputs_are_released() {
+        use bevy::prelude::KeyCode;
+
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::KeyQ, ButtonValue::from_pressed(true));
+        assert_eq!(input_store.press_order(&KeyCode::KeyQ), Some(0));
+
+        // Held inputs keep the frame at which they were pressed
+        input_store.clear();
+        input_store.update_buttonlike(KeyCode::KeyQ, ButtonValue::from_pressed(true));
+        assert_eq!(input_store.press_order(&KeyCode::KeyQ), Some(0));
+
+        // Inputs that aren't reported during a frame count as released by its end,
+        // so the next press is fresh
+        input_store.clear();
+        input_store.clear();
+        assert_eq!(input_store.press_order(&KeyCode::KeyQ), None);
+        assert_eq!(input_store.release_order(&KeyCode::KeyQ), Some(2));
+
+        input_store.update_buttonlike(KeyCode::KeyQ, ButtonValue::from_pressed(true));
+        assert_eq!(input_store.press_order(&KeyCode::KeyQ), Some(3));
+    }
 }
@@ -1,5 +1,7 @@
 //! This module contains [`VirtualAxis`], [`VirtualDPad`], and [`VirtualDPad3D`].
 
+use std::cmp::Ordering;
+
 use crate as leafwing_input_manager;
 use crate::clashing_inputs::BasicInputs;
 use crate::input_processing::{
@@ -19,6 +21,65 @@ use bevy::prelude::{Entity, Reflect, World};
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
 
+/// The rule used to resolve simultaneous opposite cardinal directions (SOCD),
+/// which happen when the buttons for both directions along an axis are pressed at once.
+///
+/// Resolving by press order relies on [`Buttonlike::press_order`],
+/// which is tracked for keyboard keys, mouse buttons, and gamepad buttons.
+/// If the press order of either button is unknown, or both buttons were pressed during the same frame,
+/// the buttons are resolved as in [`SocdMode::Neutral`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub enum SocdMode {
+    /// The opposite buttons cancel each other out.
+    #[default]
+    Neutral,
+
+    /// The most recently pressed button wins.
+    LastWins,
+
+    /// The earliest pressed button wins, until it is released.
+    FirstWins,
+}
+
+impl SocdMode {
+    /// Computes the value along an axis from its `negative` and `positive` buttons,
+    /// resolving simultaneous presses according to this mode.
+    #[must_use]
+    pub fn resolve(
+        &self,
+        negative: &dyn Buttonlike,
+        positive: &dyn Buttonlike,
+        input_store: &CentralInputStore,
+        gamepad: Entity,
+    ) -> f32 {
+        let negative_value = negative.value(input_store, gamepad);
+        let positive_value = positive.value(input_store, gamepad);
+        let neutral = positive_value - negative_value;
+
+        if *self == Self::Neutral {
+            return neutral;
+        }
+
+        let press_orders = negative
+            .press_order(input_store, gamepad)
+            .zip(positive.press_order(input_store, gamepad));
+        let Some((negative_order, positive_order)) = press_orders else {
+            return neutral;
+        };
+
+        match (self, negative_order.cmp(&positive_order)) {
+            (Self::LastWins, Ordering::Less) | (Self::FirstWins, Ordering::Greater) => {
+                positive_value
+            }
+            (Self::LastWins, Ordering::Greater) | (Self::FirstWins, Ordering::Less) => {
+                -negative_value
+            }
+            _ => neutral,
+        }
+    }
+}
+
 /// A virtual single-axis control constructed from two [`Buttonlike`]s.
 /// One button represents the negative direction (left for the X-axis, down for the Y-axis),
 /// while the other represents the positive direction (right for the X-axis, up for the Y-axis).
@@ -33,6 +94,8 @@ use serde::{Deserialize, Serialize};
 /// - `1.0` if only the positive button is currently pressed.
 /// - `0.0` if neither button is pressed, or both are pressed simultaneously.
 ///
+/// Use [`VirtualAxis::with_socd_mode`] to change how simultaneous presses are resolved.
+///
 /// ```rust
 /// use bevy::prelude::*;
 /// use bevy::input::InputPlugin;
@@ -66,6 +129,10 @@ pub struct VirtualAxis {
 
     /// A processing pipeline that handles input values.
     pub processors: Vec<AxisProcessor>,
+
+    /// The rule used to resolve simultaneous presses of both buttons.
+    #[serde(default)]
+    pub socd_mode: SocdMode,
 }
 
 impl VirtualAxis {
@@ -77,9 +144,17 @@ impl VirtualAxis {
             negative: Box::new(negative),
             positive: Box::new(positive),
             processors: Vec::new(),
+            socd_mode: SocdMode::default(),
         }
     }
 
+    /// Sets the [`SocdMode`] used to resolve simultaneous presses of both buttons.
+    #[inline]
+    pub fn with_socd_mode(mut self, socd_mode: SocdMode) -> Self {
+        self.socd_mode = socd_mode;
+        self
+    }
+
     /// The [`VirtualAxis`] using the vertical arrow key mappings.
     ///
     /// - [`KeyCode::ArrowDown`] for negative direction.
@@ -205,12 +280,12 @@ impl Axislike for VirtualAxis {
     #[must_use]
     #[inline]
     fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
-        let negative = self.negative.value(input_store, gamepad);
-        let positive = self.positive.value(input_store, gamepad);
-        let value = positive - negative;
-        self.processors
-            .iter()
-            .fold(value, |value, processor| processor.process(value))
+        let value = self
+            .socd_mode
+            .resolve(&*self.negative, &*self.positive, input_store, gamepad);
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sets the value of corresponding button based on the given `value`.
@@ -269,6 +344,8 @@ impl WithAxisProcessingPipelineExt for VirtualAxis {
 /// - `1.0` if only the positive button is currently pressed (Up/Right).
 /// - `0.0` if neither button is pressed, or both are pressed simultaneously.
 ///
+/// Use [`VirtualDPad::with_socd_mode`] to change how simultaneous presses are resolved.
+///
 /// ```rust
 /// use bevy::prelude::*;
 /// use bevy::input::InputPlugin;
@@ -308,6 +385,10 @@ pub struct VirtualDPad {
 
     /// A processing pipeline that handles input values.
     pub processors: Vec<DualAxisProcessor>,
+
+    /// The rule used to resolve simultaneous presses of opposite buttons, applied to each axis.
+    #[serde(default)]
+    pub socd_mode: SocdMode,
 }
 
 impl VirtualDPad {
@@ -326,9 +407,17 @@ impl VirtualDPad {
             left: Box::new(left),
             right: Box::new(right),
             processors: Vec::new(),
+            socd_mode: SocdMode::default(),
         }
     }
 
+    /// Sets the [`SocdMode`] used to resolve simultaneous presses of opposite buttons.
+    #[inline]
+    pub fn with_socd_mode(mut self, socd_mode: SocdMode) -> Self {
+        self.socd_mode = socd_mode;
+        self
+    }
+
     /// The [`VirtualDPad`] using the common arrow key mappings.
     ///
     /// - [`KeyCode::ArrowUp`] for upward direction.
@@ -435,14 +524,16 @@ impl DualAxislike for VirtualDPad {
     #[must_use]
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, gamepad: Entity) -> Vec2 {
-        let up = self.up.value(input_store, gamepad);
-        let down = self.down.value(input_store, gamepad);
-        let left = self.left.value(input_store, gamepad);
-        let right = self.right.value(input_store, gamepad);
-        let value = Vec2::new(right - left, up - down);
-        self.processors
-            .iter()
-            .fold(value, |value, processor| processor.process(value))
+        let x = self
+            .socd_mode
+            .resolve(&*self.left, &*self.right, input_store, gamepad);
+        let y = self
+            .socd_mode
+            .resolve(&*self.down, &*self.up, input_store, gamepad);
+        let value = Vec2::new(x, y);
+        self.processors.iter().fold(value, |value, processor| {
+            processor.process_with_delta(value, input_store.delta_secs())
+        })
     }
 
     /// Sets the value of corresponding button on each axis based on the given `value`.
@@ -689,4 +780,75 @@ mod tests {
         assert_eq!(xy.axis_pair(inputs, gamepad), Vec2::new(0.0, 0.0));
         assert_eq!(xyz.axis_triple(inputs, gamepad), Vec3::new(0.0, 0.0, 1.0));
     }
+
+    #[test]
+    fn test_per_second() {
+        let x = VirtualAxis::horizontal_arrow_keys().per_second();
+        let xy = VirtualDPad::arrow_keys().per_second();
+        let gamepad = Entity::PLACEHOLDER;
+
+        let mut app = test_app();
+        KeyCode::ArrowRight.press(app.world_mut());
+        app.update();
+        let mut inputs = app.world_mut().resource_mut::<CentralInputStore>();
+        inputs.set_delta_secs(0.25);
+
+        assert_eq!(x.value(&inputs, gamepad), 4.0);
+        assert_eq!(xy.axis_pair(&inputs, gamepad), Vec2::new(4.0, 0.0));
+    }
+
+    #[test]
+    fn test_socd_modes() {
+        let neutral = VirtualAxis::horizontal_arrow_keys();
+        let last_wins = VirtualAxis::horizontal_arrow_keys().with_socd_mode(SocdMode::LastWins);
+        let first_wins = VirtualAxis::horizontal_arrow_keys().with_socd_mode(SocdMode::FirstWins);
+        let dpad_last_wins = VirtualDPad::arrow_keys().with_socd_mode(SocdMode::LastWins);
+
+        let gamepad = Entity::PLACEHOLDER;
+
+        // Press arrow left, then arrow right on a later frame
+        let mut app = test_app();
+        KeyCode::ArrowLeft.press(app.world_mut());
+        app.update();
+        KeyCode::ArrowRight.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(neutral.value(inputs, gamepad), 0.0);
+        assert_eq!(last_wins.value(inputs, gamepad), 1.0);
+        assert_eq!(first_wins.value(inputs, gamepad), -1.0);
+        assert_eq!(
+            dpad_last_wins.axis_pair(inputs, gamepad),
+            Vec2::new(1.0, 0.0)
+        );
+
+        // Release arrow left, leaving only arrow right pressed
+        KeyCode::ArrowLeft.release(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(neutral.value(inputs, gamepad), 1.0);
+        assert_eq!(last_wins.value(inputs, gamepad), 1.0);
+        assert_eq!(first_wins.value(inputs, gamepad), 1.0);
+
+        // Press arrow left again, which is now the most recent press
+        KeyCode::ArrowLeft.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(neutral.value(inputs, gamepad), 0.0);
+        assert_eq!(last_wins.value(inputs, gamepad), -1.0);
+        assert_eq!(first_wins.value(inputs, gamepad), 1.0);
+
+        // Buttons pressed during the same frame cancel each other out in every mode
+        let mut app = test_app();
+        KeyCode::ArrowLeft.press(app.world_mut());
+        KeyCode::ArrowRight.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(neutral.value(inputs, gamepad), 0.0);
+        assert_eq!(last_wins.value(inputs, gamepad), 0.0);
+        assert_eq!(first_wins.value(inputs, gamepad), 0.0);
+    }
 }
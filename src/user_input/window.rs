@@ -0,0 +1,218 @@
+//! Window inputs
+
+use bevy::ecs::event::EventCursor;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::StaticSystemParam;
+use bevy::prelude::{Entity, Events, Local, Reflect, ResMut, World};
+use bevy::utils::HashSet;
+use bevy::window::{WindowFocused, WindowResized};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::buttonlike::ButtonValue;
+use crate::clashing_inputs::BasicInputs;
+use crate::user_input::UserInput;
+use crate::InputControlKind;
+
+use super::updating::{CentralInputStore, UpdatableInput};
+use super::Buttonlike;
+
+/// Events of the app's windows that can be bound to buttonlike actions,
+/// such as to pause the game when the window loses focus.
+///
+/// Each event presses the input for a single frame:
+/// actions bound to it are [`just_pressed`](crate::action_state::ActionState::just_pressed)
+/// when the event is received, and released on the next frame.
+///
+/// These are read from the [`WindowFocused`] and [`WindowResized`] events of all windows,
+/// and are never pressed if the [`WindowPlugin`](bevy::window::WindowPlugin) isn't added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub enum AppEvent {
+    /// A window lost focus, typically because the player switched to another app.
+    FocusLost,
+
+    /// A window gained focus.
+    FocusGained,
+
+    /// A window was resized.
+    Resized,
+}
+
+impl UserInput for AppEvent {
+    /// [`AppEvent`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns a [`BasicInputs`] that only contains the [`AppEvent`] itself,
+    /// as it represents a simple button.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(*self))
+    }
+}
+
+/// The state kept by [`AppEvent`] between frames:
+/// how far each kind of window event has been read, and which events were pressed on the last frame.
+#[derive(Default)]
+pub struct AppEventCursors {
+    focused: EventCursor<WindowFocused>,
+    resized: EventCursor<WindowResized>,
+    pressed: HashSet<AppEvent>,
+}
+
+impl UpdatableInput for AppEvent {
+    type SourceData = (
+        Option<SRes<Events<WindowFocused>>>,
+        Option<SRes<Events<WindowResized>>>,
+        Local<'static, AppEventCursors>,
+    );
+
+    fn compute(
+        mut central_input_store: ResMut<CentralInputStore>,
+        source_data: StaticSystemParam<Self::SourceData>,
+    ) {
+        let (focused_events, resized_events, mut cursors) = source_data.into_inner();
+        let cursors = &mut *cursors;
+
+        let mut pressed = HashSet::new();
+        if let Some(focused_events) = focused_events {
+            for event in cursors.focused.read(&focused_events) {
+                pressed.insert(if event.focused {
+                    AppEvent::FocusGained
+                } else {
+                    AppEvent::FocusLost
+                });
+            }
+        }
+
+        if let Some(resized_events) = resized_events {
+            if cursors.resized.read(&resized_events).next().is_some() {
+                pressed.insert(AppEvent::Resized);
+            }
+        }
+
+        for event in cursors.pressed.difference(&pressed) {
+            central_input_store.update_buttonlike(*event, ButtonValue::from_pressed(false));
+        }
+
+        for event in pressed.iter() {
+            central_input_store.update_buttonlike(*event, ButtonValue::from_pressed(true));
+        }
+
+        cursors.pressed = pressed;
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for AppEvent {
+    /// Checks if the specified event was received this frame.
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, _gamepad: Entity) -> bool {
+        input_store.pressed(self)
+    }
+
+    /// Returns the frame at which the specified event was received.
+    #[inline]
+    fn press_order(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Option<u64> {
+        input_store.press_order(self)
+    }
+
+    /// Sends a fake window event to the world.
+    ///
+    /// # Note
+    ///
+    /// The `window` field will be filled with a placeholder value,
+    /// and resized windows are reported with a size of zero.
+    fn press(&self, world: &mut World) {
+        let window = Entity::PLACEHOLDER;
+        match self {
+            Self::FocusLost | Self::FocusGained => {
+                let mut events = world.resource_mut::<Events<WindowFocused>>();
+                events.send(WindowFocused {
+                    window,
+                    focused: *self == Self::FocusGained,
+                });
+            }
+            Self::Resized => {
+                let mut events = world.resource_mut::<Events<WindowResized>>();
+                events.send(WindowResized {
+                    window,
+                    width: 0.0,
+                    height: 0.0,
+                });
+            }
+        }
+    }
+
+    /// Does nothing, as window events are released automatically on the next frame.
+    fn release(&self, _world: &mut World) {}
+
+    /// If the value is greater than `0.0`, send the event; otherwise do nothing.
+    fn set_value(&self, world: &mut World, value: f32) {
+        if value > 0.0 {
+            self.press(world);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::CentralInputStorePlugin;
+    use crate::prelude::*;
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    enum Action {
+        Pause,
+    }
+
+    #[test]
+    fn focus_lost_fires_action() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin))
+            .add_event::<WindowFocused>()
+            .add_event::<WindowResized>()
+            .add_plugins(InputManagerPlugin::<Action>::default())
+            .insert_resource(InputMap::new([(Action::Pause, AppEvent::FocusLost)]))
+            .init_resource::<ActionState<Action>>();
+        app.update();
+
+        // Gaining focus doesn't pause
+        AppEvent::FocusGained.press(app.world_mut());
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.released(&Action::Pause));
+
+        // Losing focus fires the action once
+        AppEvent::FocusLost.press(app.world_mut());
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_pressed(&Action::Pause));
+
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_released(&Action::Pause));
+
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.released(&Action::Pause));
+        assert!(!action_state.just_released(&Action::Pause));
+    }
+
+    #[test]
+    fn window_events_are_optional() {
+        // Without any window events, the inputs are simply never pressed
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, CentralInputStorePlugin));
+        app.update();
+
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!AppEvent::FocusLost.pressed(inputs, Entity::PLACEHOLDER));
+    }
+}
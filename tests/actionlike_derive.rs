@@ -39,3 +39,14 @@ struct StructAction {
 
 #[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
 struct TupleAction(usize, usize);
+
+#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
+enum DatafulControlAction {
+    Jump,
+    #[actionlike(Axis)]
+    Throttle(u8),
+    #[actionlike(DualAxis)]
+    Aim {
+        stick: u8,
+    },
+}
@@ -13,6 +13,8 @@ use updating::CentralInputStore;
 #[derive(Actionlike, Debug, PartialEq, Eq, Clone, Copy, Hash, Reflect)]
 enum TestAction {
     Throttle,
+    Confirm,
+    Save,
 }
 
 fn test_app() -> App {
@@ -158,6 +160,105 @@ fn gamepad_trigger() {
     assert_eq!(button_value, 0.7);
 }
 
+#[test]
+fn any_gamepad_button() {
+    let mut app = test_app();
+    app.world_mut()
+        .resource_mut::<InputMap<TestAction>>()
+        .insert(TestAction::Confirm, AnyGamepadButton);
+
+    GamepadButton::North.press(app.world_mut());
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.just_pressed(&TestAction::Confirm));
+    assert!(!action_state.pressed(&TestAction::Throttle));
+
+    // The pressed button can be found from the central input store
+    let gamepad = app
+        .world_mut()
+        .query_filtered::<Entity, With<Gamepad>>()
+        .single(app.world());
+    let input_store = app.world().resource::<CentralInputStore>();
+    let pressed_buttons: Vec<_> = AnyGamepadButton::pressed_buttons(input_store, gamepad).collect();
+    assert_eq!(pressed_buttons, [GamepadButton::North]);
+
+    AnyGamepadButton.release(app.world_mut());
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.just_released(&TestAction::Confirm));
+}
+
+#[test]
+fn triggering_binding_index() {
+    let mut app = test_app();
+    app.world_mut()
+        .resource_mut::<InputMap<TestAction>>()
+        .insert(TestAction::Save, KeyCode::F5)
+        .insert(TestAction::Save, KeyCode::KeyS);
+
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert_eq!(
+        action_state.triggering_binding_index(&TestAction::Save),
+        None
+    );
+
+    // Pressing the secondary binding
+    KeyCode::KeyS.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.pressed(&TestAction::Save));
+    assert_eq!(
+        action_state.triggering_binding_index(&TestAction::Save),
+        Some(1)
+    );
+
+    // The primary binding takes precedence when both are held
+    KeyCode::F5.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert_eq!(
+        action_state.triggering_binding_index(&TestAction::Save),
+        Some(0)
+    );
+
+    KeyCode::F5.release(app.world_mut());
+    KeyCode::KeyS.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert_eq!(
+        action_state.triggering_binding_index(&TestAction::Save),
+        None
+    );
+}
+
+#[test]
+fn insert_chord() {
+    let mut app = test_app();
+    app.world_mut()
+        .resource_mut::<InputMap<TestAction>>()
+        .insert_chord(TestAction::Save, [KeyCode::ControlLeft, KeyCode::KeyS]);
+
+    // A single key of the chord is not enough
+    KeyCode::KeyS.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.released(&TestAction::Save));
+
+    // Holding both keys fires the action
+    KeyCode::ControlLeft.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.just_pressed(&TestAction::Save));
+
+    // Letting go of either key releases it
+    KeyCode::KeyS.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<TestAction>>();
+    assert!(action_state.just_released(&TestAction::Save));
+}
+
 #[test]
 fn buttonlike_actions_can_be_pressed_and_released_when_pressed() {
     let mut app = test_app();
@@ -205,3 +205,93 @@ fn action_order_clash_handling() {
     app.assert_input_map_actions_eq(ClashStrategy::PressAll, [Two, TwoAndThree]);
     app.assert_input_map_actions_eq(ClashStrategy::PrioritizeLongest, [TwoAndThree]);
 }
+
+#[test]
+fn per_map_clash_strategy() {
+    use Action::*;
+    use KeyCode::*;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default());
+
+    let input_map = InputMap::new([(One, Digit1), (Two, Digit2)])
+        .with(OneAndTwo, ButtonlikeChord::new([Digit1, Digit2]));
+
+    let global = app
+        .world_mut()
+        .spawn((input_map.clone(), ActionState::<Action>::default()))
+        .id();
+    let unresolved = app
+        .world_mut()
+        .spawn((
+            input_map.with_clash_strategy(Some(ClashStrategy::PressAll)),
+            ActionState::<Action>::default(),
+        ))
+        .id();
+
+    Digit1.press(app.world_mut());
+    Digit2.press(app.world_mut());
+    app.update();
+
+    // The global `ClashStrategy` resource only presses the longest chord
+    let action_state = app.world().get::<ActionState<Action>>(global).unwrap();
+    assert!(action_state.pressed(&OneAndTwo));
+    assert!(!action_state.pressed(&One));
+    assert!(!action_state.pressed(&Two));
+
+    // With clash resolution disabled, every matching action is pressed
+    let action_state = app.world().get::<ActionState<Action>>(unresolved).unwrap();
+    assert!(action_state.pressed(&OneAndTwo));
+    assert!(action_state.pressed(&One));
+    assert!(action_state.pressed(&Two));
+
+    // Removing the override falls back to the global `ClashStrategy` resource
+    let mut input_map = app
+        .world_mut()
+        .get_mut::<InputMap<Action>>(unresolved)
+        .unwrap();
+    input_map.set_clash_strategy(None);
+    assert_eq!(input_map.clash_strategy(), None);
+    Digit1.release(app.world_mut());
+    Digit2.release(app.world_mut());
+    app.update();
+    Digit1.press(app.world_mut());
+    Digit2.press(app.world_mut());
+    app.update();
+    let action_state = app.world().get::<ActionState<Action>>(unresolved).unwrap();
+    assert!(action_state.pressed(&OneAndTwo));
+    assert!(!action_state.pressed(&One));
+    assert!(!action_state.pressed(&Two));
+}
+
+#[test]
+fn exclusive_group() {
+    use Action::*;
+    use KeyCode::*;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default());
+
+    let input_map = InputMap::new([(One, Digit1), (Two, Digit2)]).with_exclusive_group(&[Two, One]);
+    let entity = app
+        .world_mut()
+        .spawn((input_map, ActionState::<Action>::default()))
+        .id();
+
+    // Alone, each action of the group is pressed as usual
+    Digit1.press(app.world_mut());
+    app.update();
+    let action_state = app.world().get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.pressed(&One));
+
+    // When both fire, only the first declared action of the group stays pressed
+    Digit2.press(app.world_mut());
+    app.update();
+    let action_state = app.world().get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.pressed(&Two));
+    assert!(!action_state.pressed(&One));
+}
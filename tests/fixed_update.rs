@@ -30,10 +30,22 @@ struct FixedUpdateCounter {
 }
 
 fn build_app(fixed_timestep: Duration, frame_timestep: Duration) -> App {
+    build_app_with_plugin(
+        InputManagerPlugin::<TestAction>::default(),
+        fixed_timestep,
+        frame_timestep,
+    )
+}
+
+fn build_app_with_plugin(
+    plugin: InputManagerPlugin<TestAction>,
+    fixed_timestep: Duration,
+    frame_timestep: Duration,
+) -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins)
         .add_plugins(InputPlugin)
-        .add_plugins(InputManagerPlugin::<TestAction>::default())
+        .add_plugins(plugin)
         .init_resource::<UpdateCounter>()
         .init_resource::<FixedUpdateCounter>()
         .init_resource::<ActionState<TestAction>>()
@@ -205,3 +217,64 @@ fn frame_with_two_fixed_timestep() {
         Duration::from_millis(18)
     );
 }
+
+/// Fixed steps run both faster and slower than frames, while the button is repeatedly pressed and released
+///
+/// Each press should be reported as `just_pressed` exactly once in each schedule
+#[test]
+fn just_pressed_once_per_press() {
+    for (fixed_timestep, frame_timestep) in [(4, 9), (10, 9), (9, 9)] {
+        let mut app = build_app(
+            Duration::from_millis(fixed_timestep),
+            Duration::from_millis(frame_timestep),
+        );
+
+        let mut fixed_update_just_pressed = 0;
+        let mut update_just_pressed = 0;
+        for press in 0..3 {
+            KeyCode::ArrowUp.press(app.world_mut());
+            // Hold the button long enough for at least one fixed step to run
+            for _ in 0..3 {
+                app.update();
+                fixed_update_just_pressed +=
+                    app.world().resource::<FixedUpdateCounter>().just_pressed;
+                update_just_pressed += app.world().resource::<UpdateCounter>().just_pressed;
+                reset_counters(&mut app);
+            }
+
+            assert_eq!(fixed_update_just_pressed, press + 1);
+            assert_eq!(update_just_pressed, press + 1);
+
+            KeyCode::ArrowUp.release(app.world_mut());
+            for _ in 0..3 {
+                app.update();
+                reset_counters(&mut app);
+            }
+        }
+    }
+}
+
+/// Without the separate fixed-update state, the FixedUpdate schedule observes the Update state as is (F1 - FU1 - FU2 - F2)
+///
+/// A button pressed in F1 is then `just_pressed` in both FU1 and FU2
+#[test]
+fn without_fixed_update_tracking() {
+    let mut app = build_app_with_plugin(
+        InputManagerPlugin::<TestAction>::default().track_fixed_update(false),
+        Duration::from_millis(4),
+        Duration::from_millis(9),
+    );
+
+    KeyCode::ArrowUp.press(app.world_mut());
+
+    app.update();
+    check_update_just_pressed_count(&mut app, 1);
+    check_fixed_update_run_count(&mut app, 2);
+    check_fixed_update_just_pressed_count(&mut app, 2);
+    reset_counters(&mut app);
+
+    app.update();
+    check_update_just_pressed_count(&mut app, 0);
+    check_fixed_update_run_count(&mut app, 2);
+    check_fixed_update_just_pressed_count(&mut app, 0);
+}
@@ -3,10 +3,13 @@
 use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent, RawGamepadEvent};
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
+use leafwing_input_manager::axislike::CombinePolicy;
+use leafwing_input_manager::buttonlike::EdgeMode;
 use leafwing_input_manager::input_processing::{
     WithAxisProcessingPipelineExt, WithDualAxisProcessingPipelineExt,
 };
 use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
 enum ButtonlikeTestAction {
@@ -16,7 +19,7 @@ enum ButtonlikeTestAction {
     Right,
 }
 
-#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[actionlike(Axis)]
 enum AxislikeTestAction {
     X,
@@ -26,11 +29,15 @@ enum AxislikeTestAction {
 }
 
 fn test_app() -> App {
+    test_app_with(InputManagerPlugin::<AxislikeTestAction>::default())
+}
+
+fn test_app_with(axislike_plugin: InputManagerPlugin<AxislikeTestAction>) -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins)
         .add_plugins(InputPlugin)
         .add_plugins(InputManagerPlugin::<ButtonlikeTestAction>::default())
-        .add_plugins(InputManagerPlugin::<AxislikeTestAction>::default())
+        .add_plugins(axislike_plugin)
         .init_resource::<ActionState<ButtonlikeTestAction>>()
         .init_resource::<ActionState<AxislikeTestAction>>();
 
@@ -135,6 +142,143 @@ fn gamepad_single_axis() {
     assert_eq!(action_state.value(&AxislikeTestAction::X), 0.11111112);
 }
 
+#[test]
+fn gamepad_axis_is_analog_only() {
+    let mut app = test_app();
+    app.insert_resource(
+        InputMap::default().with_axis(AxislikeTestAction::X, GamepadControlAxis::LEFT_X),
+    );
+    app.insert_resource(InputMap::default().with(
+        ButtonlikeTestAction::Right,
+        GamepadControlDirection::positive(GamepadAxis::LeftStickX).threshold(0.5),
+    ));
+
+    // Steering tracks the raw value of the stick continuously, with no threshold
+    for value in [0.1, 0.3, 0.7, 1.0, 0.4, -0.6] {
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), value);
+        app.update();
+
+        let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+        assert_eq!(action_state.value(&AxislikeTestAction::X), value);
+        assert!(action_state.button_data(&AxislikeTestAction::X).is_none());
+        assert!(action_state.get_pressed().is_empty());
+
+        // Button behavior only comes from inputs with a threshold, which don't clash with the analog binding
+        let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+        assert_eq!(
+            action_state.pressed(&ButtonlikeTestAction::Right),
+            value > 0.5
+        );
+    }
+}
+
+#[test]
+fn gamepad_split_axis() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::default().with_split_axis(
+        ButtonlikeTestAction::Left,
+        ButtonlikeTestAction::Right,
+        GamepadAxis::LeftStickX,
+        0.2,
+    ));
+
+    // Within the threshold, neither half is pressed
+    GamepadControlAxis::LEFT_X.set_value(app.world_mut(), -0.1);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.get_pressed().is_empty());
+
+    // Past the threshold on the negative side, only the negative action is pressed
+    GamepadControlAxis::LEFT_X.set_value(app.world_mut(), -0.8);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert_eq!(action_state.get_pressed(), vec![ButtonlikeTestAction::Left]);
+
+    // And vice versa
+    GamepadControlAxis::LEFT_X.set_value(app.world_mut(), 0.8);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert_eq!(
+        action_state.get_pressed(),
+        vec![ButtonlikeTestAction::Right]
+    );
+}
+
+#[test]
+fn gamepad_axis_threshold_per_action() {
+    let mut app = test_app();
+    app.insert_resource(
+        InputMap::default()
+            .with(
+                ButtonlikeTestAction::Up,
+                GamepadControlDirection::positive(GamepadAxis::LeftStickY).threshold(0.6),
+            )
+            .with(
+                ButtonlikeTestAction::Down,
+                GamepadControlDirection::negative(GamepadAxis::LeftStickY).threshold(0.6),
+            ),
+    );
+
+    // A small push doesn't reach the original threshold
+    GamepadControlAxis::LEFT_Y.set_value(app.world_mut(), 0.4);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(&ButtonlikeTestAction::Up));
+
+    // Lowering the threshold makes the same push fire the action
+    let mut input_map = app
+        .world_mut()
+        .resource_mut::<InputMap<ButtonlikeTestAction>>();
+    assert_eq!(
+        input_map.set_axis_threshold(&ButtonlikeTestAction::Up, 0.3, 0.3),
+        1
+    );
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(&ButtonlikeTestAction::Up));
+
+    // Other actions keep their own thresholds
+    GamepadControlAxis::LEFT_Y.set_value(app.world_mut(), -0.4);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(&ButtonlikeTestAction::Down));
+}
+
+#[test]
+fn gamepad_axis_edge_modes() {
+    let mut app = test_app();
+    let input = GamepadControlDirection::positive(GamepadAxis::LeftStickY).threshold(0.5);
+    app.insert_resource(
+        InputMap::default()
+            .with(ButtonlikeTestAction::Up, input)
+            .with(ButtonlikeTestAction::Left, input)
+            .with(ButtonlikeTestAction::Right, input)
+            .with_edge_mode(ButtonlikeTestAction::Left, EdgeMode::RisingEdge)
+            .with_edge_mode(ButtonlikeTestAction::Right, EdgeMode::BothEdges),
+    );
+
+    // Ramp the stick up past the threshold and back down again
+    let ramp = [0.0, 0.25, 0.75, 1.0, 0.75, 0.25, 0.0];
+    let mut level = Vec::new();
+    let mut rising_edge = Vec::new();
+    let mut both_edges = Vec::new();
+    for value in ramp {
+        GamepadControlAxis::LEFT_Y.set_value(app.world_mut(), value);
+        app.update();
+        let action_state = app.world().resource::<ActionState<ButtonlikeTestAction>>();
+        level.push(action_state.pressed(&ButtonlikeTestAction::Up));
+        rising_edge.push(action_state.pressed(&ButtonlikeTestAction::Left));
+        both_edges.push(action_state.pressed(&ButtonlikeTestAction::Right));
+    }
+
+    assert_eq!(level, [false, false, true, true, true, false, false]);
+    assert_eq!(
+        rising_edge,
+        [false, false, true, false, false, false, false]
+    );
+    assert_eq!(both_edges, [false, false, true, false, false, true, false]);
+}
+
 #[test]
 fn gamepad_single_axis_inverted() {
     let mut app = test_app();
@@ -183,6 +327,36 @@ fn gamepad_single_axis_inverted() {
     assert_eq!(action_state.value(&AxislikeTestAction::Y), 1.0);
 }
 
+#[test]
+fn gamepad_dual_axis_asymmetric_bounds() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::default().with_dual_axis(
+        AxislikeTestAction::XY,
+        GamepadStick::LEFT.with_bounds_xy((-0.5, 0.5), (-1.0, 0.25)),
+    ));
+
+    // Each axis is clamped to its own range
+    let input = GamepadStick::LEFT;
+    input.set_axis_pair(app.world_mut(), Vec2::new(1.0, 1.0));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.5, 0.25)
+    );
+
+    let input = GamepadStick::LEFT;
+    input.set_axis_pair(app.world_mut(), Vec2::new(-1.0, -1.0));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(-0.5, -1.0)
+    );
+}
+
 #[test]
 fn gamepad_dual_axis_deadzone() {
     let mut app = test_app();
@@ -225,6 +399,99 @@ fn gamepad_dual_axis_deadzone() {
     );
 }
 
+#[test]
+#[cfg(feature = "keyboard")]
+fn dual_axis_combine_policies() {
+    let axis_pair_with = |policy: CombinePolicy, steps: &[(Vec2, bool)]| {
+        let mut app = test_app();
+        app.insert_resource(
+            InputMap::default()
+                .with_dual_axis(AxislikeTestAction::XY, GamepadStick::LEFT)
+                .with_dual_axis(AxislikeTestAction::XY, VirtualDPad::wasd())
+                .with_axis_combine(AxislikeTestAction::XY, policy),
+        );
+
+        let mut results = Vec::new();
+        for &(stick, right_held) in steps {
+            GamepadStick::LEFT.set_axis_pair(app.world_mut(), stick);
+            if right_held {
+                KeyCode::KeyD.press(app.world_mut());
+            } else {
+                KeyCode::KeyD.release(app.world_mut());
+            }
+            app.update();
+
+            let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+            results.push(action_state.axis_pair(&AxislikeTestAction::XY));
+        }
+        results
+    };
+
+    // The stick is pushed up, then the virtual D-pad right while the stick is held
+    let both = [(Vec2::new(0.0, 0.6), true)];
+    assert_eq!(
+        axis_pair_with(CombinePolicy::Sum, &both),
+        [Vec2::new(1.0, 0.6)]
+    );
+    assert_eq!(
+        axis_pair_with(CombinePolicy::MaxMagnitude, &both),
+        [Vec2::new(1.0, 0.0)]
+    );
+    let clamped = axis_pair_with(CombinePolicy::SumAndClamp, &both)[0];
+    assert!((clamped - Vec2::new(1.0, 0.6).normalize()).length() < 0.0001);
+
+    let steps = [
+        (Vec2::new(0.0, 0.6), false),
+        (Vec2::new(0.0, 0.6), true),
+        (Vec2::new(0.0, 0.6), false),
+        (Vec2::ZERO, false),
+    ];
+    assert_eq!(
+        axis_pair_with(CombinePolicy::LastActiveWins, &steps),
+        [
+            Vec2::new(0.0, 0.6),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 0.6),
+            Vec2::ZERO,
+        ]
+    );
+}
+
+#[test]
+fn global_noise_floor() {
+    let mut app =
+        test_app_with(InputManagerPlugin::<AxislikeTestAction>::default().global_noise_floor(0.1));
+    app.insert_resource(
+        InputMap::default()
+            .with_axis(AxislikeTestAction::X, GamepadControlAxis::RIGHT_X)
+            .with_dual_axis(AxislikeTestAction::XY, GamepadStick::LEFT),
+    );
+
+    // Drift below the noise floor is ignored
+    GamepadControlAxis::RIGHT_X.set_value(app.world_mut(), 0.05);
+    GamepadStick::LEFT.set_axis_pair(app.world_mut(), Vec2::new(0.05, -0.07));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), 0.0);
+    assert_eq!(action_state.axis_pair(&AxislikeTestAction::XY), Vec2::ZERO);
+    assert!(!action_state.axis_just_activated(&AxislikeTestAction::XY));
+
+    // Movements above the noise floor pass through,
+    // including the small component of a stick whose magnitude is above the floor
+    GamepadControlAxis::RIGHT_X.set_value(app.world_mut(), 0.3);
+    GamepadStick::LEFT.set_axis_pair(app.world_mut(), Vec2::new(0.5, -0.08));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), 0.3);
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.5, -0.08)
+    );
+    assert!(action_state.axis_just_activated(&AxislikeTestAction::XY));
+}
+
 #[test]
 fn gamepad_circle_deadzone() {
     let mut app = test_app();
@@ -256,6 +523,39 @@ fn gamepad_circle_deadzone() {
     );
 }
 
+#[test]
+fn gamepad_circle_deadzone_from_builder() {
+    let mut app = test_app();
+    app.insert_resource(
+        InputMap::builder()
+            .bind_dual_axis(AxislikeTestAction::XY, GamepadStick::LEFT)
+            .with_processor(CircleDeadZone::new(0.1))
+            .build(),
+    );
+
+    // Test that an input inside the circle deadzone is filtered out, assuming values of 0.1
+    let input = GamepadStick::LEFT;
+    input.set_axis_pair(app.world_mut(), Vec2::new(0.06, 0.06));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.0, 0.0)
+    );
+
+    // Test that an input outside the circle deadzone is not filtered out, assuming values of 0.1
+    let input = GamepadStick::LEFT;
+    input.set_axis_pair(app.world_mut(), Vec2::new(0.2, 0.0));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.11111112, 0.0)
+    );
+}
+
 #[test]
 fn test_zero_dual_axis_deadzone() {
     let mut app = test_app();
@@ -316,3 +616,69 @@ fn gamepad_virtual_dpad() {
         Vec2::new(-1.0, 0.0)
     );
 }
+
+#[test]
+#[cfg(feature = "keyboard")]
+fn mock_input_batch() {
+    use leafwing_input_manager::user_input::testing_utils::{FetchUserInput, MockInputBatch};
+
+    let mut app = test_app();
+
+    MockInputBatch::new()
+        .press(KeyCode::Space)
+        .set_axis_pair(GamepadStick::LEFT, Vec2::new(0.0, 1.0))
+        .press(GamepadButton::South)
+        .send(app.world_mut());
+    app.update();
+
+    // All of the inputs are registered in the same frame
+    assert!(app.read_pressed(KeyCode::Space));
+    assert_eq!(
+        app.read_dual_axis_values(GamepadStick::LEFT),
+        Vec2::new(0.0, 1.0)
+    );
+    assert!(app.read_pressed(GamepadButton::South));
+}
+
+#[test]
+#[cfg(feature = "asset")]
+fn processors_round_trip_through_serialization() {
+    use bevy::asset::ron;
+
+    let mut app = test_app();
+    let input_map = InputMap::default()
+        .with_axis(
+            AxislikeTestAction::X,
+            GamepadControlAxis::LEFT_X
+                .with_deadzone_symmetric(0.1)
+                .sensitivity(2.0),
+        )
+        .with_dual_axis(
+            AxislikeTestAction::XY,
+            GamepadStick::LEFT
+                .with_circle_deadzone(0.1)
+                .sensitivity(2.0)
+                .inverted_y(),
+        );
+
+    let serialized = ron::to_string(&input_map).unwrap();
+    let deserialized: InputMap<AxislikeTestAction> = ron::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, input_map);
+
+    // Both maps should process the same raw input identically
+    let mut processed = Vec::new();
+    for map in [input_map, deserialized] {
+        app.insert_resource(map);
+        GamepadStick::LEFT.set_axis_pair(app.world_mut(), Vec2::new(0.5, 0.3));
+        app.update();
+
+        let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+        processed.push((
+            action_state.value(&AxislikeTestAction::X),
+            action_state.axis_pair(&AxislikeTestAction::XY),
+        ));
+    }
+
+    assert_ne!(processed[0].1, Vec2::new(0.5, 0.3));
+    assert_eq!(processed[0], processed[1]);
+}
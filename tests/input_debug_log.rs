@@ -0,0 +1,191 @@
+#![cfg(all(feature = "debug_log", feature = "keyboard"))]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::input_debug_log::{InputDebugEntry, InputDebugLog};
+use leafwing_input_manager::plugin::InputDelay;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+enum Action {
+    Jump,
+    Save,
+    Type,
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(
+            InputMap::new([
+                (Action::Jump, KeyCode::Space),
+                (Action::Type, KeyCode::KeyS),
+            ])
+            .with(
+                Action::Save,
+                ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]),
+            ),
+        );
+    app
+}
+
+fn entry(
+    action: Action,
+    input: Option<Box<dyn Buttonlike>>,
+    pressed: bool,
+    suppressed_by_clash: bool,
+) -> InputDebugEntry<Action> {
+    InputDebugEntry {
+        entity: None,
+        action,
+        input,
+        pressed,
+        suppressed_by_clash,
+    }
+}
+
+#[test]
+fn records_presses_and_releases() {
+    let mut app = test_app();
+    app.update();
+    assert!(app.world().resource::<InputDebugLog<Action>>().is_empty());
+
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    assert_eq!(log.len(), 1);
+    assert_eq!(
+        log.latest(),
+        Some(&entry(
+            Action::Jump,
+            Some(Box::new(KeyCode::Space)),
+            true,
+            false
+        ))
+    );
+
+    // Holding the key doesn't record anything new
+    app.update();
+    assert_eq!(app.world().resource::<InputDebugLog<Action>>().len(), 1);
+
+    KeyCode::Space.release(app.world_mut());
+    app.update();
+
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.latest(), Some(&entry(Action::Jump, None, false, false)));
+}
+
+#[test]
+fn records_clash_suppression() {
+    let mut app = test_app();
+
+    KeyCode::ControlLeft.press(app.world_mut());
+    KeyCode::KeyS.press(app.world_mut());
+    app.update();
+
+    // The larger chord wins the clash, suppressing the single key
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    let entries: Vec<_> = log.iter().cloned().collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.contains(&entry(
+        Action::Save,
+        Some(Box::new(ButtonlikeChord::new([
+            KeyCode::ControlLeft,
+            KeyCode::KeyS
+        ]))),
+        true,
+        false
+    )));
+    assert!(entries.contains(&entry(
+        Action::Type,
+        Some(Box::new(KeyCode::KeyS)),
+        false,
+        true
+    )));
+}
+
+#[test]
+fn discards_oldest_entries() {
+    let mut app = test_app();
+    app.insert_resource(InputDebugLog::<Action>::new(2));
+
+    for _ in 0..3 {
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        KeyCode::Space.release(app.world_mut());
+        app.update();
+    }
+
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    assert_eq!(log.capacity(), 2);
+    assert_eq!(log.len(), 2);
+    let pressed: Vec<bool> = log.iter().map(|entry| entry.pressed).collect();
+    assert_eq!(pressed, [true, false]);
+}
+
+#[test]
+fn exclusive_groups_and_inversion_are_not_clashes() {
+    let mut app = test_app();
+    app.insert_resource(
+        InputMap::new([
+            (Action::Jump, KeyCode::Space),
+            (Action::Type, KeyCode::KeyT),
+        ])
+        .with_exclusive_group(&[Action::Jump, Action::Type])
+        .with(Action::Save, KeyCode::F5)
+        .with_inverted(Action::Save, true),
+    );
+
+    KeyCode::Space.press(app.world_mut());
+    KeyCode::KeyT.press(app.world_mut());
+    app.update();
+
+    // The inverted action is pressed by none of its bindings,
+    // and the lower-priority action of the group is released without any clash
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    let entries: Vec<_> = log.iter().cloned().collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.contains(&entry(
+        Action::Jump,
+        Some(Box::new(KeyCode::Space)),
+        true,
+        false
+    )));
+    assert!(entries.contains(&entry(Action::Save, None, true, false)));
+}
+
+#[test]
+fn records_delayed_decisions_when_applied() {
+    let mut app = test_app();
+    app.insert_resource(InputDelay::<Action>::new(1));
+
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert!(!app
+        .world()
+        .resource::<ActionState<Action>>()
+        .pressed(&Action::Jump));
+    assert!(app.world().resource::<InputDebugLog<Action>>().is_empty());
+
+    // The press is logged on the frame it reaches the ActionState
+    app.update();
+    assert!(app
+        .world()
+        .resource::<ActionState<Action>>()
+        .pressed(&Action::Jump));
+    let log = app.world().resource::<InputDebugLog<Action>>();
+    assert_eq!(
+        log.latest(),
+        Some(&entry(
+            Action::Jump,
+            Some(Box::new(KeyCode::Space)),
+            true,
+            false
+        ))
+    );
+}
@@ -51,6 +51,174 @@ fn spawn_player(mut commands: Commands) {
         .insert(Player);
 }
 
+#[test]
+fn global_action_state() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)])),
+        );
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+}
+
+#[test]
+fn input_context_stack() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputContextStack::new(InputMap::new([(
+            Action::PayRespects,
+            KeyCode::KeyF,
+        )])));
+
+    // Only the bindings of the menu context are active
+    app.world_mut()
+        .resource_mut::<InputContextStack<Action>>()
+        .push(InputMap::new([(Action::PayRespects, KeyCode::Enter)]));
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+
+    KeyCode::Enter.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+
+    // Popping the menu context restores the gameplay bindings
+    app.world_mut()
+        .resource_mut::<InputContextStack<Action>>()
+        .pop();
+    KeyCode::Enter.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+
+    KeyCode::KeyF.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+}
+
+#[test]
+fn held_at_startup() {
+    use bevy::input::InputPlugin;
+
+    for treat_startup_held_as_pressed in [true, false] {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(InputPlugin)
+            .add_plugins(
+                InputManagerPlugin::<Action>::default()
+                    .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)]))
+                    .treat_startup_held_as_pressed(treat_startup_held_as_pressed),
+            );
+
+        // The key is already held when the app starts
+        KeyCode::KeyF.press(app.world_mut());
+        app.update();
+
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.pressed(&Action::PayRespects));
+        assert_eq!(
+            action_state.just_pressed(&Action::PayRespects),
+            treat_startup_held_as_pressed
+        );
+
+        // Later presses are unaffected
+        KeyCode::KeyF.release(app.world_mut());
+        app.update();
+        KeyCode::KeyF.press(app.world_mut());
+        app.update();
+
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_pressed(&Action::PayRespects));
+    }
+}
+
+#[test]
+fn systems_ordered_after_update() {
+    use bevy::input::InputPlugin;
+
+    fn record_just_pressed(action_state: Res<ActionState<Action>>, mut respect: ResMut<Respect>) {
+        respect.0 = action_state.just_pressed(&Action::PayRespects);
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)])),
+        )
+        .init_resource::<Respect>()
+        .add_systems(
+            PreUpdate,
+            record_just_pressed.after(InputManagerSystem::Update),
+        );
+
+    // The press is observed during the same frame, before `Update` runs
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    assert_eq!(*app.world().resource::<Respect>(), Respect(true));
+
+    app.update();
+    assert_eq!(*app.world().resource::<Respect>(), Respect(false));
+}
+
+#[test]
+fn keycode_bindings_are_layout_independent() {
+    use bevy::input::keyboard::{Key, KeyboardInput};
+    use bevy::input::{ButtonState, InputPlugin};
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyW)])),
+        );
+
+    let send_key = |app: &mut App, key_code: KeyCode, character: &str, state: ButtonState| {
+        app.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Character(character.into()),
+            state,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    };
+
+    // On an AZERTY layout, the key in the physical W position produces a "z"
+    send_key(&mut app, KeyCode::KeyW, "z", ButtonState::Pressed);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+
+    send_key(&mut app, KeyCode::KeyW, "z", ButtonState::Released);
+    app.update();
+
+    // While the key that produces a "w" is in a different physical position
+    send_key(&mut app, KeyCode::KeyZ, "w", ButtonState::Pressed);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+}
+
 #[test]
 fn disable_input() {
     use bevy::input::InputPlugin;
@@ -158,6 +326,688 @@ fn release_when_input_map_removed() {
     assert_eq!(*respect, Respect(false));
 }
 
+#[test]
+fn consume_before_jump() {
+    use bevy::input::InputPlugin;
+
+    // Counts how many presses each system has handled
+    #[derive(Resource, Default)]
+    struct Handled {
+        consumed: usize,
+        jumps: usize,
+        held: usize,
+    }
+
+    // Like a menu that closes on the first press, stopping it from reaching gameplay
+    fn consume(mut action_state: ResMut<ActionState<Action>>, mut handled: ResMut<Handled>) {
+        if action_state.consume(&Action::PayRespects) {
+            handled.consumed += 1;
+        }
+    }
+
+    fn jump(action_state: Res<ActionState<Action>>, mut handled: ResMut<Handled>) {
+        if action_state.just_pressed(&Action::PayRespects) {
+            handled.jumps += 1;
+        }
+
+        if action_state.pressed(&Action::PayRespects) {
+            handled.held += 1;
+        }
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)])),
+        )
+        .init_resource::<Handled>()
+        .add_systems(Update, (consume, jump).chain());
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+
+    // The press is consumed before it can trigger a jump, but the action is still held
+    let handled = app.world().resource::<Handled>();
+    assert_eq!(handled.consumed, 1);
+    assert_eq!(handled.jumps, 0);
+    assert_eq!(handled.held, 1);
+
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+    assert!(!action_state.just_pressed(&Action::PayRespects));
+
+    // Holding the key does not produce a new press
+    app.update();
+    let handled = app.world().resource::<Handled>();
+    assert_eq!(handled.consumed, 1);
+    assert_eq!(handled.jumps, 0);
+    assert_eq!(handled.held, 2);
+
+    // Pressing again after a release can be consumed anew
+    KeyCode::KeyF.release(app.world_mut());
+    app.update();
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let handled = app.world().resource::<Handled>();
+    assert_eq!(handled.consumed, 2);
+    assert_eq!(handled.jumps, 0);
+    assert_eq!(handled.held, 3);
+}
+
+#[test]
+fn input_delay_frames() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::plugin::InputDelay;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)]))
+                .input_delay_frames(3),
+        );
+
+    KeyCode::KeyF.press(app.world_mut());
+
+    // The press is held back for three frames
+    for _ in 0..3 {
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.released(&Action::PayRespects));
+    }
+
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+
+    // Releases are delayed just the same
+    KeyCode::KeyF.release(app.world_mut());
+    for _ in 0..3 {
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.pressed(&Action::PayRespects));
+    }
+
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_released(&Action::PayRespects));
+
+    // Removing the delay at runtime applies inputs immediately again
+    app.world_mut().resource_mut::<InputDelay<Action>>().frames = 0;
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+}
+
+#[test]
+fn input_delay_forgets_removed_input_maps() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::plugin::InputDelay;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .insert_resource(InputDelay::<Action>::new(2));
+    let input_map = InputMap::new([(Action::PayRespects, KeyCode::KeyF)]);
+    let player = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(input_map.clone()))
+        .id();
+
+    // The press is buffered, but the input map is removed before it is applied
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    app.world_mut()
+        .entity_mut(player)
+        .remove::<InputMap<Action>>();
+    app.update();
+
+    // Once the input map is back, the stale buffered press is not applied
+    KeyCode::KeyF.release(app.world_mut());
+    app.world_mut().entity_mut(player).insert(input_map);
+    for _ in 0..3 {
+        app.update();
+        let action_state = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(action_state.released(&Action::PayRespects));
+    }
+}
+
+#[test]
+fn multiple_action_types_on_one_entity() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Movement {
+        Left,
+        Right,
+        Jump,
+    }
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum UiAction {
+        Confirm,
+        Cancel,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Movement>::default())
+        .add_plugins(InputManagerPlugin::<UiAction>::default());
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            InputManagerBundle::with_map(InputMap::new([
+                (Movement::Left, KeyCode::KeyA),
+                (Movement::Right, KeyCode::KeyD),
+                (Movement::Jump, KeyCode::Space),
+            ])),
+            InputManagerBundle::with_map(InputMap::new([
+                (UiAction::Confirm, KeyCode::Space),
+                (UiAction::Cancel, KeyCode::Escape),
+            ])),
+        ))
+        .id();
+
+    // Each ActionState only responds to its own bindings
+    KeyCode::KeyD.press(app.world_mut());
+    app.update();
+    let movement = app.world().get::<ActionState<Movement>>(entity).unwrap();
+    assert_eq!(movement.get_pressed(), vec![Movement::Right]);
+    let ui = app.world().get::<ActionState<UiAction>>(entity).unwrap();
+    assert!(ui.get_pressed().is_empty());
+
+    KeyCode::KeyD.release(app.world_mut());
+    KeyCode::Escape.press(app.world_mut());
+    app.update();
+    let movement = app.world().get::<ActionState<Movement>>(entity).unwrap();
+    assert!(movement.just_released(&Movement::Right));
+    assert!(movement.get_pressed().is_empty());
+    let ui = app.world().get::<ActionState<UiAction>>(entity).unwrap();
+    assert!(ui.just_pressed(&UiAction::Cancel));
+
+    // Inputs shared between action types trigger both
+    KeyCode::Escape.release(app.world_mut());
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    let movement = app.world().get::<ActionState<Movement>>(entity).unwrap();
+    assert!(movement.just_pressed(&Movement::Jump));
+    let ui = app.world().get::<ActionState<UiAction>>(entity).unwrap();
+    assert!(ui.just_pressed(&UiAction::Confirm));
+    assert!(ui.just_released(&UiAction::Cancel));
+}
+
+#[test]
+fn plugin_group_for_multiple_action_types() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Movement {
+        Jump,
+    }
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum UiAction {
+        Confirm,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugins::new()
+                .with::<Movement>()
+                .with::<UiAction>(),
+        )
+        .init_resource::<ActionState<Movement>>()
+        .insert_resource(InputMap::new([(Movement::Jump, KeyCode::Space)]))
+        .init_resource::<ActionState<UiAction>>()
+        .insert_resource(InputMap::new([(UiAction::Confirm, KeyCode::Enter)]));
+
+    KeyCode::Space.press(app.world_mut());
+    KeyCode::Enter.press(app.world_mut());
+    app.update();
+    let movement = app.world().resource::<ActionState<Movement>>();
+    assert!(movement.just_pressed(&Movement::Jump));
+    let ui = app.world().resource::<ActionState<UiAction>>();
+    assert!(ui.just_pressed(&UiAction::Confirm));
+
+    KeyCode::Space.release(app.world_mut());
+    KeyCode::Enter.release(app.world_mut());
+    app.update();
+    let movement = app.world().resource::<ActionState<Movement>>();
+    assert!(movement.just_released(&Movement::Jump));
+    let ui = app.world().resource::<ActionState<UiAction>>();
+    assert!(ui.just_released(&UiAction::Confirm));
+}
+
+#[test]
+fn action_aliases() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Movement {
+        Walk,
+        Sprint,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Movement>::default())
+        .init_resource::<ActionState<Movement>>()
+        .insert_resource(
+            InputMap::new([(Movement::Walk, KeyCode::KeyW)])
+                .with(Movement::Sprint, KeyCode::ShiftLeft)
+                .with_alias(Movement::Sprint, Movement::Walk),
+        );
+
+    // Sprint reacts to Walk's key while aliased
+    KeyCode::KeyW.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.pressed(&Movement::Walk));
+    assert!(action_state.pressed(&Movement::Sprint));
+
+    // Sprint's own bindings are ignored
+    KeyCode::KeyW.release(app.world_mut());
+    KeyCode::ShiftLeft.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.released(&Movement::Sprint));
+
+    // Clearing the alias restores Sprint's own bindings
+    let mut input_map = app.world_mut().resource_mut::<InputMap<Movement>>();
+    assert_eq!(
+        input_map.clear_alias(&Movement::Sprint),
+        Some(Movement::Walk)
+    );
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.pressed(&Movement::Sprint));
+    assert!(action_state.released(&Movement::Walk));
+
+    KeyCode::ShiftLeft.release(app.world_mut());
+    KeyCode::KeyW.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.released(&Movement::Sprint));
+}
+
+#[test]
+fn action_aliases_ignore_own_bindings_in_clashes() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Movement {
+        Walk,
+        Sprint,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Movement>::default())
+        .init_resource::<ActionState<Movement>>()
+        .insert_resource(
+            InputMap::new([(Movement::Walk, KeyCode::KeyW)])
+                .with(
+                    Movement::Sprint,
+                    ButtonlikeChord::new([KeyCode::ShiftLeft, KeyCode::KeyW]),
+                )
+                .with_alias(Movement::Sprint, Movement::Walk),
+        );
+
+    // Sprint's chord doesn't clash with Walk while aliased
+    KeyCode::ShiftLeft.press(app.world_mut());
+    KeyCode::KeyW.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.pressed(&Movement::Walk));
+    assert!(action_state.pressed(&Movement::Sprint));
+
+    // Exclusive groups see the value Sprint takes from Walk
+    app.world_mut()
+        .resource_mut::<InputMap<Movement>>()
+        .set_exclusive_group(&[Movement::Sprint, Movement::Walk]);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Movement>>();
+    assert!(action_state.pressed(&Movement::Sprint));
+    assert!(action_state.released(&Movement::Walk));
+}
+
+#[test]
+fn conditional_inversion() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Camera {
+        #[actionlike(DualAxis)]
+        Look,
+        InvertY,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Camera>::default())
+        .init_resource::<ActionState<Camera>>()
+        .insert_resource(
+            InputMap::default()
+                .with_dual_axis(Camera::Look, VirtualDPad::wasd())
+                .with(Camera::InvertY, KeyCode::ShiftLeft)
+                .with_conditional_inversion(
+                    Camera::Look,
+                    Camera::InvertY,
+                    DualAxisInverted::ONLY_Y,
+                ),
+        );
+
+    KeyCode::KeyW.press(app.world_mut());
+    KeyCode::KeyD.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(1.0, 1.0));
+
+    // Holding the gating action flips the Y axis only
+    KeyCode::ShiftLeft.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(1.0, -1.0));
+
+    // And releasing it restores the original direction
+    KeyCode::ShiftLeft.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(1.0, 1.0));
+}
+
+#[test]
+fn conditional_inversion_with_programmatic_gate() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Camera {
+        #[actionlike(DualAxis)]
+        Look,
+        InvertY,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Camera>::default())
+        .init_resource::<ActionState<Camera>>()
+        .insert_resource(
+            InputMap::default()
+                .with_dual_axis(Camera::Look, VirtualDPad::wasd())
+                .with_conditional_inversion(
+                    Camera::Look,
+                    Camera::InvertY,
+                    DualAxisInverted::ONLY_Y,
+                ),
+        );
+
+    KeyCode::KeyW.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(0.0, 1.0));
+
+    // The gate has no bindings, so pressing it in the ActionState flips the Y axis
+    app.world_mut()
+        .resource_mut::<ActionState<Camera>>()
+        .press(&Camera::InvertY);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert!(action_state.pressed(&Camera::InvertY));
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(0.0, -1.0));
+
+    app.world_mut()
+        .resource_mut::<ActionState<Camera>>()
+        .release(&Camera::InvertY);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Camera>>();
+    assert_eq!(action_state.axis_pair(&Camera::Look), Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn edge_mode_switched_to_level() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::buttonlike::EdgeMode;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(
+            InputMap::new([(Action::PayRespects, KeyCode::KeyF)])
+                .with_edge_mode(Action::PayRespects, EdgeMode::RisingEdge),
+        );
+
+    // With a rising edge, holding the key only fires once
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+
+    // Switching back to the default level mode at runtime holds the action again
+    app.world_mut()
+        .resource_mut::<InputMap<Action>>()
+        .set_edge_mode(Action::PayRespects, EdgeMode::Level);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+}
+
+#[test]
+fn removed_settings_are_reset() {
+    use bevy::input::InputPlugin;
+    use bevy::utils::Duration;
+    use leafwing_input_manager::buttonlike::EdgeMode;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(
+            InputMap::new([(Action::PayRespects, KeyCode::KeyF)])
+                .with_edge_mode(Action::PayRespects, EdgeMode::RisingEdge)
+                .with_cooldown(Action::PayRespects, Duration::from_secs(60)),
+        );
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+    assert!(action_state.cooldown_remaining(&Action::PayRespects) > Duration::ZERO);
+
+    // Clearing the cooldown from the map also clears it from the action state
+    let mut input_map = app.world_mut().resource_mut::<InputMap<Action>>();
+    assert_eq!(
+        input_map.clear_cooldown(&Action::PayRespects),
+        Some(Duration::from_secs(60))
+    );
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert_eq!(
+        action_state.cooldown_remaining(&Action::PayRespects),
+        Duration::ZERO
+    );
+    assert!(action_state.released(&Action::PayRespects));
+
+    // Switching to a map without an edge mode holds the action again
+    app.insert_resource(InputMap::new([(Action::PayRespects, KeyCode::KeyF)]));
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+}
+
+#[test]
+fn inverted_action() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Voice {
+        Talk,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Voice>::default())
+        .init_resource::<ActionState<Voice>>()
+        .insert_resource(
+            InputMap::new([(Voice::Talk, KeyCode::KeyT)]).with_inverted(Voice::Talk, true),
+        );
+
+    // The inverted action is pressed while its key is released
+    app.update();
+    let action_state = app.world().resource::<ActionState<Voice>>();
+    assert!(action_state.pressed(&Voice::Talk));
+
+    // And released while its key is pressed
+    KeyCode::KeyT.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Voice>>();
+    assert!(action_state.just_released(&Voice::Talk));
+
+    KeyCode::KeyT.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Voice>>();
+    assert!(action_state.just_pressed(&Voice::Talk));
+
+    // Reverting the inversion restores the usual behavior
+    app.world_mut()
+        .resource_mut::<InputMap<Voice>>()
+        .set_inverted(Voice::Talk, false);
+    app.update();
+    let action_state = app.world().resource::<ActionState<Voice>>();
+    assert!(action_state.just_released(&Voice::Talk));
+}
+
+#[test]
+fn dataful_actions() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Direction {
+        Up,
+        Down,
+        Left,
+        Right,
+    }
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum PlayerAction {
+        Move(Direction),
+        #[actionlike(Axis)]
+        Zoom(u8),
+    }
+
+    let bindings = [
+        (Direction::Up, KeyCode::KeyW),
+        (Direction::Down, KeyCode::KeyS),
+        (Direction::Left, KeyCode::KeyA),
+        (Direction::Right, KeyCode::KeyD),
+    ];
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<PlayerAction>::default())
+        .init_resource::<ActionState<PlayerAction>>()
+        .insert_resource(
+            InputMap::new(bindings.map(|(direction, key)| (PlayerAction::Move(direction), key)))
+                .with_axis(PlayerAction::Zoom(0), VirtualAxis::ws()),
+        );
+
+    // Each direction fires independently of the others
+    for (direction, key) in bindings {
+        key.press(app.world_mut());
+        app.update();
+
+        let action_state = app.world().resource::<ActionState<PlayerAction>>();
+        for (other_direction, _) in bindings {
+            let action = PlayerAction::Move(other_direction);
+            assert_eq!(action_state.pressed(&action), other_direction == direction);
+        }
+
+        key.release(app.world_mut());
+        app.update();
+    }
+
+    // Dataful variants can have their own control kind
+    assert_eq!(
+        PlayerAction::Zoom(0).input_control_kind(),
+        InputControlKind::Axis
+    );
+    KeyCode::KeyW.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<PlayerAction>>();
+    assert_eq!(action_state.value(&PlayerAction::Zoom(0)), 1.0);
+    assert_eq!(action_state.value(&PlayerAction::Zoom(1)), 0.0);
+}
+
+#[test]
+fn run_condition() {
+    use bevy::input::InputPlugin;
+
+    #[derive(Resource)]
+    struct InputEnabled(bool);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)]))
+                .run_if(|enabled: Res<InputEnabled>| enabled.0),
+        )
+        .insert_resource(InputEnabled(false));
+
+    // Inputs are ignored while the condition is false
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+
+    app.world_mut().resource_mut::<InputEnabled>().0 = true;
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(&Action::PayRespects));
+
+    // The ActionState holds steady while the condition is false
+    app.world_mut().resource_mut::<InputEnabled>().0 = false;
+    KeyCode::KeyF.release(app.world_mut());
+    for _ in 0..3 {
+        app.update();
+        let action_state = app.world().resource::<ActionState<Action>>();
+        assert!(action_state.just_pressed(&Action::PayRespects));
+    }
+
+    app.world_mut().resource_mut::<InputEnabled>().0 = true;
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.just_released(&Action::PayRespects));
+}
+
 #[cfg(feature = "timing")]
 #[test]
 fn duration() {
@@ -208,3 +1058,204 @@ fn duration() {
         .resource::<ActionState<Action>>()
         .pressed(&Action::PayRespects));
 }
+
+#[cfg(feature = "timing")]
+#[test]
+fn run_condition_freezes_durations() {
+    use bevy::input::InputPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::utils::Duration;
+
+    #[derive(Resource)]
+    struct InputEnabled(bool);
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)]))
+                .run_if(|enabled: Res<InputEnabled>| enabled.0),
+        )
+        .insert_resource(InputEnabled(true))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app.update();
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    let held = action_state.current_duration(&Action::PayRespects);
+    assert!(held > Duration::ZERO);
+
+    // Pause while the button is held
+    app.world_mut().resource_mut::<InputEnabled>().0 = false;
+    for _ in 0..10 {
+        app.update();
+    }
+
+    // Only the frame since resuming counts
+    app.world_mut().resource_mut::<InputEnabled>().0 = true;
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+    assert_eq!(
+        action_state.current_duration(&Action::PayRespects),
+        held + FRAME
+    );
+}
+
+#[test]
+fn last_active_binding_is_tracked_per_entity() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::axislike::CombinePolicy;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Steering {
+        #[actionlike(Axis)]
+        Turn,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Steering>::default());
+
+    let input_map = InputMap::default()
+        .with_axis(
+            Steering::Turn,
+            VirtualAxis::new(KeyCode::KeyA, KeyCode::KeyD),
+        )
+        .with_axis(
+            Steering::Turn,
+            VirtualAxis::new(KeyCode::ArrowRight, KeyCode::ArrowLeft),
+        )
+        .with_axis_combine(Steering::Turn, CombinePolicy::LastActiveWins);
+    let first = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(input_map.clone()))
+        .id();
+    let turn = |app: &App, entity: Entity| {
+        app.world()
+            .get::<ActionState<Steering>>(entity)
+            .unwrap()
+            .value(&Steering::Turn)
+    };
+
+    KeyCode::KeyD.press(app.world_mut());
+    app.update();
+    assert_eq!(turn(&app, first), 1.0);
+
+    // The binding that most recently became active wins
+    KeyCode::ArrowRight.press(app.world_mut());
+    app.update();
+    assert_eq!(turn(&app, first), -1.0);
+
+    // Entities spawned later start from the bindings that are currently active
+    let second = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(input_map.clone()))
+        .id();
+    app.update();
+    assert_eq!(turn(&app, first), -1.0);
+    assert_eq!(turn(&app, second), 1.0);
+
+    KeyCode::ArrowRight.release(app.world_mut());
+    app.update();
+    assert_eq!(turn(&app, first), 1.0);
+    assert_eq!(turn(&app, second), 1.0);
+
+    // Processing actions leaves the input map untouched
+    let stored_map = app.world().get::<InputMap<Steering>>(first).unwrap();
+    assert_eq!(stored_map, &input_map);
+}
+
+#[test]
+fn mocked_inputs_are_not_pressed_directly() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<Action>::default()
+                .with_global_input_map(InputMap::new([(Action::PayRespects, KeyCode::KeyF)])),
+        );
+
+    // Inputs mocked on the world are read just like real input
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.pressed(&Action::PayRespects));
+    assert!(!action_state.pressed_directly(&Action::PayRespects));
+
+    KeyCode::KeyF.release(app.world_mut());
+    app.update();
+    let mut action_state = app.world_mut().resource_mut::<ActionState<Action>>();
+    action_state.press(&Action::PayRespects);
+    assert!(action_state.pressed_directly(&Action::PayRespects));
+}
+
+#[test]
+fn triggering_binding_index_follows_final_state() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::plugin::InputDelay;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum Ability {
+        Primary,
+        Secondary,
+        Idle,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Ability>::default())
+        .init_resource::<ActionState<Ability>>()
+        .insert_resource(
+            InputMap::new([
+                (Ability::Primary, KeyCode::KeyA),
+                (Ability::Secondary, KeyCode::KeyB),
+                (Ability::Secondary, KeyCode::KeyC),
+                (Ability::Idle, KeyCode::KeyI),
+            ])
+            .with_exclusive_group(&[Ability::Primary, Ability::Secondary])
+            .with_inverted(Ability::Idle, true),
+        );
+    let index = |app: &App, action| {
+        app.world()
+            .resource::<ActionState<Ability>>()
+            .triggering_binding_index(&action)
+    };
+
+    // Inverted actions aren't pressed by any of their bindings
+    app.update();
+    assert!(app
+        .world()
+        .resource::<ActionState<Ability>>()
+        .pressed(&Ability::Idle));
+    assert_eq!(index(&app, Ability::Idle), None);
+
+    KeyCode::KeyC.press(app.world_mut());
+    app.update();
+    assert_eq!(index(&app, Ability::Secondary), Some(1));
+
+    // Actions released by their exclusive group have no triggering binding
+    KeyCode::KeyA.press(app.world_mut());
+    app.update();
+    assert_eq!(index(&app, Ability::Primary), Some(0));
+    assert_eq!(index(&app, Ability::Secondary), None);
+
+    // The triggering binding is delayed along with the rest of the input
+    KeyCode::KeyA.release(app.world_mut());
+    app.insert_resource(InputDelay::<Ability>::new(1));
+    app.update();
+    assert_eq!(index(&app, Ability::Primary), Some(0));
+    assert_eq!(index(&app, Ability::Secondary), None);
+    app.update();
+    assert_eq!(index(&app, Ability::Primary), None);
+    assert_eq!(index(&app, Ability::Secondary), Some(1));
+}
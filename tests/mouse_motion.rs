@@ -178,6 +178,28 @@ fn mouse_move_dual_axis() {
     );
 }
 
+#[test]
+fn mouse_move_ignores_noise_floor() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<AxislikeTestAction>::default().global_noise_floor(0.5))
+        .init_resource::<ActionState<AxislikeTestAction>>()
+        .insert_resource(
+            InputMap::default().with_dual_axis(AxislikeTestAction::XY, MouseMove::default()),
+        );
+
+    // Small mouse movements are deliberate, unlike analog drift
+    MouseMove::default().set_axis_pair(app.world_mut(), Vec2::new(0.2, 0.0));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.2, 0.0)
+    );
+}
+
 #[test]
 fn mouse_move_discrete() {
     let mut app = test_app();
@@ -223,3 +245,76 @@ fn mouse_drag() {
         Vec2::new(5.0, 0.0)
     );
 }
+
+#[test]
+fn mouse_drag_single_axis() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::default().with_axis(
+        AxislikeTestAction::X,
+        AxislikeChord::new(MouseButton::Right, MouseMoveAxis::X),
+    ));
+
+    // The motion is ignored while the button is released
+    let input = MouseMoveAxis::X;
+    input.set_value(app.world_mut(), 5.0);
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), 0.0);
+
+    // And forwarded while it is held
+    MouseButton::Right.press(app.world_mut());
+    input.set_value(app.world_mut(), 5.0);
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), 5.0);
+
+    // Releasing the button gates the motion again
+    MouseButton::Right.release(app.world_mut());
+    input.set_value(app.world_mut(), 5.0);
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), 0.0);
+}
+
+#[test]
+fn clamp_axis_output() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<AxislikeTestAction>::default().clamp_axis_output(true))
+        .init_resource::<ActionState<AxislikeTestAction>>();
+    app.insert_resource(
+        InputMap::default()
+            .with_axis(AxislikeTestAction::X, MouseMoveAxis::X)
+            .with_dual_axis(AxislikeTestAction::XY, MouseMove::default())
+            .with_axis_processors(AxislikeTestAction::X, [AxisProcessor::Sensitivity(3.0)])
+            .with_dual_axis_processors(
+                AxislikeTestAction::XY,
+                [DualAxisSensitivity::all(3.0).into()],
+            ),
+    );
+
+    // The over-unity sensitivity would scale the motion to a magnitude of 1.5
+    MouseMove::default().set_axis_pair(app.world_mut(), Vec2::new(0.3, 0.4));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert!((action_state.value(&AxislikeTestAction::X) - 0.9).abs() < 1e-6);
+    let axis_pair = action_state.axis_pair(&AxislikeTestAction::XY);
+    assert!((axis_pair.length() - 1.0).abs() < 1e-6);
+    assert!((axis_pair - Vec2::new(0.6, 0.8)).length() < 1e-6);
+
+    // Values beyond the range of a single axis are clamped as well
+    MouseMove::default().set_axis_pair(app.world_mut(), Vec2::new(-2.0, 0.0));
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::X), -1.0);
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(-1.0, 0.0)
+    );
+}
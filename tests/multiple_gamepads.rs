@@ -5,11 +5,14 @@ use bevy::input::gamepad::{
 };
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
+use leafwing_input_manager::plugin::InputDelay;
 use leafwing_input_manager::prelude::*;
 
 #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
 enum MyAction {
     Jump,
+    #[actionlike(DualAxis)]
+    Move,
 }
 
 fn create_test_app() -> App {
@@ -116,3 +119,203 @@ fn filters_out_other_gamepads() {
     let action_state = app.world_mut().resource_mut::<ActionState<MyAction>>();
     assert!(action_state.released(&MyAction::Jump));
 }
+
+#[test]
+fn reports_triggering_gamepad() {
+    let mut app = create_test_app();
+
+    let first_gamepad = app.world_mut().spawn(()).id();
+    let second_gamepad = app.world_mut().spawn(()).id();
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    for (gamepad, name) in [(first_gamepad, "Player 1"), (second_gamepad, "Player 2")] {
+        gamepad_connection_events.send(GamepadConnectionEvent {
+            // This MUST be consistent with any other mocked events
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: name.to_owned(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+    }
+    // Ensure that the gamepads are picked up by the appropriate system
+    app.update();
+    // Ensure that the connection events are flushed through
+    app.update();
+
+    let input_map =
+        InputMap::new([(MyAction::Jump, GamepadButton::South)]).with_any_gamepad(MyAction::Jump);
+    app.insert_resource(input_map);
+    app.init_resource::<ActionState<MyAction>>();
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.released(&MyAction::Jump));
+    assert_eq!(action_state.triggering_gamepad(&MyAction::Jump), None);
+
+    // When we press the Jump button on the second gamepad...
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(second_gamepad));
+    app.update();
+
+    // ... the action should fire, and report which gamepad pressed it.
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.pressed(&MyAction::Jump));
+    assert_eq!(
+        action_state.triggering_gamepad(&MyAction::Jump),
+        Some(second_gamepad)
+    );
+}
+
+#[test]
+fn disconnecting_gamepad_releases_actions() {
+    let mut app = create_test_app();
+
+    let gamepad = app.world_mut().spawn(()).id();
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    gamepad_connection_events.send(GamepadConnectionEvent {
+        gamepad,
+        connection: GamepadConnection::Connected {
+            name: "Disconnecting gamepad".to_owned(),
+            vendor_id: None,
+            product_id: None,
+        },
+    });
+    app.update();
+    app.update();
+
+    let mut input_map = InputMap::new([(MyAction::Jump, GamepadButton::South)]);
+    input_map.set_gamepad(gamepad);
+    app.insert_resource(input_map);
+    app.insert_resource(InputDelay::<MyAction>::new(1));
+    app.init_resource::<ActionState<MyAction>>();
+
+    // Hold the Jump button...
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(gamepad));
+    app.update();
+    app.update();
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.pressed(&MyAction::Jump));
+
+    // ... then disconnect the gamepad mid-hold
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    gamepad_connection_events.send(GamepadConnectionEvent {
+        gamepad,
+        connection: GamepadConnection::Disconnected,
+    });
+    app.update();
+
+    // The action should be released rather than stuck pressed
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.released(&MyAction::Jump));
+
+    app.update();
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.released(&MyAction::Jump));
+}
+
+#[test]
+fn disconnecting_first_gamepad_releases_actions() {
+    let mut app = create_test_app();
+
+    // Without an associated gamepad, the map reads from the first connected one
+    let gamepad = app
+        .world_mut()
+        .query_filtered::<Entity, With<Gamepad>>()
+        .iter(app.world())
+        .next()
+        .unwrap();
+    app.insert_resource(InputMap::new([(MyAction::Jump, GamepadButton::South)]));
+    app.init_resource::<ActionState<MyAction>>();
+
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(gamepad));
+    app.update();
+    app.update();
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.pressed(&MyAction::Jump));
+
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    gamepad_connection_events.send(GamepadConnectionEvent {
+        gamepad,
+        connection: GamepadConnection::Disconnected,
+    });
+    app.update();
+
+    let action_state = app.world().resource::<ActionState<MyAction>>();
+    assert!(action_state.released(&MyAction::Jump));
+}
+
+#[test]
+fn player_gamepads_are_isolated() {
+    let mut app = create_test_app();
+
+    let first_gamepad = app.world_mut().spawn(()).id();
+    let second_gamepad = app.world_mut().spawn(()).id();
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    for (gamepad, name) in [(first_gamepad, "Player 1"), (second_gamepad, "Player 2")] {
+        gamepad_connection_events.send(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: name.to_owned(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+    }
+    app.update();
+    app.update();
+
+    // Both players share the same bindings, each scoped to their own gamepad
+    let input_map = InputMap::new([(MyAction::Jump, GamepadButton::South)])
+        .with_dual_axis(MyAction::Move, GamepadStick::LEFT);
+    let first_player = app
+        .world_mut()
+        .spawn((
+            input_map.clone().with_gamepad(first_gamepad),
+            ActionState::<MyAction>::default(),
+        ))
+        .id();
+    let second_player = app
+        .world_mut()
+        .spawn((
+            input_map.with_gamepad(second_gamepad),
+            ActionState::<MyAction>::default(),
+        ))
+        .id();
+
+    // The first player jumps, while the second player moves
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(first_gamepad));
+    GamepadStick::LEFT.set_axis_pair_as_gamepad(
+        app.world_mut(),
+        Vec2::new(0.0, 1.0),
+        Some(second_gamepad),
+    );
+    app.update();
+
+    let first_state = app
+        .world()
+        .get::<ActionState<MyAction>>(first_player)
+        .unwrap();
+    assert!(first_state.pressed(&MyAction::Jump));
+    assert_eq!(first_state.axis_pair(&MyAction::Move), Vec2::ZERO);
+
+    let second_state = app
+        .world()
+        .get::<ActionState<MyAction>>(second_player)
+        .unwrap();
+    assert!(second_state.released(&MyAction::Jump));
+    assert_eq!(second_state.axis_pair(&MyAction::Move), Vec2::new(0.0, 1.0));
+}
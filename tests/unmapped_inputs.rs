@@ -0,0 +1,85 @@
+#![cfg(feature = "keyboard")]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::unmapped_inputs::UnmappedInputs;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+enum Action {
+    Jump,
+    Save,
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default().report_unmapped_inputs(true))
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::new([(Action::Jump, KeyCode::Space)]).with(
+            Action::Save,
+            ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]),
+        ));
+    app.update();
+    app
+}
+
+fn unmapped_inputs(app: &App) -> &UnmappedInputs<Action> {
+    app.world().resource::<UnmappedInputs<Action>>()
+}
+
+#[test]
+fn unbound_key_is_reported() {
+    let mut app = test_app();
+    assert!(unmapped_inputs(&app).is_empty());
+
+    KeyCode::KeyQ.press(app.world_mut());
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    let unmapped = unmapped_inputs(&app);
+    assert!(unmapped.contains(&KeyCode::KeyQ));
+    assert!(!unmapped.contains(&KeyCode::Space));
+    assert_eq!(unmapped.len(), 1);
+
+    KeyCode::KeyQ.release(app.world_mut());
+    app.update();
+    assert!(unmapped_inputs(&app).is_empty());
+}
+
+#[test]
+fn chord_members_are_bound() {
+    let mut app = test_app();
+
+    KeyCode::ControlLeft.press(app.world_mut());
+    app.update();
+    assert!(unmapped_inputs(&app).is_empty());
+}
+
+#[test]
+fn entity_input_maps_are_checked() {
+    let mut app = test_app();
+    app.world_mut()
+        .spawn(InputManagerBundle::with_map(InputMap::new([(
+            Action::Jump,
+            KeyCode::KeyQ,
+        )])));
+
+    KeyCode::KeyQ.press(app.world_mut());
+    app.update();
+    assert!(unmapped_inputs(&app).is_empty());
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default());
+    app.update();
+
+    assert!(app
+        .world()
+        .get_resource::<UnmappedInputs<Action>>()
+        .is_none());
+}